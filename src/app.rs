@@ -1,12 +1,33 @@
 use eframe::egui;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::batch::{BatchItem, BatchJob, BatchOp, Outcome};
+use crate::entry_store::EntryStore;
+use crate::index_file::CacheCodec;
 use crate::indexer::{FileIndexer, IndexBuildStats, IndexRootSource};
-use crate::searcher::{MatchType, SearchResult, Searcher};
+use crate::ranking::{RankingPreset, RankingProfile, ScoreExplanation};
+use crate::rename::{
+    apply_plan, undo_batch, CaseChange, RenameBatch, RenameLog, RenamePlan, RenameRecord, RenameRules, RenameSource,
+    RenameStatus,
+};
+use crate::categories::{parse_extensions, CategoryRegistry, FileCategory};
+use crate::facets::{Facets, FileTypeFilter};
+use crate::file_ops;
+use crate::history::{suggest, Completions, SearchHistory};
+use crate::icons::{IconCache, IconRequest, ICON_SIZE, THUMBNAIL_SIZE};
+use crate::keymap::{captured_shortcut, Keymap, ResultAction};
+use crate::launcher::{GlobalHotkey, LauncherEvent, QuickLauncher};
+use crate::preview::{self, Preview, PreviewContent, PreviewLoader};
+use crate::saved_searches::{SavedSearch, SavedSearches};
+use crate::searcher::{MatchField, MatchType, SearchHits, SearchResult, Searcher, SortOrder};
+use crate::selection::Selection;
+use crate::usage::{UsageKind, UsageStore};
 
 #[derive(PartialEq, Clone, Copy)]
 enum Tab {
@@ -14,37 +35,255 @@ enum Tab {
     Settings,
 }
 
-#[derive(PartialEq, Clone, Copy)]
-enum FileTypeFilter {
-    All,
-    Files,
-    Folders,
-    Documents,
-    Images,
-    Videos,
-    Audio,
+const CACHE_SETTINGS_KEY: &str = "cache_settings";
+const USAGE_SETTINGS_KEY: &str = "usage_settings";
+const RANKING_PROFILE_KEY: &str = "ranking_profile";
+const FILE_CATEGORIES_KEY: &str = "file_categories";
+const SAVED_SEARCHES_KEY: &str = "saved_searches";
+const SEARCH_HISTORY_KEY: &str = "search_history";
+const KEYMAP_KEY: &str = "keymap";
+const RENAME_RULES_KEY: &str = "rename_rules";
+const RENAME_LOG_KEY: &str = "rename_log";
+const PREVIEW_SETTINGS_KEY: &str = "preview_settings";
+const ICON_SETTINGS_KEY: &str = "icon_settings";
+const HOTKEY_SETTINGS_KEY: &str = "hotkey_settings";
+/// 结果右键菜单的分组（组间加分隔线）；“打开方式”与系统菜单仅在 Windows 上显示。
+const CONTEXT_MENU: [&[ResultAction]; 5] = [
+    &[ResultAction::Open, ResultAction::OpenWith, ResultAction::RevealInFolder],
+    &[ResultAction::CopyPath, ResultAction::CopyName, ResultAction::CopyAsFile],
+    &[ResultAction::Rename, ResultAction::MoveToTrash],
+    &[ResultAction::SearchInFolder, ResultAction::Properties],
+    &[ResultAction::ShellMenu],
+];
+/// 搜索框下方最多显示的补全候选数。
+const MAX_SUGGESTIONS: usize = 8;
+/// 结果列表每次从命中中生成的结果数。
+const RESULT_PAGE_SIZE: usize = 200;
+
+/// 本地索引缓存设置（持久化到 eframe storage）。默认关闭，与“启动即重建索引”的默认行为一致。
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct CacheSettings {
+    enabled: bool,
+    codec: CacheCodec,
+}
+
+/// 文件操作针对的结果；`generation` 与 `index` 用于在操作成功后改写索引中的同一条目。
+struct ResultTarget {
+    path: String,
+    name: String,
+    is_dir: bool,
+    size: u64,
+    modified_ms: u64,
+    generation: u64,
+    index: usize,
+}
+
+impl ResultTarget {
+    fn from_result(result: &SearchResult) -> Self {
+        let entry = result.entry();
+        Self {
+            path: result.display_path.clone(),
+            name: entry.name().to_string(),
+            is_dir: entry.is_dir(),
+            size: entry.size(),
+            modified_ms: entry.modified_ms(),
+            generation: result.generation,
+            index: result.index,
+        }
+    }
+}
+
+/// 重命名对话框（F2）。
+struct RenameDialog {
+    target: ResultTarget,
+    input: String,
+    error: Option<String>,
+}
+
+/// 需要额外输入的批量操作。
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BatchDialogKind {
+    CopyTo,
+    MoveTo,
+    Export,
+}
+
+impl BatchDialogKind {
+    fn label(self) -> &'static str {
+        match self {
+            BatchDialogKind::CopyTo => "复制到文件夹",
+            BatchDialogKind::MoveTo => "移动到文件夹",
+            BatchDialogKind::Export => "导出为 CSV",
+        }
+    }
+}
+
+/// 批量操作的参数对话框：目标文件夹或导出路径。
+struct BatchDialog {
+    kind: BatchDialogKind,
+    targets: Vec<ResultTarget>,
+    input: String,
+}
+
+/// 批量重命名工具。规则保存在 `FileSearchApp::rename_rules`，变化时重新生成预览。
+struct RenameTool {
+    sources: Vec<RenameSource>,
+    /// 生成当前预览所用的规则。
+    planned: Option<RenameRules>,
+    plan: Result<RenamePlan, String>,
+    error: Option<String>,
+}
+
+/// 属性窗口的内容（读取一次，不随文件变化刷新）。
+struct PropertiesView {
+    rows: Vec<(&'static str, String)>,
+}
+
+impl PropertiesView {
+    fn read(target: &ResultTarget) -> Self {
+        Self {
+            rows: preview::metadata_rows(&target.path, target.is_dir),
+        }
+    }
+}
+
+/// 使用记录设置：开启时记录打开/定位操作，并按 frecency 给常用文件加分。
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+struct UsageSettings {
+    enabled: bool,
+}
+
+impl Default for UsageSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// 预览面板设置；面板宽度由 egui 自行记忆。
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+struct PreviewSettings {
+    enabled: bool,
+}
+
+impl Default for PreviewSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// 当前搜索的全部命中与生成结果所用的搜索器（与 `search_seq` 对应）。
+struct PagedSearch {
+    seq: u64,
+    searcher: Searcher,
+    hits: SearchHits,
+}
+
+/// 结果图标设置。
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct IconSettings {
+    /// 图片显示缩略图（行高随之增大）。
+    thumbnails: bool,
+}
+
+/// 全局快捷键与托盘设置。
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+struct HotkeySettings {
+    enabled: bool,
+    shortcut: egui::KeyboardShortcut,
+    /// 关闭主窗口时驻留在系统托盘（仅 Windows）。
+    tray: bool,
+}
+
+impl Default for HotkeySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            shortcut: egui::KeyboardShortcut::new(egui::Modifiers::CTRL | egui::Modifiers::ALT, egui::Key::Space),
+            tray: true,
+        }
+    }
 }
 
 pub struct FileSearchApp {
     search_text: String,
     searcher: Searcher,
     indexer: Arc<Mutex<FileIndexer>>,
+    /// 已生成的结果（全部命中的前缀），滚动到末尾时由 `load_result_pages` 接着生成。
     results: Arc<Mutex<Vec<SearchResult>>>,
-    selected_result: Option<usize>,
+    paged: Arc<Mutex<Option<Arc<PagedSearch>>>>,
+    page_loading: Arc<AtomicBool>,
+    /// 上一帧绘制的结果行，供键盘导航时计算滚动位置。
+    visible_rows: Range<usize>,
+    facets: Arc<Mutex<Facets>>,
+    selection: Selection,
     current_tab: Tab,
     index_paths: Vec<PathBuf>,
     is_indexing: bool,
     index_progress: (usize, usize),
     total_files: usize,
-    show_hidden: bool,
     window_size: [f32; 2],
-    file_extension: String,  // 文件扩展名过滤
-    file_type_filter: FileTypeFilter,  // 文件类型过滤
     new_path_input: String,  // 新路径输入
     index_seq: Arc<AtomicU64>,
     search_seq: Arc<AtomicU64>,
     last_index_time: Option<SystemTime>,
     index_stats: Arc<Mutex<Option<IndexBuildStats>>>,
+    cache_settings: CacheSettings,
+    cache_status: Option<String>,
+    /// 最近一次在后台写入缓存失败的原因；写入成功后清空。
+    cache_save_error: Arc<Mutex<Option<String>>>,
+    usage: Arc<Mutex<UsageStore>>,
+    usage_settings: UsageSettings,
+    saved: Arc<Mutex<SavedSearches>>,
+    saved_name_input: String,
+    saved_roots_input: String,
+    history: SearchHistory,
+    completions: Arc<Mutex<Completions>>,
+    keymap: Keymap,
+    /// 设置中正在录制快捷键的动作。
+    capturing_action: Option<ResultAction>,
+    /// 结果列表一页的行数，供 PageUp/PageDown 使用。
+    page_rows: usize,
+    scroll_to_selected: bool,
+    rename_dialog: Option<RenameDialog>,
+    properties: Option<PropertiesView>,
+    batch_dialog: Option<BatchDialog>,
+    batch_job: Option<BatchJob>,
+    rename_rules: RenameRules,
+    rename_tool: Option<RenameTool>,
+    /// 可撤销的重命名（含单项 F2 重命名）。
+    rename_log: RenameLog,
+    /// 串行执行后台的索引改动（见 `edit_index`）。
+    index_edit_lock: Arc<Mutex<()>>,
+    /// 后台改动已写入索引，下一帧重新搜索。
+    index_edited: Arc<AtomicBool>,
+    /// 供后台任务完成时请求重绘。
+    repaint_ctx: egui::Context,
+    preview_settings: PreviewSettings,
+    preview_loader: PreviewLoader,
+    preview: Option<Preview>,
+    /// 图片预览的纹理（图片像素上传后不再保留在 `preview` 中）。
+    preview_texture: Option<egui::TextureHandle>,
+    icon_settings: IconSettings,
+    icons: IconCache,
+    /// 最近一次文件操作的结果，显示在状态栏。
+    op_status: Option<String>,
+    hotkey_settings: HotkeySettings,
+    global_hotkey: Option<GlobalHotkey>,
+    /// 全局快捷键不可用或注册失败的原因，显示在设置中。
+    hotkey_error: Option<String>,
+    capturing_hotkey: bool,
+    launcher: QuickLauncher,
+    #[cfg(windows)]
+    tray: Option<crate::windows_tray::Tray>,
+    /// 主窗口已隐藏到托盘。
+    hidden_to_tray: bool,
+    /// 从托盘菜单退出，不再把关闭改为隐藏。
+    quitting: bool,
     #[cfg(windows)]
     is_elevated: Option<bool>,
     #[cfg(windows)]
@@ -65,21 +304,62 @@ impl Default for FileSearchApp {
             searcher: Searcher::new(),
             indexer: Arc::new(Mutex::new(indexer)),
             results: Arc::new(Mutex::new(Vec::new())),
-            selected_result: None,
+            paged: Arc::new(Mutex::new(None)),
+            page_loading: Arc::new(AtomicBool::new(false)),
+            visible_rows: 0..0,
+            facets: Arc::new(Mutex::new(Facets::default())),
+            selection: Selection::default(),
             current_tab: Tab::Search,
             index_paths,
             is_indexing: false,
             index_progress: (0, 0),
             total_files: 0,
-            show_hidden: false,
             window_size: [800.0, 600.0],
-            file_extension: String::new(),
-            file_type_filter: FileTypeFilter::All,
             new_path_input: String::new(),
             index_seq: Arc::new(AtomicU64::new(0)),
             search_seq: Arc::new(AtomicU64::new(0)),
             last_index_time: None,
             index_stats: Arc::new(Mutex::new(None)),
+            cache_settings: CacheSettings::default(),
+            cache_status: None,
+            cache_save_error: Arc::new(Mutex::new(None)),
+            usage: Arc::new(Mutex::new(UsageStore::default())),
+            usage_settings: UsageSettings::default(),
+            saved: Arc::new(Mutex::new(SavedSearches::default())),
+            saved_name_input: String::new(),
+            saved_roots_input: String::new(),
+            history: SearchHistory::default(),
+            completions: Arc::new(Mutex::new(Completions::default())),
+            keymap: Keymap::default(),
+            capturing_action: None,
+            page_rows: 20,
+            scroll_to_selected: false,
+            rename_dialog: None,
+            properties: None,
+            batch_dialog: None,
+            batch_job: None,
+            rename_rules: RenameRules::default(),
+            rename_tool: None,
+            rename_log: RenameLog::default(),
+            index_edit_lock: Arc::new(Mutex::new(())),
+            index_edited: Arc::new(AtomicBool::new(false)),
+            repaint_ctx: egui::Context::default(),
+            preview_settings: PreviewSettings::default(),
+            preview_loader: PreviewLoader::default(),
+            preview: None,
+            preview_texture: None,
+            icon_settings: IconSettings::default(),
+            icons: IconCache::default(),
+            op_status: None,
+            hotkey_settings: HotkeySettings::default(),
+            global_hotkey: None,
+            hotkey_error: None,
+            capturing_hotkey: false,
+            launcher: QuickLauncher::default(),
+            #[cfg(windows)]
+            tray: None,
+            hidden_to_tray: false,
+            quitting: false,
             #[cfg(windows)]
             is_elevated: None,
             #[cfg(windows)]
@@ -91,14 +371,165 @@ impl Default for FileSearchApp {
 }
 
 impl FileSearchApp {
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         let mut app = Self::default();
-        // 不使用本地缓存：启动后直接从 NTFS 的 USN/MFT 枚举构建索引（失败则回退 WalkDir 扫描）。
-        app.rebuild_index();
+        if let Some(storage) = cc.storage {
+            app.cache_settings = eframe::get_value(storage, CACHE_SETTINGS_KEY).unwrap_or_default();
+            app.usage_settings = eframe::get_value(storage, USAGE_SETTINGS_KEY).unwrap_or_default();
+            app.searcher.options.ranking = eframe::get_value(storage, RANKING_PROFILE_KEY).unwrap_or_default();
+            if let Some(categories) = eframe::get_value::<CategoryRegistry>(storage, FILE_CATEGORIES_KEY) {
+                app.searcher.options.categories = Arc::new(categories);
+            }
+            app.saved = Arc::new(Mutex::new(eframe::get_value(storage, SAVED_SEARCHES_KEY).unwrap_or_default()));
+            app.history = eframe::get_value(storage, SEARCH_HISTORY_KEY).unwrap_or_default();
+            app.keymap = eframe::get_value(storage, KEYMAP_KEY).unwrap_or_default();
+            app.rename_rules = eframe::get_value(storage, RENAME_RULES_KEY).unwrap_or_default();
+            app.rename_log = eframe::get_value(storage, RENAME_LOG_KEY).unwrap_or_default();
+            app.preview_settings = eframe::get_value(storage, PREVIEW_SETTINGS_KEY).unwrap_or_default();
+            app.icon_settings = eframe::get_value(storage, ICON_SETTINGS_KEY).unwrap_or_default();
+            app.hotkey_settings = eframe::get_value(storage, HOTKEY_SETTINGS_KEY).unwrap_or_default();
+        }
+        app.repaint_ctx = cc.egui_ctx.clone();
+        app.start_resident(cc);
+        app.usage = Arc::new(Mutex::new(UsageStore::load(
+            Self::data_dir().map(|d| d.join("usage.bin")),
+        )));
+        // 默认不使用本地缓存：启动后直接从 NTFS 的 USN/MFT 枚举构建索引（失败则回退 WalkDir 扫描）。
+        // 启用缓存时先加载缓存让搜索立即可用，再在后台重建。
+        app.start_indexing(app.cache_settings.enabled);
 
         app
     }
 
+    /// 创建托盘图标并注册全局快捷键。两者的回调在主窗口隐藏时也会触发，
+    /// 隐藏的窗口不会重绘，因此先唤起窗口再请求重绘。
+    fn start_resident(&mut self, cc: &eframe::CreationContext<'_>) {
+        #[cfg(windows)]
+        let hwnd = crate::windows_shell::window_hwnd(cc);
+        let ctx = cc.egui_ctx.clone();
+        let wake = move || {
+            #[cfg(windows)]
+            {
+                if let Some(hwnd) = hwnd {
+                    crate::windows_shell::show_window(hwnd);
+                }
+            }
+            ctx.request_repaint();
+        };
+        #[cfg(windows)]
+        {
+            match crate::windows_tray::Tray::new(wake.clone()) {
+                Ok(tray) => self.tray = Some(tray),
+                Err(e) => self.op_status = Some(e),
+            }
+        }
+        match GlobalHotkey::new(wake) {
+            Ok(hotkey) => self.global_hotkey = Some(hotkey),
+            Err(e) => self.hotkey_error = Some(e),
+        }
+        self.apply_hotkey();
+    }
+
+    /// 按设置重新注册全局快捷键。
+    fn apply_hotkey(&mut self) {
+        let Some(hotkey) = self.global_hotkey.as_mut() else {
+            return;
+        };
+        let shortcut = self.hotkey_settings.enabled.then_some(&self.hotkey_settings.shortcut);
+        self.hotkey_error = hotkey.set(shortcut).err();
+    }
+
+    fn can_hide_to_tray(&self) -> bool {
+        #[cfg(windows)]
+        let available = self.tray.is_some();
+
+        #[cfg(not(windows))]
+        let available = false;
+
+        available && self.hotkey_settings.tray && !self.quitting
+    }
+
+    /// 处理全局快捷键、托盘菜单，以及关闭主窗口（启用托盘时改为隐藏，索引与快捷键继续可用）。
+    fn handle_resident_events(&mut self, ctx: &egui::Context) {
+        if self.global_hotkey.as_ref().is_some_and(GlobalHotkey::take_pressed) {
+            if self.launcher.is_open() {
+                self.launcher.close(ctx, false);
+            } else {
+                self.launcher.open(ctx, self.hidden_to_tray);
+            }
+        }
+
+        #[cfg(windows)]
+        {
+            use crate::windows_tray::TrayCommand;
+            let commands = self.tray.as_ref().map(|t| t.take_commands()).unwrap_or_default();
+            for command in commands {
+                match command {
+                    TrayCommand::ShowMain => {
+                        self.launcher.close(ctx, true);
+                        self.hidden_to_tray = false;
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                    }
+                    TrayCommand::QuickSearch => self.launcher.open(ctx, self.hidden_to_tray),
+                    TrayCommand::Quit => {
+                        self.launcher.close(ctx, true);
+                        self.quitting = true;
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                    }
+                }
+            }
+        }
+
+        if ctx.input(|i| i.viewport().close_requested()) && self.can_hide_to_tray() {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            self.launcher.close(ctx, false);
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false));
+            self.hidden_to_tray = true;
+        }
+    }
+
+    /// 快速搜索窗口：打开或定位后恢复主窗口原来的状态，Tab 则在主窗口中显示全部结果。
+    fn show_launcher(&mut self, ctx: &egui::Context) {
+        match self.launcher.ui(ctx) {
+            Some(LauncherEvent::Search) => self.launcher.search(ctx, &self.indexer, self.query_searcher()),
+            Some(LauncherEvent::Open(path)) => {
+                self.launcher.close(ctx, false);
+                self.open_result(&path);
+            }
+            Some(LauncherEvent::Reveal(path)) => {
+                self.launcher.close(ctx, false);
+                self.reveal_result(&path);
+            }
+            Some(LauncherEvent::ShowAll(query)) => {
+                self.launcher.close(ctx, true);
+                self.hidden_to_tray = false;
+                self.search_text = query;
+                self.current_tab = Tab::Search;
+                self.perform_search();
+            }
+            None => {}
+        }
+    }
+
+    /// 本地数据目录：Windows 为 `%LOCALAPPDATA%\RustSearch`，其他平台为 `~/.cache/rust_search`。
+    fn data_dir() -> Option<PathBuf> {
+        #[cfg(windows)]
+        let dir = std::env::var_os("LOCALAPPDATA").map(|d| PathBuf::from(d).join("RustSearch"));
+
+        #[cfg(not(windows))]
+        let dir = std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))
+            .map(|d| d.join("rust_search"));
+
+        dir
+    }
+
+    fn cache_path() -> Option<PathBuf> {
+        Self::data_dir().map(|d| d.join("index.rsix"))
+    }
+
     fn default_index_paths() -> Vec<PathBuf> {
         #[cfg(windows)]
         {
@@ -144,128 +575,1135 @@ impl FileSearchApp {
         let _ = std::process::Command::new("xdg-open").arg(&open_path).spawn();
     }
 
-    fn rebuild_index(&mut self) {
-        let indexer = Arc::clone(&self.indexer);
-        let paths = self.index_paths.clone();
-        let index_seq = Arc::clone(&self.index_seq);
-        let seq = index_seq.fetch_add(1, Ordering::SeqCst) + 1;
-        let index_stats = Arc::clone(&self.index_stats);
-
-        let handles = {
-            let indexer_guard = indexer.lock().unwrap();
-            indexer_guard.begin_indexing();
-            indexer_guard.handles()
+    /// 在文件管理器中显示（选中）该路径。
+    fn reveal_path_in_os(path: &str) {
+        if cfg!(windows) {
+            let _ = std::process::Command::new("explorer")
+                .arg(format!("/select,{}", path.replace("/", "\\")))
+                .spawn();
+            return;
+        }
+        if cfg!(target_os = "macos") {
+            let _ = std::process::Command::new("open").args(["-R", path]).spawn();
+            return;
+        }
+        let parent = std::path::Path::new(path).parent().unwrap_or(std::path::Path::new("/"));
+        let _ = opener::open(parent);
+    }
+
+    fn open_result(&self, path: &str) {
+        Self::open_path_in_os(path);
+        self.record_usage(path, UsageKind::Open);
+    }
+
+    fn reveal_result(&self, path: &str) {
+        Self::reveal_path_in_os(path);
+        self.record_usage(path, UsageKind::Reveal);
+    }
+
+    fn record_usage(&self, path: &str, kind: UsageKind) {
+        if !self.usage_settings.enabled {
+            return;
+        }
+        let _ = self.usage.lock().unwrap().record(path, kind, now_ms());
+    }
+
+    fn rebuild_index(&mut self) {
+        self.start_indexing(false);
+    }
+
+    fn start_indexing(&mut self, load_cache: bool) {
+        let indexer = Arc::clone(&self.indexer);
+        let paths = self.index_paths.clone();
+        let index_seq = Arc::clone(&self.index_seq);
+        let seq = index_seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let index_stats = Arc::clone(&self.index_stats);
+        let cache_path = Self::cache_path().filter(|_| self.cache_settings.enabled);
+        let cache_codec = self.cache_settings.codec;
+        let cache_save_error = Arc::clone(&self.cache_save_error);
+
+        let handles = {
+            let indexer_guard = indexer.lock().unwrap();
+            indexer_guard.begin_indexing();
+            indexer_guard.handles()
+        };
+
+        thread::spawn(move || {
+            #[cfg(windows)]
+            {
+                let _ = crate::windows_usn::try_enable_usn_privileges();
+            }
+            if let Some(Ok((entries, usn_states))) = cache_path
+                .as_deref()
+                .filter(|_| load_cache)
+                .map(FileIndexer::load_cache)
+            {
+                let mut indexer_guard = indexer.lock().unwrap();
+                if index_seq.load(Ordering::SeqCst) == seq {
+                    indexer_guard.set_cache(entries, usn_states);
+                    indexer_guard.begin_indexing();
+                }
+            }
+            let (entries, usn_states, stats) =
+                FileIndexer::build_index_snapshot_with_stats(paths, Some(&handles));
+            if index_seq.load(Ordering::SeqCst) != seq {
+                return;
+            }
+            let snapshot = {
+                // 先更新内存索引，让搜索尽快可用；缓存写入放到后面，不阻塞“索引完成”的体验
+                let mut indexer_guard = indexer.lock().unwrap();
+                indexer_guard.replace_index(entries, usn_states);
+                (indexer_guard.entries_arc(), indexer_guard.usn_states_clone())
+            };
+            *index_stats.lock().unwrap() = Some(stats);
+            if let Some(path) = cache_path {
+                *cache_save_error.lock().unwrap() = FileIndexer::save_cache(&path, &snapshot.0, &snapshot.1, cache_codec)
+                    .err()
+                    .map(|e| format!("缓存保存失败: {e}"));
+            }
+        });
+        self.last_index_time = Some(SystemTime::now());
+    }
+
+    /// 按当前选项与使用记录构造一次搜索用的 `Searcher`。
+    fn query_searcher(&self) -> Searcher {
+        let mut searcher = Searcher::new();
+        searcher.set_options(self.searcher.options.clone());
+        if self.usage_settings.enabled {
+            searcher.set_frecency(Some(Arc::new(self.usage.lock().unwrap().frecency_table(now_ms()))));
+        }
+        searcher
+    }
+
+    /// 重新计算某个结果的得分明细；索引正在被占用（重建或搜索中）时返回 `None`。
+    fn explain_result(&self, index: usize) -> Option<ScoreExplanation> {
+        let indexer = self.indexer.try_lock().ok()?;
+        self.query_searcher().explain(&indexer, &self.search_text, index)
+    }
+
+    fn perform_search(&mut self) {
+        self.history.push(&self.search_text);
+        let search_text = self.search_text.clone();
+        let indexer = Arc::clone(&self.indexer);
+        let results = Arc::clone(&self.results);
+        let paged = Arc::clone(&self.paged);
+        let facets = Arc::clone(&self.facets);
+        let search_seq = Arc::clone(&self.search_seq);
+        let seq = search_seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let searcher = self.query_searcher();
+
+        thread::spawn(move || {
+            let indexer_guard = indexer.lock().unwrap();
+            let (hits, search_facets) = searcher.search_hits(&indexer_guard, &search_text);
+            let first_page = searcher.page(&indexer_guard, &hits, 0..RESULT_PAGE_SIZE).unwrap_or_default();
+            drop(indexer_guard);
+
+            if search_seq.load(Ordering::SeqCst) != seq {
+                return;
+            }
+
+            let mut results_guard = results.lock().unwrap();
+            *results_guard = first_page;
+            *paged.lock().unwrap() = Some(Arc::new(PagedSearch { seq, searcher, hits }));
+            *facets.lock().unwrap() = search_facets;
+        });
+        self.selection.clear();
+    }
+
+    /// 全部命中数（尚未全部生成结果）。
+    fn total_results(&self) -> usize {
+        let loaded = self.results.lock().unwrap().len();
+        self.paged.lock().unwrap().as_ref().map_or(loaded, |p| p.hits.len().max(loaded))
+    }
+
+    /// 结果列表需要显示到第 `upto` 行时，在后台为尚未生成的命中生成结果（整页取）。
+    /// 索引在搜索之后被替换时不再继续，列表只保留已生成的部分。
+    fn load_result_pages(&self, ctx: &egui::Context, upto: usize) {
+        let Some(paged) = self.paged.lock().unwrap().clone() else {
+            return;
+        };
+        let loaded = self.results.lock().unwrap().len();
+        let end = upto.next_multiple_of(RESULT_PAGE_SIZE).min(paged.hits.len());
+        if loaded >= end || self.page_loading.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let indexer = Arc::clone(&self.indexer);
+        let results = Arc::clone(&self.results);
+        let current = Arc::clone(&self.paged);
+        let search_seq = Arc::clone(&self.search_seq);
+        let page_loading = Arc::clone(&self.page_loading);
+        let ctx = ctx.clone();
+        thread::spawn(move || {
+            let page = paged.searcher.page(&indexer.lock().unwrap(), &paged.hits, loaded..end);
+            if search_seq.load(Ordering::SeqCst) == paged.seq {
+                let mut results = results.lock().unwrap();
+                match page {
+                    Some(page) if results.len() == loaded => results.extend(page),
+                    Some(_) => {}
+                    None => *current.lock().unwrap() = None,
+                }
+            }
+            page_loading.store(false, Ordering::SeqCst);
+            ctx.request_repaint();
+        });
+    }
+
+    /// 保存的搜索列表：单击执行，实时搜索在有新结果时显示角标。
+    fn show_saved_searches(&mut self, ui: &mut egui::Ui) {
+        ui.heading("保存的搜索");
+        let mut run = None;
+        let mut remove = None;
+        {
+            let mut saved = self.saved.lock().unwrap();
+            for (idx, search) in saved.searches.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    let mut label = search.name.clone();
+                    if search.new_matches > 0 {
+                        label.push_str(&format!(" (+{})", search.new_matches));
+                    }
+                    let hint = match idx {
+                        0..9 => format!("{}\nCtrl+{}", search.query, idx + 1),
+                        _ => search.query.clone(),
+                    };
+                    if ui.button(label).on_hover_text(hint).clicked() {
+                        run = Some(idx);
+                    }
+                    ui.checkbox(&mut search.live, "实时")
+                        .on_hover_text("索引更新后自动重跑，并提示新出现的结果");
+                    if ui.small_button("x").clicked() {
+                        remove = Some(idx);
+                    }
+                });
+            }
+            if let Some(idx) = remove {
+                saved.searches.remove(idx);
+            }
+        }
+        if let Some(idx) = run {
+            self.run_saved_search(idx);
+        }
+
+        ui.separator();
+        ui.label("名称:");
+        ui.text_edit_singleline(&mut self.saved_name_input);
+        ui.label("限定目录（用 ; 分隔，可留空）:");
+        ui.text_edit_singleline(&mut self.saved_roots_input);
+        let can_save = !self.saved_name_input.trim().is_empty() && !self.search_text.trim().is_empty();
+        if ui.add_enabled(can_save, egui::Button::new("保存当前搜索")).clicked() {
+            let mut options = self.searcher.options.clone();
+            options.filters.roots = self
+                .saved_roots_input
+                .split(';')
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .map(str::to_string)
+                .collect();
+            let name = self.saved_name_input.trim().to_string();
+            self.saved
+                .lock()
+                .unwrap()
+                .save(SavedSearch::new(name, self.search_text.clone(), options));
+            self.saved_name_input.clear();
+            self.saved_roots_input.clear();
+        }
+    }
+
+    /// 载入保存的查询与选项（沿用当前的排序权重与分类表）并执行。
+    fn run_saved_search(&mut self, idx: usize) {
+        let Some((query, mut options)) = self.saved.lock().unwrap().searches.get_mut(idx).map(|s| {
+            s.mark_opened();
+            (s.query.clone(), s.options.clone())
+        }) else {
+            return;
+        };
+        options.ranking = self.searcher.options.ranking.clone();
+        options.categories = Arc::clone(&self.searcher.options.categories);
+        self.search_text = query;
+        self.searcher.options = options;
+        self.current_tab = Tab::Search;
+        self.perform_search();
+    }
+
+    /// 索引更新后在后台重跑全部实时搜索，统计新出现的结果。
+    fn refresh_live_searches(&self) {
+        let live: Vec<(String, String, crate::searcher::SearchOptions)> = self
+            .saved
+            .lock()
+            .unwrap()
+            .searches
+            .iter()
+            .filter(|s| s.live)
+            .map(|s| (s.name.clone(), s.query.clone(), s.options.clone()))
+            .collect();
+        if live.is_empty() {
+            return;
+        }
+        let indexer = Arc::clone(&self.indexer);
+        let saved = Arc::clone(&self.saved);
+        let ranking = self.searcher.options.ranking.clone();
+        let categories = Arc::clone(&self.searcher.options.categories);
+        thread::spawn(move || {
+            for (name, query, mut options) in live {
+                options.ranking = ranking.clone();
+                options.categories = Arc::clone(&categories);
+                let mut searcher = Searcher::new();
+                searcher.set_options(options);
+                let paths: Vec<String> = {
+                    let indexer_guard = indexer.lock().unwrap();
+                    searcher
+                        .search(&indexer_guard, &query)
+                        .into_iter()
+                        .map(|r| r.display_path)
+                        .collect()
+                };
+                if let Some(search) = saved.lock().unwrap().get_mut(&name) {
+                    search.observe(paths);
+                }
+            }
+        });
+    }
+
+    /// 选中结果的独立副本（不持有结果列表的锁），供文件操作使用。
+    fn selected_target(&self) -> Option<ResultTarget> {
+        let results = self.results.lock().unwrap();
+        results.get(self.selection.cursor()?).map(ResultTarget::from_result)
+    }
+
+    /// 全部选中结果的独立副本（按结果顺序）。
+    fn selected_targets(&self) -> Vec<ResultTarget> {
+        let results = self.results.lock().unwrap();
+        self.selection
+            .indices()
+            .filter_map(|idx| results.get(idx))
+            .map(ResultTarget::from_result)
+            .collect()
+    }
+
+    /// 执行结果列表的动作（快捷键与右键菜单共用）：导航类移动选中项（按住 Shift 时连续多选），
+    /// 多选时复制、删除、重命名作用于全部选中项，其余作用于当前项。
+    fn run_result_action(&mut self, ctx: &egui::Context, action: ResultAction) {
+        let len = self.results.lock().unwrap().len();
+        if action == ResultAction::SelectAll {
+            self.selection.select_all(len);
+            return;
+        }
+        if let Some(selected) = action.move_selection(self.selection.cursor(), len, self.page_rows) {
+            if ctx.input(|i| i.modifiers.shift) {
+                self.selection.extend_to(selected);
+            } else {
+                self.selection.select(selected);
+            }
+            self.scroll_to_selected = true;
+            return;
+        }
+        if self.selection.len() > 1 && self.run_batch_action(ctx, action) {
+            return;
+        }
+        let Some(target) = self.selected_target() else {
+            return;
+        };
+        let result = match action {
+            ResultAction::Open => {
+                self.open_result(&target.path);
+                Ok(())
+            }
+            ResultAction::RevealInFolder => {
+                self.reveal_result(&target.path);
+                Ok(())
+            }
+            ResultAction::CopyPath => {
+                ctx.copy_text(target.path);
+                Ok(())
+            }
+            ResultAction::CopyName => {
+                ctx.copy_text(target.name);
+                Ok(())
+            }
+            ResultAction::CopyAsFile => {
+                match file_ops::copy_files_to_clipboard(std::slice::from_ref(&target.path)) {
+                    Err(e) if e.kind() == std::io::ErrorKind::Unsupported => {
+                        ctx.copy_text(file_ops::file_uri(&target.path));
+                        Ok(())
+                    }
+                    other => other,
+                }
+            }
+            ResultAction::OpenWith => file_ops::open_with(&target.path),
+            ResultAction::ShellMenu => file_ops::show_context_menu(&target.path),
+            ResultAction::Properties => match file_ops::show_properties(&target.path) {
+                Err(e) if e.kind() == std::io::ErrorKind::Unsupported => {
+                    self.properties = Some(PropertiesView::read(&target));
+                    Ok(())
+                }
+                other => other,
+            },
+            ResultAction::SearchInFolder => {
+                let folder = if target.is_dir {
+                    target.path
+                } else {
+                    target.path.rsplit_once('/').map_or(target.path.clone(), |(parent, _)| parent.to_string())
+                };
+                self.searcher.options.filters.folder = Some(folder);
+                self.perform_search();
+                Ok(())
+            }
+            ResultAction::MoveToTrash => {
+                let trashed = file_ops::move_to_trash(&target.path);
+                if trashed.is_ok() {
+                    let index = target.index;
+                    self.edit_index(target.generation, move |store| store.mark_removed(&[index]));
+                    self.op_status = Some(format!("已移到回收站: {}", target.path));
+                }
+                trashed
+            }
+            ResultAction::Rename => {
+                let input = target.name.clone();
+                self.rename_dialog = Some(RenameDialog {
+                    target,
+                    input,
+                    error: None,
+                });
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            self.op_status = Some(format!("{}失败: {e}", action.label()));
+        }
+    }
+
+    /// 文件操作成功后就地改写索引中的条目，随后按当前查询重新搜索（保留选中位置）。
+    /// 改动在后台线程中等待索引锁，不阻塞界面；多次改动按提交顺序串行执行。
+    /// `generation` 之后索引被重建过时下标不再对应，放弃改动，交给下次重建。
+    fn edit_index(&self, generation: u64, apply: impl FnOnce(&mut EntryStore) + Send + 'static) {
+        let indexer = Arc::clone(&self.indexer);
+        let edit_lock = Arc::clone(&self.index_edit_lock);
+        let edited = Arc::clone(&self.index_edited);
+        let ctx = self.repaint_ctx.clone();
+        thread::spawn(move || {
+            let _serial = edit_lock.lock().unwrap();
+            if indexer.lock().unwrap().edit(generation, apply) {
+                edited.store(true, Ordering::SeqCst);
+                ctx.request_repaint();
+            }
+        });
+    }
+
+    /// 多选时作用于全部选中项的动作；返回 `false` 表示该动作只作用于当前项。
+    fn run_batch_action(&mut self, ctx: &egui::Context, action: ResultAction) -> bool {
+        let targets = self.selected_targets();
+        match action {
+            ResultAction::CopyPath | ResultAction::CopyName => {
+                let lines: Vec<&str> = targets
+                    .iter()
+                    .map(|t| if action == ResultAction::CopyPath { &t.path } else { &t.name })
+                    .map(String::as_str)
+                    .collect();
+                ctx.copy_text(lines.join("\n"));
+                self.op_status = Some(format!("已复制 {} 项", lines.len()));
+            }
+            ResultAction::CopyAsFile => {
+                let paths: Vec<String> = targets.into_iter().map(|t| t.path).collect();
+                match file_ops::copy_files_to_clipboard(&paths) {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == std::io::ErrorKind::Unsupported => {
+                        let uris: Vec<String> = paths.iter().map(|p| file_ops::file_uri(p)).collect();
+                        ctx.copy_text(uris.join("\n"));
+                    }
+                    Err(e) => self.op_status = Some(format!("{}失败: {e}", action.label())),
+                }
+            }
+            ResultAction::MoveToTrash => self.start_batch(BatchOp::Trash, targets),
+            ResultAction::Rename => self.open_rename_tool(targets),
+            _ => return false,
+        }
+        true
+    }
+
+    fn open_batch_dialog(&mut self, kind: BatchDialogKind, targets: Vec<ResultTarget>) {
+        let input = match kind {
+            BatchDialogKind::CopyTo | BatchDialogKind::MoveTo => String::new(),
+            BatchDialogKind::Export => "搜索结果.csv".to_string(),
+        };
+        self.batch_dialog = Some(BatchDialog { kind, targets, input });
+    }
+
+    /// 在后台执行批量操作；结果列表中的条目都来自同一个索引版本。
+    fn start_batch(&mut self, op: BatchOp, targets: Vec<ResultTarget>) {
+        let Some(generation) = targets.first().map(|t| t.generation) else {
+            return;
+        };
+        let items = targets
+            .into_iter()
+            .map(|t| BatchItem {
+                path: t.path,
+                index: t.index,
+                name: t.name,
+                is_dir: t.is_dir,
+                size: t.size,
+                modified_ms: t.modified_ms,
+            })
+            .collect();
+        self.batch_job = Some(BatchJob::start(op, items, generation));
+    }
+
+    /// 多选时的批量操作栏。
+    fn show_batch_bar(&mut self, ui: &mut egui::Ui) {
+        let mut action = None;
+        let mut dialog = None;
+        ui.horizontal(|ui| {
+            ui.label(format!("已选 {} 项", self.selection.len()));
+            ui.add_enabled_ui(self.batch_job.is_none(), |ui| {
+                if ui.button("复制路径").clicked() {
+                    action = Some(ResultAction::CopyPath);
+                }
+                if ui.button("复制到…").clicked() {
+                    dialog = Some(BatchDialogKind::CopyTo);
+                }
+                if ui.button("移动到…").clicked() {
+                    dialog = Some(BatchDialogKind::MoveTo);
+                }
+                if ui.button("移到回收站").clicked() {
+                    action = Some(ResultAction::MoveToTrash);
+                }
+                if ui.button("批量重命名…").clicked() {
+                    action = Some(ResultAction::Rename);
+                }
+                if ui.button("导出…").clicked() {
+                    dialog = Some(BatchDialogKind::Export);
+                }
+            });
+            if ui.button("取消选择").clicked() {
+                self.selection.clear();
+            }
+        });
+        if let Some(action) = action {
+            self.run_batch_action(ui.ctx(), action);
+        }
+        if let Some(kind) = dialog {
+            let targets = self.selected_targets();
+            self.open_batch_dialog(kind, targets);
+        }
+    }
+
+    /// 批量操作的参数对话框。
+    fn show_batch_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = self.batch_dialog.as_mut() else {
+            return;
+        };
+        let mut open = true;
+        let mut cancelled = false;
+        let mut confirmed = false;
+        egui::Window::new(dialog.kind.label())
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!("共 {} 项", dialog.targets.len()));
+                let hint = match dialog.kind {
+                    BatchDialogKind::CopyTo | BatchDialogKind::MoveTo => "目标文件夹",
+                    BatchDialogKind::Export => "CSV 文件路径",
+                };
+                ui.add(egui::TextEdit::singleline(&mut dialog.input).hint_text(hint).desired_width(360.0));
+                ui.horizontal(|ui| {
+                    let ready = !dialog.input.trim().is_empty();
+                    confirmed = ui.add_enabled(ready, egui::Button::new("确定")).clicked();
+                    cancelled = ui.button("取消").clicked();
+                });
+            });
+        if !open || cancelled || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.batch_dialog = None;
+            return;
+        }
+        if !confirmed {
+            return;
+        }
+        let Some(dialog) = self.batch_dialog.take() else {
+            return;
+        };
+        let input = dialog.input.trim().to_string();
+        let op = match dialog.kind {
+            BatchDialogKind::CopyTo => BatchOp::CopyTo(input),
+            BatchDialogKind::MoveTo => BatchOp::MoveTo(input),
+            BatchDialogKind::Export => BatchOp::ExportCsv(input),
+        };
+        self.start_batch(op, dialog.targets);
+    }
+
+    /// 批量操作的进度窗口：进行中可取消，结束后列出失败的项；结束时把结果写回索引。
+    fn show_batch_progress(&mut self, ctx: &egui::Context) {
+        let Some(job) = self.batch_job.as_mut() else {
+            return;
+        };
+        let (done, finished, errors) = {
+            let progress = job.progress.lock().unwrap();
+            (progress.done, progress.finished, progress.errors.clone())
+        };
+        let apply = finished && !job.applied;
+        job.applied |= finished;
+
+        let mut close = false;
+        egui::Window::new(job.title)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                let fraction = done as f32 / job.total.max(1) as f32;
+                ui.add(egui::ProgressBar::new(fraction).text(format!("{done} / {}", job.total)));
+                if !finished {
+                    if ui.button("取消").clicked() {
+                        job.cancel.store(true, Ordering::Relaxed);
+                    }
+                    return;
+                }
+                ui.label(format!("成功 {} 项，失败 {} 项", done.saturating_sub(errors.len()), errors.len()));
+                if done < job.total {
+                    ui.label(format!("已取消，剩余 {} 项未处理", job.total - done));
+                }
+                if !errors.is_empty() {
+                    egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                        for (path, message) in &errors {
+                            ui.colored_label(egui::Color32::RED, format!("{path}: {message}"));
+                        }
+                    });
+                }
+                close = ui.button("关闭").clicked();
+            });
+        if !finished {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+        if apply {
+            self.apply_batch();
+        }
+        if close {
+            self.batch_job = None;
+        }
+    }
+
+    /// 把批量操作成功的项写回索引（与单项操作一样要求索引版本未变，见 `edit_index`）。
+    /// 移动或复制的新位置已在后台扫描（目录连同子项），这里只追加扫描结果并标记原条目删除。
+    fn apply_batch(&mut self) {
+        let Some(job) = &self.batch_job else {
+            return;
+        };
+        let (outcomes, scanned) = {
+            let mut progress = job.progress.lock().unwrap();
+            (std::mem::take(&mut progress.outcomes), std::mem::take(&mut progress.scanned))
+        };
+        if outcomes.is_empty() {
+            return;
+        }
+        self.edit_index(job.generation, move |store| {
+            let removed: Vec<usize> = outcomes
+                .iter()
+                .filter(|(_, outcome)| matches!(outcome, Outcome::Removed | Outcome::Moved(_)))
+                .map(|(index, _)| *index)
+                .collect();
+            store.append_walked(&scanned);
+            store.mark_removed(&removed);
+        });
+    }
+
+    /// 重命名对话框：成功后同步改写索引中的条目并重新搜索。
+    fn show_rename_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = self.rename_dialog.as_mut() else {
+            return;
+        };
+        let mut open = true;
+        let mut cancelled = false;
+        let mut confirmed = false;
+        egui::Window::new("重命名")
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(&dialog.target.path);
+                let response = ui.text_edit_singleline(&mut dialog.input);
+                if !response.has_focus() && !response.lost_focus() {
+                    response.request_focus();
+                }
+                confirmed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                if let Some(error) = &dialog.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+                ui.horizontal(|ui| {
+                    confirmed |= ui.button("确定").clicked();
+                    cancelled = ui.button("取消").clicked();
+                });
+            });
+        if !open || cancelled || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.rename_dialog = None;
+            return;
+        }
+        if !confirmed {
+            return;
+        }
+        match file_ops::rename(&dialog.target.path, &dialog.input) {
+            Ok(new_path) => {
+                let new_name = dialog.input.trim().to_string();
+                let Some(dialog) = self.rename_dialog.take() else {
+                    return;
+                };
+                let index = dialog.target.index;
+                self.edit_index(dialog.target.generation, move |store| store.rename(index, &new_name));
+                self.rename_log.push(RenameBatch {
+                    time_ms: now_ms(),
+                    records: vec![RenameRecord {
+                        index: dialog.target.index,
+                        old_path: dialog.target.path,
+                        new_path: new_path.clone(),
+                    }],
+                });
+                self.op_status = Some(format!("已重命名为: {new_path}"));
+            }
+            Err(e) => dialog.error = Some(format!("重命名失败: {e}")),
+        }
+    }
+
+    fn open_rename_tool(&mut self, targets: Vec<ResultTarget>) {
+        let sources = targets
+            .into_iter()
+            .map(|t| RenameSource {
+                // USN 条目不带修改时间，这里读取真实值
+                modified_ms: std::fs::metadata(&t.path)
+                    .map(|m| crate::indexer::modified_ms(&m))
+                    .ok()
+                    .filter(|&ms| ms != 0),
+                index: t.index,
+                path: t.path,
+                name: t.name,
+            })
+            .collect();
+        self.rename_tool = Some(RenameTool {
+            sources,
+            planned: None,
+            plan: Ok(RenamePlan::default()),
+            error: None,
+        });
+    }
+
+    /// 批量重命名工具：编辑规则时实时预览，有冲突时不能执行；执行后可从状态栏撤销。
+    fn show_rename_tool(&mut self, ctx: &egui::Context) {
+        let Some(tool) = self.rename_tool.as_mut() else {
+            return;
+        };
+        let rules = &mut self.rename_rules;
+        if tool.planned.as_ref() != Some(&*rules) {
+            tool.plan = RenamePlan::build(rules, &tool.sources);
+            tool.planned = Some(rules.clone());
+            tool.error = None;
+        }
+
+        let mut open = true;
+        let mut cancelled = false;
+        let mut confirmed = false;
+        egui::Window::new("批量重命名")
+            .collapsible(false)
+            .default_width(560.0)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                egui::Grid::new("rename_rules").num_columns(2).show(ui, |ui| {
+                    ui.label("查找:");
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut rules.find);
+                        ui.checkbox(&mut rules.regex, "正则");
+                        ui.checkbox(&mut rules.case_sensitive, "区分大小写");
+                    });
+                    ui.end_row();
+                    ui.label("替换为:");
+                    ui.text_edit_singleline(&mut rules.replace)
+                        .on_hover_text("正则模式下可用 $1、${name} 引用捕获组");
+                    ui.end_row();
+                    ui.label("模板:");
+                    ui.text_edit_singleline(&mut rules.template).on_hover_text(
+                        "{name} 主名，{ext} 扩展名，{n} 序号，{n:3} 补零序号，\n{date} 修改日期，{date:%Y-%m-%d} 自定义日期格式",
+                    );
+                    ui.end_row();
+                    ui.label("序号:");
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut rules.start).prefix("起始 "));
+                        ui.add(egui::DragValue::new(&mut rules.step).range(1..=1000).prefix("步长 "));
+                    });
+                    ui.end_row();
+                    ui.label("大小写:");
+                    egui::ComboBox::from_id_salt("rename_case")
+                        .selected_text(rules.case.label())
+                        .show_ui(ui, |ui| {
+                            for case in CaseChange::ALL {
+                                ui.selectable_value(&mut rules.case, case, case.label());
+                            }
+                        });
+                    ui.end_row();
+                });
+                ui.separator();
+
+                match &tool.plan {
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::RED, e);
+                    }
+                    Ok(plan) => {
+                        ui.label(format!(
+                            "共 {} 项，将重命名 {} 项，冲突 {} 项",
+                            plan.items.len(),
+                            plan.ready().count(),
+                            plan.problems()
+                        ));
+                        let row_height = ui.text_style_height(&egui::TextStyle::Body);
+                        egui::ScrollArea::vertical()
+                            .max_height(320.0)
+                            .auto_shrink([false, true])
+                            .show_rows(ui, row_height, plan.items.len(), |ui, rows| {
+                                for item in &plan.items[rows] {
+                                    ui.horizontal(|ui| {
+                                        ui.label(&item.old_name);
+                                        match &item.status {
+                                            RenameStatus::Ready => ui.label(format!("→ {}", item.new_name)),
+                                            RenameStatus::Unchanged => ui.weak("（不变）"),
+                                            RenameStatus::Problem(problem) => ui.colored_label(
+                                                egui::Color32::RED,
+                                                format!("→ {}（{problem}）", item.new_name),
+                                            ),
+                                        };
+                                    });
+                                }
+                            });
+                    }
+                }
+                if let Some(error) = &tool.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+                ui.horizontal(|ui| {
+                    let ready = tool.plan.as_ref().is_ok_and(RenamePlan::can_apply);
+                    confirmed = ui.add_enabled(ready, egui::Button::new("重命名")).clicked();
+                    cancelled = ui.button("取消").clicked();
+                });
+            });
+        if !open || cancelled || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.rename_tool = None;
+            return;
+        }
+        if !confirmed {
+            return;
+        }
+        let Ok(plan) = &tool.plan else {
+            return;
+        };
+        match apply_plan(plan, now_ms()) {
+            Ok(batch) => {
+                self.rename_tool = None;
+                let renames: Vec<(usize, &str, &str)> = batch
+                    .records
+                    .iter()
+                    .map(|r| (r.index, r.old_path.as_str(), r.new_path.as_str()))
+                    .collect();
+                self.rename_entries(&renames);
+                self.op_status = Some(format!("已重命名 {} 项", batch.records.len()));
+                self.rename_log.push(batch);
+            }
+            Err(e) => tool.error = Some(format!("重命名失败，已撤回完成的部分: {e}")),
+        }
+    }
+
+    /// 撤销最近一批重命名。
+    fn undo_last_rename(&mut self) {
+        let Some(batch) = self.rename_log.last() else {
+            return;
+        };
+        match undo_batch(batch) {
+            Ok(()) => {
+                let renames: Vec<(usize, &str, &str)> = batch
+                    .records
+                    .iter()
+                    .map(|r| (r.index, r.new_path.as_str(), r.old_path.as_str()))
+                    .collect();
+                let count = renames.len();
+                self.rename_entries(&renames);
+                self.rename_log.pop();
+                self.op_status = Some(format!("已撤销 {count} 项重命名"));
+            }
+            Err(e) => self.op_status = Some(format!("撤销重命名失败: {e}")),
+        }
+    }
+
+    /// 改写索引中被重命名的条目。`(下标, 原路径, 新路径)`：先按路径核对下标仍指向同一文件
+    /// （期间重建过索引、或撤销日志来自上次运行时可能不再对应），不对应的交给下次重建。
+    fn rename_entries(&self, renames: &[(usize, &str, &str)]) {
+        let (generation, valid) = {
+            let indexer = self.indexer.lock().unwrap();
+            let valid: Vec<(usize, String)> = renames
+                .iter()
+                .filter(|(index, from, _)| indexer.display_path_for(*index) == *from)
+                .filter_map(|(index, _, to)| Some((*index, to.rsplit('/').next()?.to_string())))
+                .collect();
+            (indexer.generation(), valid)
+        };
+        if valid.is_empty() {
+            return;
+        }
+        self.edit_index(generation, move |store| {
+            for (index, name) in valid {
+                store.rename(index, &name);
+            }
+        });
+    }
+
+    /// 跟随当前项请求预览，并接收后台加载完成的结果。
+    fn update_preview(&mut self, ctx: &egui::Context) {
+        match self.selected_target() {
+            Some(target) => self.preview_loader.request(ctx, &target.path, target.is_dir),
+            None => {
+                self.preview_loader.clear();
+                self.preview = None;
+                self.preview_texture = None;
+            }
+        }
+        if let Some(mut preview) = self.preview_loader.take_ready() {
+            self.preview_texture = match &mut preview.content {
+                PreviewContent::Image { image, .. } => {
+                    Some(ctx.load_texture("preview", std::mem::take(image), egui::TextureOptions::LINEAR))
+                }
+                _ => None,
+            };
+            self.preview = Some(preview);
+        }
+    }
+
+    /// 预览面板：上方为内容，下方为元数据。
+    fn show_preview_panel(&self, ui: &mut egui::Ui) {
+        let Some(preview) = &self.preview else {
+            let hint = match self.preview_loader.requested() {
+                Some(_) => "正在加载…",
+                None => "选择一个结果以预览",
+            };
+            ui.weak(hint);
+            return;
+        };
+        egui::TopBottomPanel::bottom("preview_metadata").show_inside(ui, |ui| {
+            egui::Grid::new("preview_metadata_grid").num_columns(2).striped(true).show(ui, |ui| {
+                for (label, value) in &preview.metadata {
+                    ui.label(*label);
+                    ui.add(egui::Label::new(value).wrap());
+                    ui.end_row();
+                }
+            });
+        });
+        if self.preview_loader.requested() != Some(preview.path.as_str()) {
+            ui.spinner();
+        }
+        egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| match &preview.content {
+            PreviewContent::Image { original, .. } => {
+                if let Some(texture) = &self.preview_texture {
+                    ui.add(egui::Image::new(texture).max_width(ui.available_width()));
+                }
+                ui.weak(format!("{} × {}", original.0, original.1));
+            }
+            PreviewContent::Text {
+                text,
+                language,
+                truncated,
+            } => {
+                let theme = egui_extras::syntax_highlighting::CodeTheme::from_memory(ui.ctx(), ui.style());
+                egui_extras::syntax_highlighting::code_view_ui(ui, &theme, text, language);
+                if *truncated {
+                    ui.weak("（文件较大，仅显示开头部分）");
+                }
+            }
+            PreviewContent::Hex { dump, truncated } => {
+                ui.monospace(dump);
+                if *truncated {
+                    ui.weak("（仅显示开头部分）");
+                }
+            }
+            PreviewContent::Folder { entries, total } => {
+                ui.weak(format!("共 {total} 项"));
+                for (name, is_dir) in entries {
+                    ui.label(format!("{} {name}", if *is_dir { "📁" } else { "📄" }));
+                }
+                if *total > entries.len() {
+                    ui.weak(format!("（仅列出前 {} 项）", entries.len()));
+                }
+            }
+            PreviewContent::Message(message) => {
+                ui.weak(message);
+            }
+        });
+    }
+
+    /// 非 Windows 平台的“属性”窗口。
+    fn show_properties_window(&mut self, ctx: &egui::Context) {
+        let Some(view) = &self.properties else {
+            return;
         };
+        let mut open = true;
+        egui::Window::new("属性")
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                egui::Grid::new("properties").num_columns(2).show(ui, |ui| {
+                    for (label, value) in &view.rows {
+                        ui.label(*label);
+                        ui.label(value);
+                        ui.end_row();
+                    }
+                });
+            });
+        if !open {
+            self.properties = None;
+        }
+    }
 
+    /// 索引更新后在后台重建补全用的文件夹名与扩展名词表。
+    fn refresh_completions(&self) {
+        let entries = self.indexer.lock().unwrap().entries_arc();
+        let completions = Arc::clone(&self.completions);
         thread::spawn(move || {
-            #[cfg(windows)]
-            {
-                let _ = crate::windows_usn::try_enable_usn_privileges();
-            }
-            let (entries, usn_states, stats) =
-                FileIndexer::build_index_snapshot_with_stats(paths, Some(&handles));
-            if index_seq.load(Ordering::SeqCst) != seq {
-                return;
-            }
-            {
-                // 先更新内存索引，让搜索尽快可用；缓存写入放到后面，不阻塞“索引完成”的体验
-                let mut indexer_guard = indexer.lock().unwrap();
-                indexer_guard.replace_index(entries, usn_states);
-            }
-            let mut guard = index_stats.lock().unwrap();
-            *guard = Some(stats);
+            let built = Completions::build(&entries);
+            *completions.lock().unwrap() = built;
         });
-        self.last_index_time = Some(SystemTime::now());
     }
 
-    fn perform_search(&mut self) {
-        let search_text = self.search_text.clone();
-        let indexer = Arc::clone(&self.indexer);
-        let results = Arc::clone(&self.results);
-        let search_options = self.searcher.options.clone();
-        let file_type_filter = self.file_type_filter;
-        let file_extension = self.file_extension.clone();
-        let search_seq = Arc::clone(&self.search_seq);
-        let seq = search_seq.fetch_add(1, Ordering::SeqCst) + 1;
+    /// 替换搜索框内容并把光标移到末尾（历史回溯与采纳补全时使用）。
+    fn set_search_text(&mut self, ctx: &egui::Context, id: egui::Id, text: String) {
+        self.search_text = text;
+        if let Some(mut state) = egui::TextEdit::load_state(ctx, id) {
+            let end = egui::text::CCursor::new(self.search_text.chars().count());
+            state.cursor.set_char_range(Some(egui::text::CCursorRange::one(end)));
+            state.store(ctx, id);
+        }
+    }
 
-        thread::spawn(move || {
-            let indexer_guard = indexer.lock().unwrap();
-            let mut searcher = Searcher::new();
-            searcher.set_options(search_options);
-            let mut search_results = searcher.search(&*indexer_guard, &search_text);
+    /// 分面筛选：每行一个维度，点击取值即按其筛选并重新搜索，再次点击取消。
+    fn show_facets(&mut self, ui: &mut egui::Ui) {
+        // “在此文件夹中搜索”设置的文件夹不一定出现在分面中（命中都在其子文件夹时），单独显示并可清除
+        if let Some(folder) = self.searcher.options.filters.folder.clone() {
+            let mut clear = false;
+            ui.horizontal(|ui| {
+                ui.label("仅搜索文件夹:");
+                ui.label(egui::RichText::new(&folder).strong());
+                clear = ui.small_button("清除").clicked();
+            });
+            if clear {
+                self.searcher.options.filters.folder = None;
+                self.perform_search();
+            }
+        }
 
-            // 应用文件类型过滤
-            if file_type_filter != FileTypeFilter::All || !file_extension.is_empty() {
-                search_results.retain(|r| {
-                    let entry = &r.entry;
+        let facets = self.facets.lock().unwrap().clone();
+        if facets == Facets::default() {
+            return;
+        }
 
-                    // 文件夹过滤
-                    if file_type_filter == FileTypeFilter::Folders && entry.is_dir {
-                        return true;
+        let mut changed = false;
+        let categories = Arc::clone(&self.searcher.options.categories);
+        let filters = &mut self.searcher.options.filters;
+        egui::CollapsingHeader::new("筛选").default_open(true).show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.label("类型:");
+                for (filter, count) in &facets.types {
+                    let selected = filters.file_type == *filter;
+                    if ui.selectable_label(selected, format!("{} ({count})", filter.label(&categories))).clicked() {
+                        filters.file_type = if selected { FileTypeFilter::All } else { filter.clone() };
+                        changed = true;
                     }
-                    if file_type_filter == FileTypeFilter::Folders && !entry.is_dir {
-                        return false;
+                }
+            });
+            ui.horizontal_wrapped(|ui| {
+                ui.label("扩展名:");
+                let current = filters.extension.trim_start_matches('.').to_lowercase();
+                for (ext, count) in facets.extensions.iter().take(12) {
+                    let selected = current == *ext;
+                    if ui.selectable_label(selected, format!(".{ext} ({count})")).clicked() {
+                        filters.extension = if selected { String::new() } else { ext.clone() };
+                        changed = true;
                     }
-                    if file_type_filter == FileTypeFilter::Files && entry.is_dir {
-                        return false;
+                }
+            });
+            ui.horizontal_wrapped(|ui| {
+                ui.label("文件夹:");
+                for (folder, count) in &facets.folders {
+                    let selected = filters.folder.as_ref() == Some(folder);
+                    let short = folder.rsplit(['/', '\\']).find(|s| !s.is_empty()).unwrap_or(folder);
+                    if ui
+                        .selectable_label(selected, format!("{short} ({count})"))
+                        .on_hover_text(folder)
+                        .clicked()
+                    {
+                        filters.folder = (!selected).then(|| folder.clone());
+                        changed = true;
                     }
-
-                    // 文件类型过滤
-                    if !entry.is_dir {
-                        let ext = entry.name.split('.').last().unwrap_or("").to_lowercase();
-
-                        match file_type_filter {
-                            FileTypeFilter::Documents => {
-                                let docs = ["doc", "docx", "txt", "pdf", "xls", "xlsx", "ppt", "pptx", "md"];
-                                if !docs.contains(&ext.as_str()) && !entry.is_dir {
-                                    return false;
-                                }
-                            }
-                            FileTypeFilter::Images => {
-                                let images = ["jpg", "jpeg", "png", "gif", "bmp", "svg", "webp", "ico"];
-                                if !images.contains(&ext.as_str()) && !entry.is_dir {
-                                    return false;
-                                }
-                            }
-                            FileTypeFilter::Videos => {
-                                let videos = ["mp4", "avi", "mkv", "mov", "wmv", "flv", "webm"];
-                                if !videos.contains(&ext.as_str()) && !entry.is_dir {
-                                    return false;
-                                }
-                            }
-                            FileTypeFilter::Audio => {
-                                let audio = ["mp3", "wav", "flac", "aac", "ogg", "wma", "m4a"];
-                                if !audio.contains(&ext.as_str()) && !entry.is_dir {
-                                    return false;
-                                }
-                            }
-                            _ => {}
+                }
+            });
+            if !facets.sizes.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("大小:");
+                    for (bucket, count) in &facets.sizes {
+                        let selected = filters.size == Some(*bucket);
+                        if ui.selectable_label(selected, format!("{} ({count})", bucket.label())).clicked() {
+                            filters.size = (!selected).then_some(*bucket);
+                            changed = true;
                         }
-
-                        // 扩展名过滤
-                        if !file_extension.is_empty() {
-                            let target_ext = file_extension.trim_start_matches('.').to_lowercase();
-                            if ext != target_ext {
-                                return false;
-                            }
+                    }
+                });
+            }
+            if !facets.dates.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("修改时间:");
+                    for (bucket, count) in &facets.dates {
+                        let selected = filters.date == Some(*bucket);
+                        if ui.selectable_label(selected, format!("{} ({count})", bucket.label())).clicked() {
+                            filters.date = (!selected).then_some(*bucket);
+                            changed = true;
                         }
                     }
-
-                    true
                 });
             }
+        });
+        if changed {
+            self.perform_search();
+        }
+    }
 
-            if search_seq.load(Ordering::SeqCst) != seq {
-                return;
-            }
+    /// 按字符区间把命中部分渲染为加粗色块，其余部分使用 `color`。
+    fn highlighted_text(
+        ui: &egui::Ui,
+        text: &str,
+        ranges: &[Range<usize>],
+        color: egui::Color32,
+    ) -> egui::text::LayoutJob {
+        let font_id = egui::TextStyle::Body.resolve(ui.style());
+        let plain = egui::TextFormat::simple(font_id.clone(), color);
+        let highlight = egui::TextFormat {
+            color: egui::Color32::from_rgb(200, 80, 0),
+            background: egui::Color32::from_rgb(255, 243, 205),
+            ..egui::TextFormat::simple(font_id, color)
+        };
 
-            let mut results_guard = results.lock().unwrap();
-            *results_guard = search_results;
-        });
-        self.selected_result = None;
+        let offsets: Vec<usize> = text
+            .char_indices()
+            .map(|(b, _)| b)
+            .chain(std::iter::once(text.len()))
+            .collect();
+        let byte_at = |c: usize| offsets.get(c).copied().unwrap_or(text.len());
+
+        let mut job = egui::text::LayoutJob::default();
+        let mut pos = 0usize;
+        for r in ranges {
+            let (start, end) = (byte_at(r.start).max(pos), byte_at(r.end));
+            if start >= end {
+                continue;
+            }
+            job.append(&text[pos..start], 0.0, plain.clone());
+            job.append(&text[start..end], 0.0, highlight.clone());
+            pos = end;
+        }
+        job.append(&text[pos..], 0.0, plain);
+        job
     }
 
-    fn format_size(size: u64) -> String {
+    pub(crate) fn format_size(size: u64) -> String {
         if size == u64::MAX {
             return "—".to_string();
         }
@@ -282,14 +1720,81 @@ impl FileSearchApp {
 }
 
 impl eframe::App for FileSearchApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, CACHE_SETTINGS_KEY, &self.cache_settings);
+        eframe::set_value(storage, USAGE_SETTINGS_KEY, &self.usage_settings);
+        eframe::set_value(storage, RANKING_PROFILE_KEY, &self.searcher.options.ranking);
+        eframe::set_value(storage, FILE_CATEGORIES_KEY, &*self.searcher.options.categories);
+        eframe::set_value(storage, SAVED_SEARCHES_KEY, &*self.saved.lock().unwrap());
+        eframe::set_value(storage, SEARCH_HISTORY_KEY, &self.history);
+        eframe::set_value(storage, KEYMAP_KEY, &self.keymap);
+        eframe::set_value(storage, RENAME_RULES_KEY, &self.rename_rules);
+        eframe::set_value(storage, RENAME_LOG_KEY, &self.rename_log);
+        eframe::set_value(storage, PREVIEW_SETTINGS_KEY, &self.preview_settings);
+        eframe::set_value(storage, ICON_SETTINGS_KEY, &self.icon_settings);
+        eframe::set_value(storage, HOTKEY_SETTINGS_KEY, &self.hotkey_settings);
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // 检查索引状态
+        let was_indexing = self.is_indexing;
         {
             let indexer = self.indexer.lock().unwrap();
             self.is_indexing = indexer.is_indexing();
             self.index_progress = indexer.progress();
             self.total_files = indexer.get_entries().len();
         }
+        if was_indexing && !self.is_indexing {
+            self.refresh_live_searches();
+            self.refresh_completions();
+        }
+        if self.index_edited.swap(false, Ordering::SeqCst) {
+            let selection = self.selection.clone();
+            self.perform_search();
+            self.selection = selection;
+        }
+
+        self.handle_resident_events(ctx);
+        if self.launcher.is_open() {
+            self.show_launcher(ctx);
+            return;
+        }
+
+        // Ctrl+1..9 执行对应的保存搜索
+        const SAVED_KEYS: [egui::Key; 9] = [
+            egui::Key::Num1,
+            egui::Key::Num2,
+            egui::Key::Num3,
+            egui::Key::Num4,
+            egui::Key::Num5,
+            egui::Key::Num6,
+            egui::Key::Num7,
+            egui::Key::Num8,
+            egui::Key::Num9,
+        ];
+        let hotkey = ctx.input(|i| {
+            SAVED_KEYS
+                .iter()
+                .position(|&k| i.modifiers.command && i.key_pressed(k))
+        });
+        if let Some(idx) = hotkey {
+            self.run_saved_search(idx);
+        }
+
+        if self.current_tab == Tab::Search {
+            egui::SidePanel::left("saved_searches")
+                .resizable(true)
+                .default_width(180.0)
+                .show(ctx, |ui| self.show_saved_searches(ui));
+            if self.preview_settings.enabled {
+                self.update_preview(ctx);
+                egui::SidePanel::right("preview")
+                    .resizable(true)
+                    .default_width(320.0)
+                    .width_range(200.0..=960.0)
+                    .show(ctx, |ui| self.show_preview_panel(ui));
+            }
+        }
 
         egui::CentralPanel::default().show(ctx, |ui| {
             // 顶部标签页
@@ -315,6 +1820,12 @@ impl eframe::App for FileSearchApp {
             }
         });
 
+        self.show_rename_dialog(ctx);
+        self.show_properties_window(ctx);
+        self.show_batch_dialog(ctx);
+        self.show_rename_tool(ctx);
+        self.show_batch_progress(ctx);
+
         #[cfg(windows)]
         {
             // 若 USN 枚举因权限(code=5)回退到 WalkDir，则主动提示一次可重启为管理员。
@@ -372,13 +1883,80 @@ impl eframe::App for FileSearchApp {
 
 impl FileSearchApp {
     fn show_search_tab(&mut self, ui: &mut egui::Ui) {
-        // 搜索框
+        // 结果列表的快捷键：在搜索框等输入控件有焦点时不处理
+        if self.rename_dialog.is_none()
+            && self.rename_tool.is_none()
+            && self.batch_dialog.is_none()
+            && self.batch_job.is_none()
+            && ui.memory(|m| m.focused().is_none())
+            && let Some(action) = ui.input_mut(|i| self.keymap.consume(i))
+        {
+            self.run_result_action(ui.ctx(), action);
+        }
+
+        // 搜索框：上/下方向键回溯历史，Tab 采纳第一个补全候选
+        let search_id = egui::Id::new("search_box");
+        let popup_id = search_id.with("suggestions");
+        let suggestions = {
+            let completions = self.completions.lock().unwrap();
+            suggest(&self.history, &completions, &self.search_text, MAX_SUGGESTIONS)
+        };
         ui.horizontal(|ui| {
             ui.label("搜索:");
-            let response = ui.text_edit_singleline(&mut self.search_text);
+            if ui.memory(|m| m.has_focus(search_id)) {
+                let (up, down, tab) = ui.input_mut(|i| {
+                    (
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::Tab),
+                    )
+                });
+                let recalled = if up {
+                    self.history.older(&self.search_text).map(str::to_string)
+                } else if down {
+                    self.history.newer().map(str::to_string)
+                } else if tab {
+                    suggestions.first().map(|s| s.text.clone())
+                } else {
+                    None
+                };
+                if let Some(text) = recalled {
+                    self.set_search_text(ui.ctx(), search_id, text);
+                }
+            }
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.search_text)
+                    .id(search_id)
+                    .hint_text("↑↓ 历史，Tab 补全"),
+            );
+            if response.changed() {
+                self.history.reset_recall();
+            }
+            if response.changed() || response.gained_focus() {
+                ui.memory_mut(|m| m.open_popup(popup_id));
+            }
+
+            let mut accepted = None;
+            if !suggestions.is_empty() {
+                egui::popup_below_widget(ui, popup_id, &response, egui::PopupCloseBehavior::CloseOnClickOutside, |ui| {
+                    ui.set_min_width(response.rect.width());
+                    for suggestion in &suggestions {
+                        let label = format!("{} {}", suggestion.kind.icon(), suggestion.text);
+                        if ui.selectable_label(false, label).clicked() {
+                            accepted = Some(suggestion.text.clone());
+                        }
+                    }
+                });
+            }
+            if let Some(text) = accepted {
+                ui.memory_mut(|m| m.close_popup());
+                response.request_focus();
+                self.set_search_text(ui.ctx(), search_id, text);
+            }
 
             // 回车搜索
             if response.has_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                ui.memory_mut(|m| m.close_popup());
                 self.perform_search();
             }
 
@@ -394,128 +1972,224 @@ impl FileSearchApp {
             ui.checkbox(&mut self.searcher.options.case_sensitive, "区分大小写");
             ui.checkbox(&mut self.searcher.options.path_search, "搜索路径");
             ui.checkbox(&mut self.searcher.options.fuzzy, "宽松搜索");
-            ui.checkbox(&mut self.show_hidden, "显示隐藏文件");
+            ui.checkbox(&mut self.searcher.options.pinyin, "拼音匹配");
+            ui.add(egui::Slider::new(&mut self.searcher.options.typo_tolerance, 0..=2).text("拼写容错"));
+            let sort = &mut self.searcher.options.sort;
+            egui::ComboBox::from_id_salt("sort_order")
+                .selected_text(format!("排序: {}", sort.label()))
+                .show_ui(ui, |ui| {
+                    for order in SortOrder::ALL {
+                        ui.selectable_value(sort, order, order.label());
+                    }
+                });
+            if ui.checkbox(&mut self.searcher.options.filters.show_hidden, "显示隐藏文件").changed() {
+                self.perform_search();
+            }
+            ui.checkbox(&mut self.preview_settings.enabled, "预览");
+            ui.checkbox(&mut self.icon_settings.thumbnails, "缩略图")
+                .on_hover_text("图片结果显示缩略图");
         });
 
         // 文件类型过滤
         //（仅影响下一次“搜索”按钮/回车触发的搜索）
+        let categories = Arc::clone(&self.searcher.options.categories);
+        let filters = &mut self.searcher.options.filters;
         ui.horizontal(|ui| {
             ui.label("文件类型:");
             egui::ComboBox::from_id_salt("file_type_filter")
-                .selected_text(match self.file_type_filter {
-                    FileTypeFilter::All => "全部",
-                    FileTypeFilter::Files => "仅文件",
-                    FileTypeFilter::Folders => "仅文件夹",
-                    FileTypeFilter::Documents => "文档",
-                    FileTypeFilter::Images => "图片",
-                    FileTypeFilter::Videos => "视频",
-                    FileTypeFilter::Audio => "音频",
-                })
+                .selected_text(filters.file_type.label(&categories))
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut self.file_type_filter, FileTypeFilter::All, "全部");
-                    ui.selectable_value(&mut self.file_type_filter, FileTypeFilter::Files, "仅文件");
-                    ui.selectable_value(&mut self.file_type_filter, FileTypeFilter::Folders, "仅文件夹");
-                    ui.selectable_value(&mut self.file_type_filter, FileTypeFilter::Documents, "文档 (doc/txt/pdf)");
-                    ui.selectable_value(&mut self.file_type_filter, FileTypeFilter::Images, "图片 (jpg/png/gif)");
-                    ui.selectable_value(&mut self.file_type_filter, FileTypeFilter::Videos, "视频 (mp4/avi/mkv)");
-                    ui.selectable_value(&mut self.file_type_filter, FileTypeFilter::Audio, "音频 (mp3/wav/flac)");
+                    let category_filters = categories
+                        .categories
+                        .iter()
+                        .map(|c| FileTypeFilter::Category(c.id.clone()));
+                    for filter in FileTypeFilter::BASIC.into_iter().chain(category_filters) {
+                        let label = filter.label(&categories);
+                        ui.selectable_value(&mut filters.file_type, filter, label);
+                    }
                 });
 
             ui.label(".ext");
-            ui.text_edit_singleline(&mut self.file_extension);
+            ui.text_edit_singleline(&mut filters.extension);
         });
 
         ui.separator();
 
         // 结果列表
-        let num_results = {
-            let results = self.results.lock().unwrap();
-            if self.show_hidden {
-                results.len()
-            } else {
-                results.iter().filter(|r| !r.entry.is_hidden).count()
-            }
-        };
+        let num_results = self.results.lock().unwrap().len();
+        let total_results = self.total_results();
+        // 结果刷新后可能变少
+        self.selection.retain_below(num_results);
 
         ui.horizontal(|ui| {
-            ui.label(format!("找到 {} 个结果", num_results));
+            ui.label(format!("找到 {} 个结果", total_results));
             ui.label(egui::RichText::new("双击打开").small().weak());
+            if num_results > 0
+                && ui
+                    .small_button("批量重命名…")
+                    .on_hover_text("对选中的结果批量重命名；未多选时作用于已加载的全部结果")
+                    .clicked()
+            {
+                let targets = if self.selection.len() > 1 {
+                    self.selected_targets()
+                } else {
+                    self.results.lock().unwrap().iter().map(ResultTarget::from_result).collect()
+                };
+                self.open_rename_tool(targets);
+            }
         });
 
-        // 使用 ScrollArea 显示结果
-        egui::ScrollArea::vertical()
-            .auto_shrink(false)
-            .show(ui, |ui| {
-                let results = self.results.lock().unwrap();
-                for (idx, result) in results.iter().enumerate() {
-                    let entry = &result.entry;
-                    if !self.show_hidden && entry.is_hidden {
-                        continue;
-                    }
-
-                    let is_selected = self.selected_result == Some(idx);
-
-                    let row = ui
-                        .horizontal(|ui| {
-                            if entry.is_dir {
-                                ui.label("📁");
-                            } else {
-                                ui.label("📄");
-                            }
-
-                            let name_color = if result.match_type == MatchType::Path {
-                                egui::Color32::from_rgb(100, 100, 100)
-                            } else {
-                                egui::Color32::from_rgb(0, 0, 0)
-                            };
-
-                            ui.label(egui::RichText::new(&entry.name).color(name_color));
+        if self.selection.len() > 1 {
+            self.show_batch_bar(ui);
+        }
 
-                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                ui.label(
-                                    egui::RichText::new(Self::format_size(entry.size))
-                                        .small()
-                                        .weak(),
-                                );
-                            });
+        self.show_facets(ui);
+
+        // 固定行高，只绘制可见行；未生成的行显示占位并在后台生成
+        let categories = Arc::clone(&self.searcher.options.categories);
+        let thumbnails = self.icon_settings.thumbnails;
+        let icon_size = if thumbnails { THUMBNAIL_SIZE } else { ICON_SIZE };
+        let row_inner = ui.spacing().interact_size.y.max(icon_size);
+        let row_height = row_inner + ui.spacing().item_spacing.y;
+        self.icons.begin_frame(ui.ctx());
+        let viewport = ui.available_height();
+        self.page_rows = ((viewport / row_height) as usize).saturating_sub(1).max(1);
+        let mut scroll_area = egui::ScrollArea::vertical().auto_shrink(false);
+        // 选中行不在上一帧的可见范围内时不会被绘制，直接算出滚动位置
+        if std::mem::take(&mut self.scroll_to_selected)
+            && let Some(cursor) = self.selection.cursor()
+        {
+            if cursor <= self.visible_rows.start {
+                scroll_area = scroll_area.vertical_scroll_offset(cursor as f32 * row_height);
+            } else if cursor + 1 >= self.visible_rows.end {
+                scroll_area = scroll_area.vertical_scroll_offset(((cursor + 1) as f32 * row_height - viewport).max(0.0));
+            }
+        }
+        let mut visible_rows = 0..0;
+        let mut context_action = None;
+        scroll_area.show_rows(ui, row_inner, total_results, |ui, rows| {
+            visible_rows = rows.clone();
+            let results = self.results.lock().unwrap();
+            for idx in rows {
+                let Some(result) = results.get(idx) else {
+                    ui.horizontal(|ui| {
+                        ui.set_min_height(row_inner);
+                        ui.weak("加载中…");
+                    });
+                    continue;
+                };
+                let entry = result.entry();
+
+                let is_selected = self.selection.contains(idx);
+
+                let row = ui
+                    .horizontal(|ui| {
+                        ui.set_min_height(row_inner);
+                        let (icon_rect, _) = ui.allocate_exact_size(egui::vec2(icon_size, icon_size), egui::Sense::hover());
+                        let icon = IconRequest {
+                            path: &result.display_path,
+                            extension: entry.extension_lower(),
+                            is_dir: entry.is_dir(),
+                            modified_ms: entry.modified_ms(),
+                            category: categories.for_extension(entry.extension_lower()).map(|c| c.id.as_str()),
+                        };
+                        self.icons.paint(ui, icon_rect, &icon, thumbnails);
+
+                        let name_color = if result.match_type == MatchType::Path {
+                            egui::Color32::from_rgb(100, 100, 100)
+                        } else {
+                            egui::Color32::from_rgb(0, 0, 0)
+                        };
+
+                        let ranges = result.merged_ranges(MatchField::Name);
+                        ui.label(Self::highlighted_text(ui, entry.name(), &ranges, name_color));
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(
+                                egui::RichText::new(Self::format_size(entry.size()))
+                                    .small()
+                                    .weak(),
+                            );
                         });
+                    });
 
-                    let response = ui.interact(row.response.rect, ui.id().with(idx), egui::Sense::click());
+                let response = ui.interact(row.response.rect, ui.id().with(idx), egui::Sense::click());
 
-                    if is_selected {
-                        ui.painter().rect_filled(
-                            response.rect,
-                            2.0,
-                            egui::Color32::from_rgb(173, 216, 230),
-                        );
-                    }
+                if is_selected {
+                    ui.painter().rect_filled(
+                        response.rect,
+                        2.0,
+                        egui::Color32::from_rgb(173, 216, 230),
+                    );
+                }
 
-                    // 悬停效果
-                    if response.hovered() {
-                        ui.painter().rect_filled(
-                            response.rect,
-                            2.0,
-                            egui::Color32::from_rgb(220, 220, 220),
-                        );
-                    }
+                // 悬停效果
+                if response.hovered() {
+                    ui.painter().rect_filled(
+                        response.rect,
+                        2.0,
+                        egui::Color32::from_rgb(220, 220, 220),
+                    );
+                }
 
-                    if response.clicked() {
-                        self.selected_result = Some(idx);
+                // Ctrl+单击切换，Shift+单击连续选择
+                if response.clicked() {
+                    let modifiers = ui.input(|i| i.modifiers);
+                    if modifiers.command {
+                        self.selection.toggle(idx);
+                    } else if modifiers.shift {
+                        self.selection.extend_to(idx);
+                    } else {
+                        self.selection.select(idx);
                     }
+                }
+
+                if response.double_clicked() {
+                    self.open_result(&result.display_path);
+                }
 
-                    if response.double_clicked() {
-                        Self::open_path_in_os(&result.display_path);
+                response.context_menu(|ui| {
+                    let available =
+                        |a: &&ResultAction| cfg!(windows) || !matches!(a, ResultAction::OpenWith | ResultAction::ShellMenu);
+                    for (i, group) in CONTEXT_MENU.iter().enumerate() {
+                        if !group.iter().any(|a| available(&a)) {
+                            continue;
+                        }
+                        if i > 0 {
+                            ui.separator();
+                        }
+                        for &action in group.iter().filter(available) {
+                            let mut button = egui::Button::new(action.label());
+                            if let Some(shortcut) = self.keymap.shortcut(action) {
+                                button = button.shortcut_text(ui.ctx().format_shortcut(&shortcut));
+                            }
+                            if ui.add(button).clicked() {
+                                context_action = Some((idx, action));
+                                ui.close_menu();
+                            }
+                        }
                     }
+                });
 
-                    // 路径提示
-                    response.on_hover_text(&result.display_path);
-                }
-            });
+                // 路径提示
+                response.on_hover_text(&result.display_path);
+            }
+        });
+        self.icons.end_frame(ui.ctx());
+        self.load_result_pages(ui.ctx(), visible_rows.end + RESULT_PAGE_SIZE / 2);
+        self.visible_rows = visible_rows;
+        if let Some((idx, action)) = context_action {
+            // 在选中项上右键时保留多选，否则改为只选中该项
+            if !self.selection.contains(idx) {
+                self.selection.select(idx);
+            }
+            self.run_result_action(ui.ctx(), action);
+        }
 
         // 状态栏
         ui.separator();
         ui.horizontal(|ui| {
-            if let Some(idx) = self.selected_result {
+            if let Some(idx) = self.selection.cursor() {
                 let results = self.results.lock().unwrap();
                 if let Some(result) = results.get(idx) {
                     let resp = ui
@@ -525,17 +2199,177 @@ impl FileSearchApp {
                         )
                         .on_hover_text("双击打开");
                     if resp.double_clicked() {
-                        Self::open_path_in_os(&result.display_path);
+                        self.open_result(&result.display_path);
+                    }
+                    if ui.small_button("在文件夹中显示").clicked() {
+                        self.reveal_result(&result.display_path);
+                    }
+                    ui.label(egui::RichText::new(format!("得分 {:.1}", result.score)).small().weak())
+                        .on_hover_ui(|ui| match self.explain_result(result.index) {
+                            Some(explanation) => {
+                                for term in &explanation.terms {
+                                    let label = match term.token {
+                                        Some(t) => format!("词 {}：{}", t + 1, term.label),
+                                        None => term.label.to_string(),
+                                    };
+                                    ui.label(format!("{label} {:+.1}", term.value));
+                                }
+                                ui.separator();
+                                ui.label(format!("合计 {:.1}", explanation.total));
+                            }
+                            None => {
+                                ui.label("暂时无法计算得分明细");
+                            }
+                        });
+                }
+            }
+        });
+        ui.horizontal(|ui| {
+            if let Some(status) = &self.op_status {
+                ui.label(egui::RichText::new(status).small().weak());
+            }
+            if let Some(batch) = self.rename_log.last()
+                && ui
+                    .small_button(format!("撤销重命名（{} 项）", batch.records.len()))
+                    .clicked()
+            {
+                self.undo_last_rename();
+            }
+        });
+    }
+
+    /// 结果列表快捷键：单击“修改”后按下新的组合键，Esc 取消。
+    fn show_keymap_settings(&mut self, ui: &mut egui::Ui) {
+        if let Some(action) = self.capturing_action {
+            let pressed = ui.input(|i| (captured_shortcut(i), i.key_pressed(egui::Key::Escape)));
+            match pressed {
+                (Some(shortcut), _) => {
+                    self.keymap.set(action, shortcut);
+                    self.capturing_action = None;
+                }
+                (None, true) => self.capturing_action = None,
+                _ => {}
+            }
+        }
+        egui::CollapsingHeader::new("结果列表快捷键").show(ui, |ui| {
+            egui::Grid::new("keymap").num_columns(3).show(ui, |ui| {
+                for action in ResultAction::ALL {
+                    ui.label(action.label());
+                    if self.capturing_action == Some(action) {
+                        ui.label(egui::RichText::new("请按下新的快捷键…").italics());
+                    } else {
+                        let text = self.keymap.shortcut(action).map_or("（未设置）".to_string(), |s| ui.ctx().format_shortcut(&s));
+                        ui.label(text);
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.small_button("修改").clicked() {
+                            self.capturing_action = Some(action);
+                        }
+                        if ui.small_button("清除").clicked() {
+                            self.keymap.unbind(action);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+            if ui.button("恢复默认快捷键").clicked() {
+                self.keymap = Keymap::default();
+                self.capturing_action = None;
+            }
+        });
+    }
+
+    /// 全局快捷键与托盘：录制方式与结果列表快捷键相同。
+    fn show_hotkey_settings(&mut self, ui: &mut egui::Ui) {
+        if self.capturing_hotkey {
+            let pressed = ui.input(|i| (captured_shortcut(i), i.key_pressed(egui::Key::Escape)));
+            match pressed {
+                (Some(shortcut), _) => {
+                    self.hotkey_settings.shortcut = shortcut;
+                    self.capturing_hotkey = false;
+                    self.apply_hotkey();
+                }
+                (None, true) => self.capturing_hotkey = false,
+                _ => {}
+            }
+        }
+        egui::CollapsingHeader::new("快速搜索与托盘").show(ui, |ui| {
+            ui.horizontal(|ui| {
+                if ui.checkbox(&mut self.hotkey_settings.enabled, "全局快捷键呼出快速搜索").changed() {
+                    self.apply_hotkey();
+                }
+                if self.capturing_hotkey {
+                    ui.label(egui::RichText::new("请按下新的快捷键…").italics());
+                } else {
+                    ui.label(ui.ctx().format_shortcut(&self.hotkey_settings.shortcut));
+                    if ui.small_button("修改").clicked() {
+                        self.capturing_hotkey = true;
+                        self.capturing_action = None;
+                    }
+                }
+            });
+            if let Some(error) = &self.hotkey_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+            if cfg!(windows) {
+                ui.checkbox(
+                    &mut self.hotkey_settings.tray,
+                    "关闭主窗口时驻留在系统托盘（索引与快捷键继续可用）",
+                );
+            }
+        });
+    }
+
+    /// 文件类型分类编辑：图标、名称与扩展名（空格或逗号分隔）；`id` 创建后不变，供已保存的筛选条件引用。
+    fn show_category_settings(&mut self, ui: &mut egui::Ui) {
+        let registry = Arc::make_mut(&mut self.searcher.options.categories);
+        egui::CollapsingHeader::new("文件类型分类").show(ui, |ui| {
+            let mut to_remove = None;
+            egui::Grid::new("file_categories").num_columns(4).show(ui, |ui| {
+                for (idx, category) in registry.categories.iter_mut().enumerate() {
+                    ui.add(egui::TextEdit::singleline(&mut category.icon).desired_width(24.0));
+                    ui.add(egui::TextEdit::singleline(&mut category.name).desired_width(80.0))
+                        .on_hover_text(format!("type:{}", category.id));
+                    let mut extensions = category.extensions.join(" ");
+                    if ui
+                        .add(egui::TextEdit::singleline(&mut extensions).desired_width(320.0))
+                        .changed()
+                    {
+                        category.extensions = parse_extensions(&extensions);
                     }
+                    if ui.button("x").clicked() {
+                        to_remove = Some(idx);
+                    }
+                    ui.end_row();
                 }
+            });
+            if let Some(idx) = to_remove {
+                registry.categories.remove(idx);
             }
+            ui.horizontal(|ui| {
+                if ui.button("添加分类").clicked() {
+                    let id = registry.unused_id();
+                    registry.categories.push(FileCategory {
+                        name: id.clone(),
+                        id,
+                        ..FileCategory::default()
+                    });
+                }
+                if ui.button("恢复默认分类").clicked() {
+                    *registry = CategoryRegistry::default();
+                }
+            });
         });
     }
 
     fn show_settings_tab(&mut self, ui: &mut egui::Ui) {
         ui.heading("索引设置");
 
-        ui.label("索引来源: Windows (NTFS) 通过 USN/MFT 枚举（无本地索引缓存）");
+        if self.cache_settings.enabled {
+            ui.label("索引来源: Windows (NTFS) 通过 USN/MFT 枚举（启动时先加载本地索引缓存）");
+        } else {
+            ui.label("索引来源: Windows (NTFS) 通过 USN/MFT 枚举（无本地索引缓存）");
+        }
         if let Some(t) = self.last_index_time {
             if let Ok(age) = SystemTime::now().duration_since(t) {
                 ui.label(format!("上次开始索引: {} 秒前", age.as_secs()));
@@ -562,6 +2396,17 @@ impl FileSearchApp {
             ui.separator();
         }
 
+        // 按当前索引统计：从 v4 缓存加载时大部分列直接映射自缓存文件，不占堆内存
+        let mem = self.indexer.lock().unwrap().get_entries().memory_stats();
+        ui.label(format!(
+            "索引内存: {}（字符串 {}，小写副本 {}，偏移/字段表 {}），映射自缓存文件 {}",
+            Self::format_size(mem.total_bytes() as u64),
+            Self::format_size(mem.string_bytes as u64),
+            Self::format_size(mem.lower_bytes as u64),
+            Self::format_size(mem.table_bytes as u64),
+            Self::format_size(mem.mapped_bytes as u64),
+        ));
+
         if let Some(stats) = self.index_stats.lock().unwrap().clone() {
             ui.label(format!("本次统计: 共 {} 项，用时 {} ms", stats.total_entries, stats.total_ms));
             ui.separator();
@@ -585,6 +2430,40 @@ impl FileSearchApp {
             }
         }
 
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.cache_settings.enabled, "启用本地索引缓存");
+            egui::ComboBox::from_id_salt("cache_codec")
+                .selected_text(self.cache_settings.codec.label())
+                .show_ui(ui, |ui| {
+                    for codec in CacheCodec::ALL {
+                        ui.selectable_value(&mut self.cache_settings.codec, codec, codec.label());
+                    }
+                });
+        });
+        if self.cache_settings.enabled {
+            ui.horizontal(|ui| {
+                if ui.button("校验缓存").clicked() {
+                    self.cache_status = Self::cache_path().map(|path| match FileIndexer::verify_cache(&crate::indexer::current_cache_path(&path)) {
+                        Ok(report) => format!(
+                            "缓存 {}（{}）：{} 项，{} 个卷的 USN 状态，{}",
+                            Self::format_size(report.file_bytes),
+                            report.format_label(),
+                            report.entry_count,
+                            report.usn_states.len(),
+                            if report.is_ok() { "校验通过".to_string() } else { report.problems.join("；") },
+                        ),
+                        Err(e) => format!("缓存不可用: {e}"),
+                    });
+                }
+                if let Some(status) = &self.cache_status {
+                    ui.label(status);
+                }
+            });
+            if let Some(error) = self.cache_save_error.lock().unwrap().as_deref() {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+        }
+
         ui.horizontal(|ui| {
             if ui.button("自动索引全部磁盘").clicked() {
                 self.index_paths = Self::default_index_paths();
@@ -655,6 +2534,63 @@ impl FileSearchApp {
         );
         ui.checkbox(&mut self.searcher.options.path_search, "默认搜索路径");
 
+        let ranking = &mut self.searcher.options.ranking;
+        ui.horizontal(|ui| {
+            ui.label("排序方式:");
+            egui::ComboBox::from_id_salt("ranking_preset")
+                .selected_text(ranking.preset().map_or("自定义", RankingPreset::label))
+                .show_ui(ui, |ui| {
+                    for preset in RankingPreset::ALL {
+                        if ui.selectable_label(ranking.preset() == Some(preset), preset.label()).clicked() {
+                            *ranking = preset.profile();
+                        }
+                    }
+                });
+            if ui.button("恢复默认").clicked() {
+                *ranking = RankingProfile::default();
+            }
+        });
+        egui::CollapsingHeader::new("排序权重").show(ui, |ui| {
+            egui::Grid::new("ranking_weights").num_columns(2).show(ui, |ui| {
+                for (label, value) in ranking.fields_mut() {
+                    ui.label(label);
+                    ui.add(egui::DragValue::new(value).speed(0.1));
+                    ui.end_row();
+                }
+            });
+        });
+
+        self.show_category_settings(ui);
+        self.show_keymap_settings(ui);
+        self.show_hotkey_settings(ui);
+
+        ui.separator();
+        ui.heading("搜索历史");
+        ui.horizontal(|ui| {
+            let mut capacity = self.history.capacity;
+            if ui.add(egui::Slider::new(&mut capacity, 0..=1000).text("保留条数")).changed() {
+                self.history.set_capacity(capacity);
+            }
+            ui.label(format!("已记录 {} 条", self.history.entries.len()));
+            if ui.button("清除搜索历史").clicked() {
+                self.history.clear();
+            }
+        });
+
+        ui.separator();
+        ui.heading("使用记录");
+        ui.checkbox(
+            &mut self.usage_settings.enabled,
+            "记录打开/定位的文件，并让常用文件排在前面",
+        );
+        ui.horizontal(|ui| {
+            let count = self.usage.lock().unwrap().len();
+            ui.label(format!("已记录 {} 个路径", count));
+            if ui.button("清除使用记录").clicked() {
+                let _ = self.usage.lock().unwrap().clear();
+            }
+        });
+
         ui.separator();
         ui.heading("关于");
         ui.label(format!("文件搜索工具 v{}", env!("CARGO_PKG_VERSION")));
@@ -667,3 +2603,9 @@ impl FileSearchApp {
         ui.label(" - 为加速启动，路径按需解析（展示/打开时再拼接）");
     }
 }
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
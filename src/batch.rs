@@ -0,0 +1,228 @@
+//! 对多选结果的批量操作：在后台线程逐项执行，界面显示进度并在结束后列出每一项的错误。
+//!
+//! 成功的项记录为 `Outcome`，由界面线程在任务结束后一次性写回索引（见 `FileSearchApp::apply_batch`）。
+//! 移动或复制到的新位置在后台重新扫描，写回时只追加扫描结果，不在持有索引锁时访问磁盘。
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::entry_store::EntryStore;
+use crate::file_ops;
+
+#[derive(Clone, Debug)]
+pub enum BatchOp {
+    CopyTo(String),
+    MoveTo(String),
+    Trash,
+    /// 导出为 CSV（名称、路径、大小、修改时间）。
+    ExportCsv(String),
+}
+
+impl BatchOp {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BatchOp::CopyTo(_) => "复制到文件夹",
+            BatchOp::MoveTo(_) => "移动到文件夹",
+            BatchOp::Trash => "移到回收站",
+            BatchOp::ExportCsv(_) => "导出",
+        }
+    }
+}
+
+/// 一个待处理的结果：展示路径、其在索引中的下标，以及导出时需要的字段。
+#[derive(Clone, Debug)]
+pub struct BatchItem {
+    pub path: String,
+    pub index: usize,
+    pub name: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub modified_ms: u64,
+}
+
+/// 单项成功后对索引的影响。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Removed,
+    Moved(String),
+    Copied(String),
+    Unchanged,
+}
+
+#[derive(Default)]
+pub struct BatchProgress {
+    pub done: usize,
+    /// (路径, 错误信息)
+    pub errors: Vec<(String, String)>,
+    /// (条目下标, 结果)
+    pub outcomes: Vec<(usize, Outcome)>,
+    /// 移动或复制后的新位置（目录连同子项）的扫描结果，写回索引时追加。
+    pub scanned: EntryStore,
+    pub finished: bool,
+}
+
+pub struct BatchJob {
+    pub title: &'static str,
+    pub total: usize,
+    /// 发起任务时的索引版本，写回索引前用来确认下标仍然有效。
+    pub generation: u64,
+    pub progress: Arc<Mutex<BatchProgress>>,
+    pub cancel: Arc<AtomicBool>,
+    /// 结果已写回索引。
+    pub applied: bool,
+}
+
+impl BatchJob {
+    pub fn start(op: BatchOp, items: Vec<BatchItem>, generation: u64) -> Self {
+        let job = Self {
+            title: op.label(),
+            total: items.len(),
+            generation,
+            progress: Arc::new(Mutex::new(BatchProgress::default())),
+            cancel: Arc::new(AtomicBool::new(false)),
+            applied: false,
+        };
+        let progress = Arc::clone(&job.progress);
+        let cancel = Arc::clone(&job.cancel);
+        thread::spawn(move || run(op, items, &progress, &cancel));
+        job
+    }
+}
+
+fn run(op: BatchOp, items: Vec<BatchItem>, progress: &Mutex<BatchProgress>, cancel: &AtomicBool) {
+    let mut export = match &op {
+        BatchOp::ExportCsv(path) => match create_csv(path) {
+            Ok(writer) => Some(writer),
+            Err(e) => {
+                let mut p = progress.lock().unwrap();
+                p.errors.push((path.clone(), e.to_string()));
+                p.finished = true;
+                return;
+            }
+        },
+        _ => None,
+    };
+
+    let mut scanned = EntryStore::new();
+    for item in &items {
+        if cancel.load(Ordering::Relaxed) {
+            break;
+        }
+        let result = match &op {
+            BatchOp::CopyTo(folder) => file_ops::copy_into(&item.path, folder).map(Outcome::Copied),
+            BatchOp::MoveTo(folder) => file_ops::move_into(&item.path, folder).map(Outcome::Moved),
+            BatchOp::Trash => file_ops::move_to_trash(&item.path).map(|_| Outcome::Removed),
+            BatchOp::ExportCsv(_) => match export.as_mut() {
+                Some(writer) => write_csv_row(writer, item).map(|_| Outcome::Unchanged),
+                None => Ok(Outcome::Unchanged),
+            },
+        };
+        if let Ok(Outcome::Moved(path) | Outcome::Copied(path)) = &result {
+            crate::indexer::append_subtree(&mut scanned, Path::new(path));
+        }
+        let mut p = progress.lock().unwrap();
+        p.done += 1;
+        match result {
+            Ok(Outcome::Unchanged) => {}
+            Ok(outcome) => p.outcomes.push((item.index, outcome)),
+            Err(e) => p.errors.push((item.path.clone(), e.to_string())),
+        }
+    }
+
+    if let Some(Err(e)) = export.map(|mut w| w.flush())
+        && let BatchOp::ExportCsv(path) = &op
+    {
+        progress.lock().unwrap().errors.push((path.clone(), e.to_string()));
+    }
+    let mut p = progress.lock().unwrap();
+    p.scanned = scanned;
+    p.finished = true;
+}
+
+/// 带 UTF-8 BOM，便于 Excel 正确识别中文。
+fn create_csv(path: &str) -> io::Result<BufWriter<File>> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writer.write_all("\u{feff}名称,路径,大小,修改时间\r\n".as_bytes())?;
+    Ok(writer)
+}
+
+fn write_csv_row(writer: &mut impl Write, item: &BatchItem) -> io::Result<()> {
+    // 大小未知（`u64::MAX`）与修改时间未知（0）留空，不写出占位数值
+    let size = if item.is_dir || item.size == u64::MAX { String::new() } else { item.size.to_string() };
+    let modified = Some(item.modified_ms)
+        .filter(|&ms| ms != 0)
+        .and_then(|ms| chrono::DateTime::from_timestamp_millis(ms as i64))
+        .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default();
+    write!(
+        writer,
+        "{},{},{},{}\r\n",
+        csv_field(&item.name),
+        csv_field(&item.path),
+        size,
+        modified
+    )
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_writes_every_item_and_failures_are_reported_per_item() {
+        let dir = std::env::temp_dir().join(format!("rust_search_batch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().replace('\\', "/");
+        let item = |index: usize, name: &str| BatchItem {
+            path: format!("{dir_str}/{name}"),
+            index,
+            name: name.to_string(),
+            is_dir: false,
+            size: 3,
+            modified_ms: 0,
+        };
+        std::fs::write(dir.join("a,1.txt"), b"abc").unwrap();
+        let mut items = vec![item(0, "a,1.txt"), item(1, "missing.txt")];
+        items[1].size = u64::MAX;
+
+        let csv = format!("{dir_str}/out.csv");
+        let wait = |job: BatchJob| {
+            while !job.progress.lock().unwrap().finished {
+                thread::sleep(std::time::Duration::from_millis(5));
+            }
+            std::mem::take(&mut *job.progress.lock().unwrap())
+        };
+        let export = wait(BatchJob::start(BatchOp::ExportCsv(csv.clone()), items.clone(), 0));
+        assert_eq!(export.done, 2);
+        assert!(export.errors.is_empty());
+        let text = std::fs::read_to_string(&csv).unwrap();
+        assert_eq!(text.lines().count(), 3);
+        assert!(text.contains("\"a,1.txt\""));
+        // 未知的大小与修改时间为空单元格
+        assert!(text.contains(&format!("\"{dir_str}/a,1.txt\",3,\r\n")));
+        assert!(text.ends_with(&format!("missing.txt,{dir_str}/missing.txt,,\r\n")));
+
+        let copies = format!("{dir_str}/copies");
+        std::fs::create_dir_all(&copies).unwrap();
+        let copied = wait(BatchJob::start(BatchOp::CopyTo(copies.clone()), items, 0));
+        assert_eq!(copied.outcomes, [(0, Outcome::Copied(format!("{copies}/a,1.txt")))]);
+        // 复制出的文件已在后台扫描，写回索引时直接追加
+        assert_eq!(copied.scanned.len(), 1);
+        assert_eq!(copied.scanned.name(0), "a,1.txt");
+        assert_eq!(copied.errors.len(), 1);
+        assert!(copied.errors[0].0.ends_with("missing.txt"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,247 @@
+//! 文件类型分类：名称、扩展名列表、可选的 MIME 与文件头（magic bytes）规则及图标。
+//!
+//! 分类表可在设置中编辑并持久化；`FileTypeFilter::Category` 与查询前缀 `type:` 按 `id` 或名称引用分类。
+
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+
+/// 文件头规则：从 `offset` 处起的字节等于 `bytes`。
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MagicRule {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+impl MagicRule {
+    fn new(offset: usize, bytes: &[u8]) -> Self {
+        Self {
+            offset,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    fn matches(&self, header: &[u8]) -> bool {
+        header.get(self.offset..self.offset + self.bytes.len()) == Some(&self.bytes[..])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FileCategory {
+    /// 稳定的英文标识，用于 `type:` 前缀与持久化的筛选条件。
+    pub id: String,
+    pub name: String,
+    pub icon: String,
+    /// 小写、不含点的扩展名。
+    pub extensions: Vec<String>,
+    /// 仅作说明（如 "image/*"），不参与匹配。
+    pub mime: Option<String>,
+    /// 没有扩展名的文件按文件头判断，任一规则命中即属于该分类。
+    pub magic: Vec<MagicRule>,
+}
+
+impl Default for FileCategory {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            name: String::new(),
+            icon: "📄".to_string(),
+            extensions: Vec::new(),
+            mime: None,
+            magic: Vec::new(),
+        }
+    }
+}
+
+impl FileCategory {
+    fn builtin(id: &str, name: &str, icon: &str, extensions: &[&str], mime: Option<&str>, magic: Vec<MagicRule>) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            icon: icon.to_string(),
+            extensions: extensions.iter().map(|e| e.to_string()).collect(),
+            mime: mime.map(str::to_string),
+            magic,
+        }
+    }
+
+    pub fn has_extension(&self, extension: &str) -> bool {
+        !extension.is_empty() && self.extensions.iter().any(|e| e == extension)
+    }
+
+    /// 按文件头判断；读取失败或没有规则时返回 `false`。
+    pub fn matches_header(&self, path: &str) -> bool {
+        let Some(len) = self.magic.iter().map(|m| m.offset + m.bytes.len()).max() else {
+            return false;
+        };
+        let mut header = Vec::with_capacity(len);
+        let read = std::fs::File::open(path).and_then(|f| f.take(len as u64).read_to_end(&mut header));
+        read.is_ok() && self.magic.iter().any(|m| m.matches(&header))
+    }
+
+    /// 下拉菜单等处显示的 "图标 名称"。
+    pub fn label(&self) -> String {
+        format!("{} {}", self.icon, self.name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CategoryRegistry {
+    pub categories: Vec<FileCategory>,
+}
+
+impl Default for CategoryRegistry {
+    fn default() -> Self {
+        let m = MagicRule::new;
+        Self {
+            categories: vec![
+                FileCategory::builtin(
+                    "document",
+                    "文档",
+                    "📝",
+                    &["doc", "docx", "txt", "pdf", "xls", "xlsx", "ppt", "pptx", "md", "odt", "ods", "odp", "rtf", "csv"],
+                    None,
+                    vec![m(0, b"%PDF")],
+                ),
+                FileCategory::builtin(
+                    "image",
+                    "图片",
+                    "🖼",
+                    &["jpg", "jpeg", "png", "gif", "bmp", "svg", "webp", "ico", "tif", "tiff", "heic"],
+                    Some("image/*"),
+                    vec![m(0, b"\x89PNG"), m(0, b"\xFF\xD8\xFF"), m(0, b"GIF8")],
+                ),
+                FileCategory::builtin(
+                    "video",
+                    "视频",
+                    "🎬",
+                    &["mp4", "avi", "mkv", "mov", "wmv", "flv", "webm", "m4v"],
+                    Some("video/*"),
+                    vec![m(4, b"ftyp"), m(0, b"\x1A\x45\xDF\xA3")],
+                ),
+                FileCategory::builtin(
+                    "audio",
+                    "音频",
+                    "🎵",
+                    &["mp3", "wav", "flac", "aac", "ogg", "wma", "m4a", "opus"],
+                    Some("audio/*"),
+                    vec![m(0, b"ID3"), m(0, b"fLaC")],
+                ),
+                FileCategory::builtin(
+                    "code",
+                    "源代码",
+                    "💻",
+                    &[
+                        "rs", "c", "h", "cpp", "hpp", "cc", "cs", "py", "js", "ts", "jsx", "tsx", "java", "kt", "go",
+                        "rb", "php", "swift", "sh", "ps1", "bat", "lua", "sql", "html", "css", "json", "toml", "yaml",
+                        "yml", "xml",
+                    ],
+                    None,
+                    vec![m(0, b"#!")],
+                ),
+                FileCategory::builtin(
+                    "archive",
+                    "压缩包",
+                    "📦",
+                    &["zip", "rar", "7z", "tar", "gz", "tgz", "bz2", "xz", "zst", "iso", "cab"],
+                    None,
+                    vec![m(0, b"PK\x03\x04"), m(0, b"Rar!"), m(0, b"7z\xBC\xAF\x27\x1C"), m(0, b"\x1F\x8B")],
+                ),
+                FileCategory::builtin(
+                    "executable",
+                    "可执行文件",
+                    "⚙",
+                    &["exe", "msi", "dll", "sys", "com", "app", "apk", "deb", "rpm", "appimage"],
+                    None,
+                    vec![m(0, b"MZ"), m(0, b"\x7FELF")],
+                ),
+                FileCategory::builtin(
+                    "model",
+                    "3D/CAD",
+                    "🧊",
+                    &[
+                        "stl", "obj", "fbx", "blend", "3ds", "dae", "gltf", "glb", "ply", "3mf", "step", "stp", "iges",
+                        "igs", "dwg", "dxf", "skp",
+                    ],
+                    None,
+                    vec![m(0, b"glTF")],
+                ),
+            ],
+        }
+    }
+}
+
+impl CategoryRegistry {
+    pub fn get(&self, id: &str) -> Option<&FileCategory> {
+        self.categories.iter().find(|c| c.id == id)
+    }
+
+    /// 按 `id` 或名称查找（不区分大小写），供 `type:` 前缀使用。
+    pub fn lookup(&self, key: &str) -> Option<&FileCategory> {
+        self.categories
+            .iter()
+            .find(|c| c.id.eq_ignore_ascii_case(key) || c.name.to_lowercase() == key.to_lowercase())
+    }
+
+    /// 按扩展名归类（不读文件头），用于图标与分面。
+    pub fn for_extension(&self, extension: &str) -> Option<&FileCategory> {
+        self.categories.iter().find(|c| c.has_extension(extension))
+    }
+
+    /// 供设置界面新增分类时生成不重复的 `id`。
+    pub fn unused_id(&self) -> String {
+        (1..)
+            .map(|n| format!("custom{n}"))
+            .find(|id| self.get(id).is_none())
+            .unwrap_or_default()
+    }
+}
+
+/// 把 "jpg, .PNG  webp" 这类输入解析为规范的扩展名列表。
+pub fn parse_extensions(text: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for ext in text.split([',', ';', ' ', '，']) {
+        let ext = ext.trim().trim_start_matches('.').to_lowercase();
+        if !ext.is_empty() && !out.contains(&ext) {
+            out.push(ext);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_cover_common_kinds_and_lookup_by_id_or_name() {
+        let registry = CategoryRegistry::default();
+        assert_eq!(registry.for_extension("rs").map(|c| c.id.as_str()), Some("code"));
+        assert_eq!(registry.for_extension("7z").map(|c| c.id.as_str()), Some("archive"));
+        assert_eq!(registry.for_extension("exe").map(|c| c.id.as_str()), Some("executable"));
+        assert_eq!(registry.for_extension("stl").map(|c| c.id.as_str()), Some("model"));
+        assert_eq!(registry.lookup("IMAGE").map(|c| c.id.as_str()), Some("image"));
+        assert_eq!(registry.lookup("图片").map(|c| c.id.as_str()), Some("image"));
+        assert!(registry.lookup("nothing").is_none());
+        assert_eq!(parse_extensions("jpg, .PNG  webp,jpg"), ["jpg", "png", "webp"]);
+    }
+
+    #[test]
+    fn magic_rules_classify_files_without_extension() {
+        let dir = std::env::temp_dir().join(format!("rust_search_magic_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let png = dir.join("screenshot");
+        std::fs::write(&png, b"\x89PNG\r\n\x1a\n....").unwrap();
+        let text = dir.join("notes");
+        std::fs::write(&text, b"hello").unwrap();
+
+        let registry = CategoryRegistry::default();
+        let image = registry.get("image").unwrap();
+        assert!(image.matches_header(&png.to_string_lossy()));
+        assert!(!image.matches_header(&text.to_string_lossy()));
+        assert!(!image.matches_header(&dir.join("missing").to_string_lossy()));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,976 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::sync::{Arc, OnceLock};
+
+use memmap2::Mmap;
+
+use crate::fold::fold_for_search;
+#[cfg(test)]
+use crate::indexer::FileEntry;
+use crate::pinyin::PinyinIndex;
+
+const FLAG_DIR: u8 = 1 << 0;
+const FLAG_HIDDEN: u8 = 1 << 1;
+/// 已在界面中删除（移到回收站等）；搜索时跳过，下次重建索引时不再出现。
+const FLAG_REMOVED: u8 = 1 << 2;
+
+/// `parents` 列中表示“没有父目录条目”（卷根或父目录未被索引）。
+pub const NO_PARENT: u32 = u32::MAX;
+
+/// 字符串在连续缓冲区中的位置；`len == 0` 表示空串（小写列里表示“与原文相同”）。
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Span {
+    start: u32,
+    len: u32,
+}
+
+impl Span {
+    const EMPTY: Span = Span { start: 0, len: 0 };
+
+    fn get<'a>(&self, buf: &'a str) -> &'a str {
+        let start = self.start as usize;
+        &buf[start..start + self.len as usize]
+    }
+}
+
+/// 每个条目的定长字段。
+///
+/// `repr(C)` 布局会原样写入 v4 缓存文件并被直接映射，调整字段前需要同时升级缓存版本。
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EntryMeta {
+    pub frn: u64,
+    pub parent_frn: u64,
+    pub size: u64,
+    pub modified_ms: u64,
+    pub drive: u8,
+    pub flags: u8,
+}
+
+const _: () = assert!(std::mem::size_of::<EntryMeta>() == 40);
+const _: () = assert!(std::mem::size_of::<Span>() == 8);
+
+impl EntryMeta {
+    pub fn new(drive: u8, frn: u64, parent_frn: u64, size: u64, modified_ms: u64, is_dir: bool, is_hidden: bool) -> Self {
+        let mut flags = 0u8;
+        if is_dir {
+            flags |= FLAG_DIR;
+        }
+        if is_hidden {
+            flags |= FLAG_HIDDEN;
+        }
+        Self {
+            frn,
+            parent_frn,
+            size,
+            modified_ms,
+            drive,
+            flags,
+        }
+    }
+
+    pub fn is_dir(&self) -> bool {
+        (self.flags & FLAG_DIR) != 0
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        (self.flags & FLAG_HIDDEN) != 0
+    }
+
+    pub fn is_removed(&self) -> bool {
+        (self.flags & FLAG_REMOVED) != 0
+    }
+}
+
+/// 内存占用统计（字节，按容量计）。
+#[derive(Clone, Debug, Default)]
+pub struct IndexMemoryStats {
+    /// 文件名 + 路径原文
+    pub string_bytes: usize,
+    /// 仅在与原文不同时才保存的小写副本
+    pub lower_bytes: usize,
+    /// 偏移表 + 定长字段表 + 父目录链接
+    pub table_bytes: usize,
+    /// 直接映射自缓存文件、不占用堆内存的部分
+    pub mapped_bytes: usize,
+}
+
+impl IndexMemoryStats {
+    pub fn total_bytes(&self) -> usize {
+        self.string_bytes + self.lower_bytes + self.table_bytes
+    }
+}
+
+/// 可以按原始字节写入/映射的定长类型。
+///
+/// # Safety
+/// 实现者必须是 `repr(C)`/基本整数类型，任意字节序列都是合法值。
+pub(crate) unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for Span {}
+unsafe impl Pod for EntryMeta {}
+
+/// 一列定长数据：要么是自有的 `Vec`，要么直接引用 mmap 中的一段。
+#[derive(Clone)]
+pub(crate) enum Column<T: Pod> {
+    Owned(Vec<T>),
+    Mapped {
+        map: Arc<Mmap>,
+        offset: usize,
+        len: usize,
+    },
+}
+
+impl<T: Pod> Default for Column<T> {
+    fn default() -> Self {
+        Column::Owned(Vec::new())
+    }
+}
+
+impl<T: Pod> Column<T> {
+    fn with_capacity(cap: usize) -> Self {
+        Column::Owned(Vec::with_capacity(cap))
+    }
+
+    /// 映射 `map[offset..offset + byte_len]`；越界、长度不整除或未对齐时返回 `None`。
+    pub(crate) fn mapped(map: &Arc<Mmap>, offset: usize, byte_len: usize) -> Option<Self> {
+        let size = std::mem::size_of::<T>();
+        if !byte_len.is_multiple_of(size) {
+            return None;
+        }
+        let end = offset.checked_add(byte_len)?;
+        if end > map.len() {
+            return None;
+        }
+        if !(map.as_ptr() as usize + offset).is_multiple_of(std::mem::align_of::<T>()) {
+            return None;
+        }
+        Some(Column::Mapped {
+            map: Arc::clone(map),
+            offset,
+            len: byte_len / size,
+        })
+    }
+
+    /// 从任意（可能未对齐的）字节复制出一列；长度不整除时返回 `None`。
+    pub(crate) fn copied(bytes: &[u8]) -> Option<Self> {
+        let size = std::mem::size_of::<T>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let len = bytes.len() / size;
+        let mut out: Vec<T> = Vec::with_capacity(len);
+        // SAFETY: `T: Pod` 任意字节都是合法值；目标容量足够，按字节复制不要求源对齐。
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), out.as_mut_ptr() as *mut u8, bytes.len());
+            out.set_len(len);
+        }
+        Some(Column::Owned(out))
+    }
+
+    pub(crate) fn as_slice(&self) -> &[T] {
+        match self {
+            Column::Owned(v) => v,
+            Column::Mapped { map, offset, len } => {
+                // SAFETY: 构造时已检查范围与对齐；`T: Pod` 任意字节都是合法值；mmap 在 `Arc` 存活期间有效。
+                unsafe { std::slice::from_raw_parts(map.as_ptr().add(*offset) as *const T, *len) }
+            }
+        }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        let slice = self.as_slice();
+        // SAFETY: 只读视图；`Pod` 类型按原始字节写出。
+        unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const u8, std::mem::size_of_val(slice)) }
+    }
+
+    /// 写入前转为自有数据（映射列会被整体复制一次）。
+    fn to_mut(&mut self) -> &mut Vec<T> {
+        if let Column::Mapped { .. } = self {
+            *self = Column::Owned(self.as_slice().to_vec());
+        }
+        match self {
+            Column::Owned(v) => v,
+            Column::Mapped { .. } => unreachable!(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Column::Owned(v) => v.len(),
+            Column::Mapped { len, .. } => *len,
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        if let Column::Owned(v) = self {
+            v.shrink_to_fit();
+        }
+    }
+
+    fn heap_bytes(&self) -> usize {
+        match self {
+            Column::Owned(v) => v.capacity() * std::mem::size_of::<T>(),
+            Column::Mapped { .. } => 0,
+        }
+    }
+
+    fn mapped_bytes(&self) -> usize {
+        match self {
+            Column::Owned(_) => 0,
+            Column::Mapped { len, .. } => len * std::mem::size_of::<T>(),
+        }
+    }
+}
+
+/// UTF-8 字节列；不变式：内容始终是合法 UTF-8。
+#[derive(Clone, Default)]
+pub(crate) struct StrColumn(Column<u8>);
+
+impl StrColumn {
+    /// 校验整列为 UTF-8 后接管；校验失败返回 `None`。
+    pub(crate) fn new(column: Column<u8>) -> Option<Self> {
+        std::str::from_utf8(column.as_slice()).ok()?;
+        Some(Self(column))
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // SAFETY: 见类型不变式——自有数据只由 `&str` 追加/按 span 边界截断，外部数据在 `new` 中校验过。
+        unsafe { std::str::from_utf8_unchecked(self.0.as_slice()) }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    fn push_str(&mut self, s: &str) -> Span {
+        if s.is_empty() {
+            return Span::EMPTY;
+        }
+        let buf = self.0.to_mut();
+        let start = buf.len();
+        buf.extend_from_slice(s.as_bytes());
+        Span {
+            start: u32::try_from(start).expect("索引字符串表超过 4 GiB"),
+            len: s.len() as u32,
+        }
+    }
+
+    /// 写入折叠后的副本（见 `fold_for_search`）；折叠结果与原文相同时返回空 span，读取时沿用原文。
+    fn push_lower(&mut self, s: &str) -> Span {
+        match fold_for_search(s) {
+            Cow::Borrowed(_) => Span::EMPTY,
+            Cow::Owned(folded) => self.push_str(&folded),
+        }
+    }
+
+    #[cfg(any(windows, test))]
+    fn truncate_with(&mut self, spans: &mut Column<Span>, len: usize) {
+        // 重命名会把新名称追加到末尾，span 不一定单调；按保留条目的最大结束位置截断
+        let keep_end = spans.as_slice()[..len]
+            .iter()
+            .map(|s| s.start as usize + s.len as usize)
+            .max()
+            .unwrap_or(0);
+        if keep_end < self.0.len() {
+            self.0.to_mut().truncate(keep_end);
+        }
+        spans.to_mut().truncate(len);
+    }
+
+    /// 检查每个 span 都落在缓冲区内且位于字符边界上（加载外部文件时使用）。
+    fn check_spans(&self, spans: &[Span]) -> bool {
+        let buf = self.as_str();
+        spans.iter().all(|s| {
+            let start = s.start as usize;
+            buf.get(start..start + s.len as usize).is_some()
+        })
+    }
+}
+
+#[derive(Default)]
+struct FrnHasher(u64);
+
+impl Hasher for FrnHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut x = self.0;
+        for &b in bytes {
+            x ^= b as u64;
+            x = x.wrapping_mul(0x100_0000_01B3);
+        }
+        self.0 = x;
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        let mut x = i ^ self.0;
+        x ^= x >> 33;
+        x = x.wrapping_mul(0xff51afd7ed558ccd);
+        x ^= x >> 33;
+        x = x.wrapping_mul(0xc4ceb9fe1a85ec53);
+        x ^= x >> 33;
+        self.0 = x;
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64((i >> 64) as u64);
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+type FrnIndex = HashMap<u128, u32, BuildHasherDefault<FrnHasher>>;
+
+fn frn_key(drive: u8, frn: u64) -> u128 {
+    ((drive as u128) << 64) | (frn as u128)
+}
+
+/// 列式索引存储：所有文件名/路径写入同一块连续缓冲区，按偏移引用；
+/// 小写列只在与原文不同时才写入，否则沿用原文。
+///
+/// 相比每个条目持有 4 个 `String`，这里没有逐条目的堆分配，千万级条目时可省下数 GB 内存。
+/// 各列也可以直接映射自 v4 缓存文件（见 `index_file`），此时加载不需要反序列化。
+#[derive(Clone, Default)]
+pub struct EntryStore {
+    names: StrColumn,
+    name_spans: Column<Span>,
+    name_lowers: StrColumn,
+    name_lower_spans: Column<Span>,
+    paths: StrColumn,
+    path_spans: Column<Span>,
+    path_lowers: StrColumn,
+    path_lower_spans: Column<Span>,
+    meta: Column<EntryMeta>,
+    /// 父目录条目下标（`NO_PARENT` 表示没有）；由 `link_parents` 按 (drive, parent_frn) 计算。
+    parents: Column<u32>,
+    parents_linked: bool,
+    /// 文件名的拼音单元；首次拼音搜索时构建，条目变动时丢弃。
+    pinyin: OnceLock<PinyinIndex>,
+}
+
+/// `EntryStore` 的各列，按 v4 缓存文件中的 section 顺序排列。
+pub(crate) struct StoreColumns {
+    pub names: StrColumn,
+    pub name_spans: Column<Span>,
+    pub name_lowers: StrColumn,
+    pub name_lower_spans: Column<Span>,
+    pub paths: StrColumn,
+    pub path_spans: Column<Span>,
+    pub path_lowers: StrColumn,
+    pub path_lower_spans: Column<Span>,
+    pub meta: Column<EntryMeta>,
+    pub parents: Column<u32>,
+}
+
+impl EntryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(entries: usize) -> Self {
+        Self {
+            name_spans: Column::with_capacity(entries),
+            name_lower_spans: Column::with_capacity(entries),
+            path_spans: Column::with_capacity(entries),
+            path_lower_spans: Column::with_capacity(entries),
+            meta: Column::with_capacity(entries),
+            ..Self::default()
+        }
+    }
+
+    #[cfg(test)]
+    pub fn from_entries(entries: Vec<FileEntry>) -> Self {
+        let mut store = Self::with_capacity(entries.len());
+        for entry in &entries {
+            store.push_entry(entry);
+        }
+        store.shrink_to_fit();
+        store
+    }
+
+    /// 由已读取/映射的各列组装；任一列长度不一致或下标越界时返回 `None`。
+    pub(crate) fn from_columns(c: StoreColumns) -> Option<Self> {
+        let len = c.meta.len();
+        let lens = [
+            c.name_spans.len(),
+            c.name_lower_spans.len(),
+            c.path_spans.len(),
+            c.path_lower_spans.len(),
+            c.parents.len(),
+        ];
+        if lens.iter().any(|&l| l != len) {
+            return None;
+        }
+        if !c.names.check_spans(c.name_spans.as_slice())
+            || !c.name_lowers.check_spans(c.name_lower_spans.as_slice())
+            || !c.paths.check_spans(c.path_spans.as_slice())
+            || !c.path_lowers.check_spans(c.path_lower_spans.as_slice())
+        {
+            return None;
+        }
+        if c
+            .parents
+            .as_slice()
+            .iter()
+            .any(|&p| p != NO_PARENT && p as usize >= len)
+        {
+            return None;
+        }
+        Some(Self {
+            names: c.names,
+            name_spans: c.name_spans,
+            name_lowers: c.name_lowers,
+            name_lower_spans: c.name_lower_spans,
+            paths: c.paths,
+            path_spans: c.path_spans,
+            path_lowers: c.path_lowers,
+            path_lower_spans: c.path_lower_spans,
+            meta: c.meta,
+            parents: c.parents,
+            parents_linked: true,
+            pinyin: OnceLock::new(),
+        })
+    }
+
+    /// 按 v4 section 顺序返回除父目录下标外各列的原始字节（父目录见 `parents_column`）。
+    pub(crate) fn column_bytes(&self) -> [&[u8]; 9] {
+        [
+            self.names.as_bytes(),
+            self.name_spans.as_bytes(),
+            self.name_lowers.as_bytes(),
+            self.name_lower_spans.as_bytes(),
+            self.paths.as_bytes(),
+            self.path_spans.as_bytes(),
+            self.path_lowers.as_bytes(),
+            self.path_lower_spans.as_bytes(),
+            self.meta.as_bytes(),
+        ]
+    }
+
+    /// 父目录下标列；尚未链接时临时计算一份。
+    pub(crate) fn parents_column(&self) -> Cow<'_, [u32]> {
+        if self.parents_linked {
+            Cow::Borrowed(self.parents.as_slice())
+        } else {
+            Cow::Owned(self.compute_parents())
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.meta.len()
+    }
+
+    pub fn push(&mut self, name: &str, path: &str, meta: EntryMeta) -> usize {
+        let idx = self.len();
+        let span = self.names.push_str(name);
+        self.name_spans.to_mut().push(span);
+        let span = self.name_lowers.push_lower(name);
+        self.name_lower_spans.to_mut().push(span);
+        let span = self.paths.push_str(path);
+        self.path_spans.to_mut().push(span);
+        let span = self.path_lowers.push_lower(path);
+        self.path_lower_spans.to_mut().push(span);
+        self.meta.to_mut().push(meta);
+        self.parents_linked = false;
+        self.pinyin = OnceLock::new();
+        idx
+    }
+
+    /// 追加一个父目录下标已知的条目（USN 增量中新建的文件）。
+    /// 与 `push` 不同，已链接的父链保持有效，无需重新 `link_parents`。
+    pub fn push_linked(&mut self, name: &str, path: &str, meta: EntryMeta, parent: Option<usize>) -> usize {
+        let linked = self.parents_linked;
+        let idx = self.push(name, path, meta);
+        if linked {
+            self.parents.to_mut().push(parent.map_or(NO_PARENT, |p| p as u32));
+            self.parents_linked = true;
+        }
+        idx
+    }
+
+    /// 追加另一份 WalkDir 扫描结果（界面中移动/复制后在后台扫描的新位置）。
+    /// 合成 id 是扫描结果中的下标 + 1，随追加位置整体平移，父链保持不变。
+    pub fn append_walked(&mut self, walked: &EntryStore) {
+        let base = self.len() as u64;
+        for entry in walked.iter() {
+            let mut meta = *entry.meta();
+            let parent = (meta.parent_frn != 0).then(|| (base + meta.parent_frn - 1) as usize);
+            meta.frn += base;
+            if meta.parent_frn != 0 {
+                meta.parent_frn += base;
+            }
+            self.push_linked(entry.name(), entry.path(), meta, parent);
+        }
+    }
+
+    #[cfg(test)]
+    pub fn push_entry(&mut self, entry: &FileEntry) -> usize {
+        self.push(
+            &entry.name,
+            &entry.path,
+            EntryMeta::new(
+                entry.drive,
+                entry.frn,
+                entry.parent_frn,
+                entry.size,
+                entry.modified_ms,
+                entry.is_dir,
+                entry.is_hidden,
+            ),
+        )
+    }
+
+    /// 丢弃 `len` 之后的条目（用于枚举中途取消/失败时回滚）。
+    #[cfg(any(windows, test))]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        self.names.truncate_with(&mut self.name_spans, len);
+        self.name_lowers.truncate_with(&mut self.name_lower_spans, len);
+        self.paths.truncate_with(&mut self.path_spans, len);
+        self.path_lowers.truncate_with(&mut self.path_lower_spans, len);
+        self.meta.to_mut().truncate(len);
+        self.parents_linked = false;
+        self.pinyin = OnceLock::new();
+    }
+
+    /// 重命名条目。子项路径由父链在展示时拼接，因此重命名目录只需改写这一个条目；
+    /// 自带路径的条目（WalkDir 根、旧缓存）同时改写其路径的最后一段。旧字符串留在缓冲区中不回收。
+    pub fn rename(&mut self, index: usize, new_name: &str) {
+        let span = self.names.push_str(new_name);
+        self.name_spans.to_mut()[index] = span;
+        let span = self.name_lowers.push_lower(new_name);
+        self.name_lower_spans.to_mut()[index] = span;
+        self.pinyin = OnceLock::new();
+
+        let old_path = self.path(index);
+        if old_path.is_empty() {
+            return;
+        }
+        let new_path = match old_path.trim_end_matches('/').rfind('/') {
+            Some(i) => format!("{}{new_name}", &old_path[..=i]),
+            None => new_name.to_string(),
+        };
+        let span = self.paths.push_str(&new_path);
+        self.path_spans.to_mut()[index] = span;
+        let span = self.path_lowers.push_lower(&new_path);
+        self.path_lower_spans.to_mut()[index] = span;
+    }
+
+    /// 把条目移到另一个目录下（USN 移动事件）；`parent` 为新父目录的下标，位于卷根时为 `None`。
+    #[cfg(windows)]
+    pub fn set_parent(&mut self, index: usize, parent_frn: u64, parent: Option<usize>) {
+        self.meta.to_mut()[index].parent_frn = parent_frn;
+        if self.parents_linked {
+            self.parents.to_mut()[index] = parent.map_or(NO_PARENT, |p| p as u32);
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn set_attributes(&mut self, index: usize, is_dir: bool, is_hidden: bool) {
+        let meta = &mut self.meta.to_mut()[index];
+        meta.flags &= !(FLAG_DIR | FLAG_HIDDEN);
+        if is_dir {
+            meta.flags |= FLAG_DIR;
+        }
+        if is_hidden {
+            meta.flags |= FLAG_HIDDEN;
+        }
+    }
+
+    /// 标记条目已删除；目录连同其下所有条目（按父链判断）一起标记。条目本身不移除，下标保持不变。
+    /// 一批删除应一次传入：其中有目录时，整批只按父链扫描全部条目一遍。
+    pub fn mark_removed(&mut self, indices: &[usize]) {
+        let meta = self.meta.to_mut();
+        let mut has_dir = false;
+        for &i in indices {
+            has_dir |= meta[i].is_dir();
+            meta[i].flags |= FLAG_REMOVED;
+        }
+        if !has_dir || !self.parents_linked {
+            return;
+        }
+
+        // 每个条目沿父链向上，直到遇到已标记删除或已确定状态的祖先，途经的条目一并定下结果；
+        // 因此每个条目只走一次，总代价与条目数成正比。
+        const UNKNOWN: u8 = 0;
+        const ALIVE: u8 = 1;
+        const REMOVED: u8 = 2;
+        const VISITING: u8 = 3;
+        let parents = self.parents.as_slice();
+        let mut state = vec![UNKNOWN; meta.len()];
+        let mut chain = Vec::new();
+        for i in 0..meta.len() {
+            let mut cur = i;
+            let removed = loop {
+                match state[cur] {
+                    REMOVED => break true,
+                    // 父链异常成环时视为未删除
+                    ALIVE | VISITING => break false,
+                    _ => {}
+                }
+                if meta[cur].is_removed() {
+                    state[cur] = REMOVED;
+                    break true;
+                }
+                state[cur] = VISITING;
+                chain.push(cur);
+                match parents[cur] {
+                    NO_PARENT => break false,
+                    p => cur = p as usize,
+                }
+            };
+            for c in chain.drain(..) {
+                state[c] = if removed { REMOVED } else { ALIVE };
+                if removed {
+                    meta[c].flags |= FLAG_REMOVED;
+                }
+            }
+        }
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.names.0.shrink_to_fit();
+        self.name_spans.shrink_to_fit();
+        self.name_lowers.0.shrink_to_fit();
+        self.name_lower_spans.shrink_to_fit();
+        self.paths.0.shrink_to_fit();
+        self.path_spans.shrink_to_fit();
+        self.path_lowers.0.shrink_to_fit();
+        self.path_lower_spans.shrink_to_fit();
+        self.meta.shrink_to_fit();
+        self.parents.shrink_to_fit();
+    }
+
+    /// 按当前折叠规则重建两列小写字符串（加载旧规则写入的缓存时使用）。
+    pub(crate) fn refold_lowers(&mut self) {
+        let mut name_lowers = StrColumn::default();
+        let mut path_lowers = StrColumn::default();
+        let mut name_lower_spans = Column::with_capacity(self.len());
+        let mut path_lower_spans = Column::with_capacity(self.len());
+        for i in 0..self.len() {
+            name_lower_spans.to_mut().push(name_lowers.push_lower(self.name(i)));
+            path_lower_spans.to_mut().push(path_lowers.push_lower(self.path(i)));
+        }
+        self.name_lowers = name_lowers;
+        self.name_lower_spans = name_lower_spans;
+        self.path_lowers = path_lowers;
+        self.path_lower_spans = path_lower_spans;
+        self.pinyin = OnceLock::new();
+    }
+
+    pub fn parents_linked(&self) -> bool {
+        self.parents_linked
+    }
+
+    /// 按 (drive, parent_frn) 为每个条目找到父目录条目下标，写入 `parents` 列。
+    pub fn link_parents(&mut self) {
+        self.parents = Column::Owned(self.compute_parents());
+        self.parents_linked = true;
+    }
+
+    fn compute_parents(&self) -> Vec<u32> {
+        let meta = self.meta.as_slice();
+        let mut dirs = FrnIndex::default();
+        dirs.reserve(meta.len().saturating_div(8).max(1024));
+        for (idx, m) in meta.iter().enumerate() {
+            if m.frn != 0 && m.is_dir() {
+                dirs.insert(frn_key(m.drive, m.frn), idx as u32);
+            }
+        }
+        meta.iter()
+            .enumerate()
+            .map(|(idx, m)| {
+                if m.parent_frn == 0 {
+                    return NO_PARENT;
+                }
+                match dirs.get(&frn_key(m.drive, m.parent_frn)) {
+                    // NTFS 卷根目录的父 FRN 指向自身
+                    Some(&p) if p as usize != idx => p,
+                    _ => NO_PARENT,
+                }
+            })
+            .collect()
+    }
+
+    pub fn get(&self, index: usize) -> Option<EntryRef<'_>> {
+        if index < self.len() {
+            Some(EntryRef { store: self, index })
+        } else {
+            None
+        }
+    }
+
+    /// 调用方需保证 `index < len()`。
+    pub fn entry(&self, index: usize) -> EntryRef<'_> {
+        debug_assert!(index < self.len());
+        EntryRef { store: self, index }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = EntryRef<'_>> + '_ {
+        (0..self.len()).map(move |index| EntryRef { store: self, index })
+    }
+
+    pub fn name(&self, index: usize) -> &str {
+        self.name_spans.as_slice()[index].get(self.names.as_str())
+    }
+
+    /// 折叠后的文件名（小写、NFC、全半角统一）；与原文相同时直接返回原文。
+    pub fn name_lower(&self, index: usize) -> &str {
+        let span = self.name_lower_spans.as_slice()[index];
+        if span.len == 0 {
+            self.name(index)
+        } else {
+            span.get(self.name_lowers.as_str())
+        }
+    }
+
+    pub fn path(&self, index: usize) -> &str {
+        self.path_spans.as_slice()[index].get(self.paths.as_str())
+    }
+
+    pub fn path_lower(&self, index: usize) -> &str {
+        let span = self.path_lower_spans.as_slice()[index];
+        if span.len == 0 {
+            self.path(index)
+        } else {
+            span.get(self.path_lowers.as_str())
+        }
+    }
+
+    pub fn meta(&self, index: usize) -> &EntryMeta {
+        &self.meta.as_slice()[index]
+    }
+
+    /// 父目录条目下标；尚未 `link_parents` 或没有父目录时返回 `None`。
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        if !self.parents_linked {
+            return None;
+        }
+        match self.parents.as_slice()[index] {
+            NO_PARENT => None,
+            p => Some(p as usize),
+        }
+    }
+
+    /// 各条目文件名的拼音单元，首次调用时按当前条目构建。
+    pub fn pinyin_index(&self) -> &PinyinIndex {
+        self.pinyin
+            .get_or_init(|| PinyinIndex::build((0..self.len()).map(|i| self.name_lower(i))))
+    }
+
+    /// 已标记删除的条目不导出。
+    #[cfg(test)]
+    pub fn to_entries(&self) -> Vec<FileEntry> {
+        self.iter().filter(|e| !e.is_removed()).map(|e| e.to_entry()).collect()
+    }
+
+    pub fn memory_stats(&self) -> IndexMemoryStats {
+        let strings = [&self.names, &self.paths];
+        let lowers = [&self.name_lowers, &self.path_lowers];
+        let spans = [
+            &self.name_spans,
+            &self.name_lower_spans,
+            &self.path_spans,
+            &self.path_lower_spans,
+        ];
+        IndexMemoryStats {
+            string_bytes: strings.iter().map(|c| c.0.heap_bytes()).sum(),
+            lower_bytes: lowers.iter().map(|c| c.0.heap_bytes()).sum(),
+            table_bytes: spans.iter().map(|c| c.heap_bytes()).sum::<usize>()
+                + self.meta.heap_bytes()
+                + self.parents.heap_bytes()
+                + self.pinyin.get().map_or(0, PinyinIndex::heap_bytes),
+            mapped_bytes: strings
+                .iter()
+                .chain(lowers.iter())
+                .map(|c| c.0.mapped_bytes())
+                .sum::<usize>()
+                + spans.iter().map(|c| c.mapped_bytes()).sum::<usize>()
+                + self.meta.mapped_bytes()
+                + self.parents.mapped_bytes(),
+        }
+    }
+}
+
+/// 按最后一个点拆成 (主名, 扩展名)，扩展名不含点；没有点、点在开头（如 ".gitignore"）或在结尾时扩展名为空。
+pub fn split_extension(name: &str) -> (&str, &str) {
+    match name.rfind('.') {
+        Some(dot) if dot > 0 && dot + 1 < name.len() => (&name[..dot], &name[dot + 1..]),
+        _ => (name, ""),
+    }
+}
+
+/// 对 `EntryStore` 中单个条目的借用视图。
+#[derive(Clone, Copy)]
+pub struct EntryRef<'a> {
+    store: &'a EntryStore,
+    index: usize,
+}
+
+impl<'a> EntryRef<'a> {
+    pub fn index(self) -> usize {
+        self.index
+    }
+
+    pub fn name(self) -> &'a str {
+        self.store.name(self.index)
+    }
+
+    pub fn name_lower(self) -> &'a str {
+        self.store.name_lower(self.index)
+    }
+
+    pub fn path(self) -> &'a str {
+        self.store.path(self.index)
+    }
+
+    pub fn path_lower(self) -> &'a str {
+        self.store.path_lower(self.index)
+    }
+
+    pub fn meta(self) -> &'a EntryMeta {
+        self.store.meta(self.index)
+    }
+
+    pub fn parent(self) -> Option<EntryRef<'a>> {
+        self.store.parent(self.index).map(|index| EntryRef {
+            store: self.store,
+            index,
+        })
+    }
+
+    pub fn drive(self) -> u8 {
+        self.meta().drive
+    }
+
+    pub fn frn(self) -> u64 {
+        self.meta().frn
+    }
+
+    pub fn size(self) -> u64 {
+        self.meta().size
+    }
+
+    pub fn modified_ms(self) -> u64 {
+        self.meta().modified_ms
+    }
+
+    pub fn is_dir(self) -> bool {
+        self.meta().is_dir()
+    }
+
+    pub fn is_hidden(self) -> bool {
+        self.meta().is_hidden()
+    }
+
+    pub fn is_removed(self) -> bool {
+        self.meta().is_removed()
+    }
+
+    /// 折叠后的扩展名（不含点）；目录没有扩展名。
+    pub fn extension_lower(self) -> &'a str {
+        if self.is_dir() {
+            return "";
+        }
+        split_extension(self.name_lower()).1
+    }
+
+    #[cfg(test)]
+    pub fn to_entry(self) -> FileEntry {
+        let meta = self.meta();
+        FileEntry {
+            name: self.name().to_string(),
+            path: self.path().to_string(),
+            drive: meta.drive,
+            frn: meta.frn,
+            parent_frn: meta.parent_frn,
+            size: meta.size,
+            modified_ms: meta.modified_ms,
+            is_dir: meta.is_dir(),
+            is_hidden: meta.is_hidden(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowercase_column_only_stores_differing_names() {
+        let mut store = EntryStore::new();
+        store.push("readme.md", "/tmp/readme.md", EntryMeta::default());
+        store.push("Cargo.toml", "/tmp/Cargo.toml", EntryMeta::default());
+        store.push("报告.docx", "/tmp/报告.docx", EntryMeta::default());
+
+        assert_eq!(store.name_lower(0), "readme.md");
+        assert_eq!(store.name_lower(1), "cargo.toml");
+        assert_eq!(store.name_lower(2), "报告.docx");
+        assert_eq!(store.name_lowers.as_str(), "cargo.toml");
+    }
+
+    #[test]
+    fn lowercase_column_folds_non_ascii_names() {
+        let mut store = EntryStore::new();
+        store.push("ÄNDERUNG.txt", "", EntryMeta::default());
+        store.push("a\u{308}nderung.txt", "", EntryMeta::default());
+        store.push("änderung.txt", "", EntryMeta::default());
+
+        assert_eq!(store.name_lower(0), "änderung.txt");
+        assert_eq!(store.name_lower(1), "änderung.txt");
+        assert_eq!(store.name_lowers.as_str(), "änderung.txtänderung.txt");
+    }
+
+    #[test]
+    fn truncate_rolls_back_string_buffers() {
+        let mut store = EntryStore::new();
+        store.push("a.txt", "/a.txt", EntryMeta::default());
+        store.push("B.txt", "/B.txt", EntryMeta::new(1, 2, 3, 4, 5, true, false));
+        store.truncate(1);
+
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.names.as_str(), "a.txt");
+        assert!(store.name_lowers.as_str().is_empty());
+
+        store.push("c.txt", "/c.txt", EntryMeta::default());
+        assert_eq!(store.name(1), "c.txt");
+        assert_eq!(store.path(1), "/c.txt");
+    }
+
+    #[test]
+    fn link_parents_resolves_by_drive_and_frn() {
+        let mut store = EntryStore::new();
+        store.push("dir", "", EntryMeta::new(b'C', 10, 5, 0, 0, true, false));
+        store.push("file.txt", "", EntryMeta::new(b'C', 11, 10, 0, 0, false, false));
+        store.push("other.txt", "", EntryMeta::new(b'D', 12, 10, 0, 0, false, false));
+        store.link_parents();
+
+        assert_eq!(store.parent(0), None);
+        assert_eq!(store.parent(1), Some(0));
+        assert_eq!(store.parent(2), None);
+    }
+
+    #[test]
+    fn removing_a_directory_marks_its_descendants() {
+        let mut store = EntryStore::new();
+        store.push("dir", "", EntryMeta::new(b'C', 10, 5, 0, 0, true, false));
+        store.push("sub", "", EntryMeta::new(b'C', 11, 10, 0, 0, true, false));
+        store.push("deep.txt", "", EntryMeta::new(b'C', 12, 11, 0, 0, false, false));
+        store.push("sibling.txt", "", EntryMeta::new(b'C', 13, 5, 0, 0, false, false));
+        store.link_parents();
+
+        store.mark_removed(&[0]);
+        let removed: Vec<bool> = store.iter().map(|e| e.is_removed()).collect();
+        assert_eq!(removed, [true, true, true, false]);
+        assert_eq!(store.len(), 4);
+        assert_eq!(store.to_entries().len(), 1);
+    }
+}
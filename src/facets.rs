@@ -0,0 +1,435 @@
+//! 搜索结果分面：按文件类型、扩展名、所在文件夹、大小与修改时间统计全部匹配条目（截取 top-k 之前），
+//! 界面据此提供可点击的筛选项。
+
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::categories::{CategoryRegistry, FileCategory};
+use crate::entry_store::EntryRef;
+use crate::fold::fold_for_search;
+use crate::indexer::FileIndexer;
+
+/// 扩展名与文件夹分面最多保留的取值数。
+const MAX_EXTENSIONS: usize = 20;
+const MAX_FOLDERS: usize = 10;
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FileTypeFilter {
+    #[default]
+    All,
+    Files,
+    Folders,
+    /// 按 `CategoryRegistry` 中的分类 `id` 筛选；分类不存在时不匹配任何条目。
+    Category(String),
+}
+
+impl FileTypeFilter {
+    pub const BASIC: [FileTypeFilter; 3] = [FileTypeFilter::All, FileTypeFilter::Files, FileTypeFilter::Folders];
+
+    pub fn label(&self, categories: &CategoryRegistry) -> String {
+        match self {
+            FileTypeFilter::All => "全部".to_string(),
+            FileTypeFilter::Files => "仅文件".to_string(),
+            FileTypeFilter::Folders => "仅文件夹".to_string(),
+            FileTypeFilter::Category(id) => categories.get(id).map_or_else(|| id.clone(), FileCategory::label),
+        }
+    }
+
+    /// `type:` 前缀的取值：文件/文件夹关键字，或分类的 `id`/名称。
+    pub fn parse(value: &str, categories: &CategoryRegistry) -> Self {
+        match value {
+            "file" | "files" | "文件" => FileTypeFilter::Files,
+            "folder" | "folders" | "dir" | "文件夹" => FileTypeFilter::Folders,
+            _ => FileTypeFilter::Category(categories.lookup(value).map_or_else(|| value.to_string(), |c| c.id.clone())),
+        }
+    }
+
+    pub(crate) fn resolve<'a>(&self, categories: &'a CategoryRegistry) -> TypeMatcher<'a> {
+        match self {
+            FileTypeFilter::All => TypeMatcher::All,
+            FileTypeFilter::Files => TypeMatcher::Files,
+            FileTypeFilter::Folders => TypeMatcher::Folders,
+            FileTypeFilter::Category(id) => TypeMatcher::Category(categories.get(id)),
+        }
+    }
+}
+
+/// 解析后的类型条件。
+pub(crate) enum TypeMatcher<'a> {
+    All,
+    Files,
+    Folders,
+    Category(Option<&'a FileCategory>),
+}
+
+impl TypeMatcher<'_> {
+    /// 只凭条目自身字段能下结论时返回结果；没有扩展名、需要读取文件头才能判断分类时返回 `None`。
+    pub(crate) fn quick(&self, entry: EntryRef<'_>) -> Option<bool> {
+        match self {
+            TypeMatcher::All => Some(true),
+            TypeMatcher::Files => Some(!entry.is_dir()),
+            TypeMatcher::Folders => Some(entry.is_dir()),
+            TypeMatcher::Category(None) => Some(false),
+            TypeMatcher::Category(Some(category)) => {
+                if entry.is_dir() {
+                    return Some(false);
+                }
+                let extension = entry.extension_lower();
+                if !extension.is_empty() {
+                    return Some(category.has_extension(extension));
+                }
+                category.magic.is_empty().then_some(false)
+            }
+        }
+    }
+
+    /// 分类只包含文件：先按扩展名判断，没有扩展名时才读取文件头。
+    pub(crate) fn matches(&self, indexer: &FileIndexer, entry: EntryRef<'_>) -> bool {
+        match (self.quick(entry), self) {
+            (Some(matched), _) => matched,
+            (None, TypeMatcher::Category(Some(category))) => {
+                category.matches_header(&indexer.display_path_for(entry.index()))
+            }
+            (None, _) => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SizeBucket {
+    Empty,
+    Tiny,
+    Small,
+    Medium,
+    Large,
+    Huge,
+    Gigantic,
+}
+
+impl SizeBucket {
+    pub const ALL: [SizeBucket; 7] = [
+        SizeBucket::Empty,
+        SizeBucket::Tiny,
+        SizeBucket::Small,
+        SizeBucket::Medium,
+        SizeBucket::Large,
+        SizeBucket::Huge,
+        SizeBucket::Gigantic,
+    ];
+
+    /// 大小未知（USN 枚举不读取大小，记为 `u64::MAX`）时返回 `None`。
+    pub fn of(size: u64) -> Option<Self> {
+        const KB: u64 = 1024;
+        const MB: u64 = 1024 * KB;
+        if size == u64::MAX {
+            return None;
+        }
+        Some(match size {
+            0 => SizeBucket::Empty,
+            1..=10_240 => SizeBucket::Tiny,
+            s if s <= 100 * KB => SizeBucket::Small,
+            s if s <= MB => SizeBucket::Medium,
+            s if s <= 16 * MB => SizeBucket::Large,
+            s if s <= 128 * MB => SizeBucket::Huge,
+            _ => SizeBucket::Gigantic,
+        })
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SizeBucket::Empty => "空 (0 KB)",
+            SizeBucket::Tiny => "极小 (≤10 KB)",
+            SizeBucket::Small => "小 (≤100 KB)",
+            SizeBucket::Medium => "中 (≤1 MB)",
+            SizeBucket::Large => "大 (≤16 MB)",
+            SizeBucket::Huge => "巨大 (≤128 MB)",
+            SizeBucket::Gigantic => "特大 (>128 MB)",
+        }
+    }
+}
+
+/// 按距今时间划分的修改时间档位。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DateBucket {
+    Today,
+    ThisWeek,
+    ThisMonth,
+    ThisYear,
+    Older,
+}
+
+impl DateBucket {
+    pub const ALL: [DateBucket; 5] = [
+        DateBucket::Today,
+        DateBucket::ThisWeek,
+        DateBucket::ThisMonth,
+        DateBucket::ThisYear,
+        DateBucket::Older,
+    ];
+
+    /// 没有修改时间（为 0，如 USN 枚举的条目）时返回 `None`。
+    pub fn of(modified_ms: u64, now_ms: u64) -> Option<Self> {
+        if modified_ms == 0 {
+            return None;
+        }
+        Some(match now_ms.saturating_sub(modified_ms) / DAY_MS {
+            0 => DateBucket::Today,
+            1..7 => DateBucket::ThisWeek,
+            7..31 => DateBucket::ThisMonth,
+            31..366 => DateBucket::ThisYear,
+            _ => DateBucket::Older,
+        })
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DateBucket::Today => "24 小时内",
+            DateBucket::ThisWeek => "7 天内",
+            DateBucket::ThisMonth => "30 天内",
+            DateBucket::ThisYear => "一年内",
+            DateBucket::Older => "更早",
+        }
+    }
+}
+
+/// 结果筛选条件；作为 `SearchOptions` 的一部分在扫描中应用（先筛选再截取 top-k）。
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchFilters {
+    pub file_type: FileTypeFilter,
+    /// 扩展名（可带点、不区分大小写）；为空时不限。
+    pub extension: String,
+    /// 只保留位于该文件夹（任意层级）下的条目。
+    pub folder: Option<String>,
+    /// 只保留位于这些目录之一下的条目；为空时不限（保存的搜索用它限定搜索范围）。
+    pub roots: Vec<String>,
+    pub size: Option<SizeBucket>,
+    pub date: Option<DateBucket>,
+    pub show_hidden: bool,
+}
+
+impl SearchFilters {
+    /// 为一次搜索预先解析类型、规范化扩展名并固定“当前时间”。
+    pub(crate) fn prepare<'a>(&'a self, categories: &'a CategoryRegistry, now_ms: u64) -> EntryFilter<'a> {
+        EntryFilter {
+            filters: self,
+            file_type: self.file_type.resolve(categories),
+            extension: fold_for_search(self.extension.trim().trim_start_matches('.')),
+            now_ms,
+        }
+    }
+}
+
+pub(crate) struct EntryFilter<'a> {
+    filters: &'a SearchFilters,
+    file_type: TypeMatcher<'a>,
+    extension: Cow<'a, str>,
+    now_ms: u64,
+}
+
+impl EntryFilter<'_> {
+    /// 只看条目自身字段的条件（不读文件、不拼路径），在给查询打分之前检查。
+    /// 大小或修改时间未知的条目不满足对应的档位条件。
+    pub(crate) fn prefilter(&self, entry: EntryRef<'_>) -> bool {
+        let f = self.filters;
+        !entry.is_removed()
+            && (f.show_hidden || !entry.is_hidden())
+            && (self.extension.is_empty() || entry.extension_lower() == self.extension)
+            && f.size.is_none_or(|b| !entry.is_dir() && SizeBucket::of(entry.size()) == Some(b))
+            && f.date.is_none_or(|b| DateBucket::of(entry.modified_ms(), self.now_ms) == Some(b))
+            && self.file_type.quick(entry) != Some(false)
+    }
+
+    /// 其余条件：按文件头判断分类、文件夹与目录范围。需要读文件或拼接展示路径，
+    /// 只对已通过 `prefilter` 且匹配查询的条目检查。
+    pub(crate) fn matches(&self, indexer: &FileIndexer, entry: EntryRef<'_>) -> bool {
+        let f = self.filters;
+        if !self.file_type.matches(indexer, entry) {
+            return false;
+        }
+        if f.folder.is_none() && f.roots.is_empty() {
+            return true;
+        }
+        let path = indexer.display_path_for(entry.index());
+        f.folder.as_deref().is_none_or(|folder| in_folder(&path, folder))
+            && (f.roots.is_empty() || f.roots.iter().any(|root| in_folder(&path, root)))
+    }
+}
+
+/// `path` 是否位于 `folder` 之下（任意层级）。
+fn in_folder(path: &str, folder: &str) -> bool {
+    let folder = folder.trim_end_matches(['/', '\\']);
+    path.strip_prefix(folder)
+        .is_some_and(|rest| rest.starts_with(['/', '\\']))
+}
+
+/// 满足筛选条件的全部匹配结果（截取前 `max_results` 之前）的分面计数。扩展名与文件夹按数量从多到少排列，
+/// 类型、大小与时间按档位顺序排列；数量为 0 的取值不列出。
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Facets {
+    /// 文件、文件夹及各分类的命中数（分类只按扩展名统计，不读取文件头）。
+    pub types: Vec<(FileTypeFilter, usize)>,
+    /// 扩展名（折叠后、不含点）及命中数；目录与无扩展名的文件不计入。
+    pub extensions: Vec<(String, usize)>,
+    /// 所在文件夹的展示路径及命中数。
+    pub folders: Vec<(String, usize)>,
+    /// 文件大小档位（目录与大小未知的条目不计入）。
+    pub sizes: Vec<(SizeBucket, usize)>,
+    /// 修改时间档位（时间未知的条目不计入）。
+    pub dates: Vec<(DateBucket, usize)>,
+}
+
+/// 所在文件夹：NTFS 枚举的条目记录父条目下标，按需拼接路径；自带路径的条目直接截取父目录部分。
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Folder<'a> {
+    Entry(usize),
+    Path(&'a str),
+}
+
+/// 扫描时累加分面计数；键直接借用索引中的字符串，避免逐条分配。
+pub(crate) struct FacetCounter<'a> {
+    now_ms: u64,
+    categories: &'a CategoryRegistry,
+    /// 下标 0 为文件、1 为文件夹，其后依次为各分类。
+    types: Vec<usize>,
+    extensions: HashMap<&'a str, usize>,
+    folders: HashMap<Folder<'a>, usize>,
+    sizes: HashMap<SizeBucket, usize>,
+    dates: HashMap<DateBucket, usize>,
+}
+
+impl<'a> FacetCounter<'a> {
+    pub(crate) fn new(now_ms: u64, categories: &'a CategoryRegistry) -> Self {
+        Self {
+            now_ms,
+            categories,
+            types: vec![0; 2 + categories.categories.len()],
+            extensions: HashMap::new(),
+            folders: HashMap::new(),
+            sizes: HashMap::new(),
+            dates: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, entry: EntryRef<'a>) {
+        let extension = entry.extension_lower();
+        if entry.is_dir() {
+            self.types[1] += 1;
+        } else {
+            self.types[0] += 1;
+            for (i, category) in self.categories.categories.iter().enumerate() {
+                if category.has_extension(extension) {
+                    self.types[2 + i] += 1;
+                }
+            }
+        }
+
+        if !extension.is_empty() {
+            *self.extensions.entry(extension).or_default() += 1;
+        }
+
+        let folder = match entry.parent() {
+            Some(parent) => Some(Folder::Entry(parent.index())),
+            None => parent_dir(entry.path()).map(Folder::Path),
+        };
+        if let Some(folder) = folder {
+            *self.folders.entry(folder).or_default() += 1;
+        }
+
+        // 大小或时间未知的条目不计入对应档位；全部未知时该维度为空，界面不显示
+        if let Some(bucket) = SizeBucket::of(entry.size()).filter(|_| !entry.is_dir()) {
+            *self.sizes.entry(bucket).or_default() += 1;
+        }
+        if let Some(bucket) = DateBucket::of(entry.modified_ms(), self.now_ms) {
+            *self.dates.entry(bucket).or_default() += 1;
+        }
+    }
+
+    fn type_counts(&self) -> Vec<(FileTypeFilter, usize)> {
+        let keys = [FileTypeFilter::Files, FileTypeFilter::Folders]
+            .into_iter()
+            .chain(self.categories.categories.iter().map(|c| FileTypeFilter::Category(c.id.clone())));
+        keys.zip(self.types.iter().copied()).filter(|&(_, n)| n > 0).collect()
+    }
+
+    pub(crate) fn finish(self, indexer: &FileIndexer) -> Facets {
+        let types = self.type_counts();
+        let mut folders: Vec<(Folder<'_>, usize)> = self.folders.into_iter().collect();
+        folders.sort_by_key(|f| Reverse(f.1));
+        // 只为排在前面的文件夹拼接路径；同一路径可能同时以两种形式出现（如根条目），合并计数。
+        let mut folder_counts: HashMap<String, usize> = HashMap::new();
+        for (folder, count) in folders.into_iter().take(MAX_FOLDERS * 2) {
+            let path = match folder {
+                Folder::Entry(index) => indexer.display_path_for(index),
+                Folder::Path(path) => path.to_string(),
+            };
+            if !path.is_empty() {
+                *folder_counts.entry(path).or_default() += count;
+            }
+        }
+
+        Facets {
+            types,
+            extensions: top_counts(self.extensions.into_iter().map(|(k, n)| (k.to_string(), n)), MAX_EXTENSIONS),
+            folders: top_counts(folder_counts.into_iter(), MAX_FOLDERS),
+            sizes: in_order(&SizeBucket::ALL, &self.sizes),
+            dates: in_order(&DateBucket::ALL, &self.dates),
+        }
+    }
+}
+
+fn parent_dir(path: &str) -> Option<&str> {
+    let trimmed = path.trim_end_matches(['/', '\\']);
+    let cut = trimmed.rfind(['/', '\\'])?;
+    let dir = &trimmed[..cut];
+    // 根目录保留分隔符（"C:/"、"/"）。
+    if dir.is_empty() || dir.ends_with(':') {
+        Some(&trimmed[..=cut])
+    } else {
+        Some(dir)
+    }
+}
+
+fn top_counts(counts: impl Iterator<Item = (String, usize)>, limit: usize) -> Vec<(String, usize)> {
+    let mut out: Vec<(String, usize)> = counts.collect();
+    out.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    out.truncate(limit);
+    out
+}
+
+fn in_order<K: Copy + Eq + std::hash::Hash>(order: &[K], counts: &HashMap<K, usize>) -> Vec<(K, usize)> {
+    order
+        .iter()
+        .filter_map(|k| counts.get(k).map(|&n| (*k, n)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_and_parent_dirs() {
+        assert_eq!(SizeBucket::of(0), Some(SizeBucket::Empty));
+        assert_eq!(SizeBucket::of(10_240), Some(SizeBucket::Tiny));
+        assert_eq!(SizeBucket::of(10_241), Some(SizeBucket::Small));
+        assert_eq!(SizeBucket::of(200 << 20), Some(SizeBucket::Gigantic));
+        assert_eq!(SizeBucket::of(u64::MAX), None);
+
+        let now = 1000 * DAY_MS;
+        assert_eq!(DateBucket::of(now - 1, now), Some(DateBucket::Today));
+        assert_eq!(DateBucket::of(now - 3 * DAY_MS, now), Some(DateBucket::ThisWeek));
+        assert_eq!(DateBucket::of(now - 400 * DAY_MS, now), Some(DateBucket::Older));
+        assert_eq!(DateBucket::of(0, now), None);
+
+        assert_eq!(parent_dir("C:/doc/a.txt"), Some("C:/doc"));
+        assert_eq!(parent_dir("C:/a.txt"), Some("C:/"));
+        assert_eq!(parent_dir("/a.txt"), Some("/"));
+        assert_eq!(parent_dir("a.txt"), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! 对搜索结果的文件操作：移到回收站、重命名、复制/移动到文件夹，以及依赖资源管理器的系统菜单、属性、打开方式与以文件形式复制。
+//! 路径使用索引中的展示形式（`/` 分隔）。
+
+use std::io;
+#[cfg(not(windows))]
+use std::path::{Path, PathBuf};
+
+/// 移到回收站（Windows 回收站、macOS 废纸篓或 freedesktop Trash），不直接删除。
+pub fn move_to_trash(path: &str) -> io::Result<()> {
+    if std::fs::symlink_metadata(path).is_err() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "文件不存在"));
+    }
+    trash_impl(path)
+}
+
+#[cfg(windows)]
+fn trash_impl(path: &str) -> io::Result<()> {
+    use std::os::windows::ffi::OsStrExt;
+    use winapi::um::shellapi::{
+        FOF_ALLOWUNDO, FOF_NOCONFIRMATION, FOF_NOERRORUI, FOF_SILENT, FO_DELETE, SHFILEOPSTRUCTW, SHFileOperationW,
+    };
+
+    // pFrom 是以两个 NUL 结尾的路径列表
+    let mut from: Vec<u16> = std::ffi::OsStr::new(&path.replace('/', "\\")).encode_wide().collect();
+    from.extend([0, 0]);
+    let mut op = SHFILEOPSTRUCTW {
+        hwnd: std::ptr::null_mut(),
+        wFunc: FO_DELETE as u32,
+        pFrom: from.as_ptr(),
+        pTo: std::ptr::null(),
+        fFlags: FOF_ALLOWUNDO | FOF_NOCONFIRMATION | FOF_SILENT | FOF_NOERRORUI,
+        fAnyOperationsAborted: 0,
+        hNameMappings: std::ptr::null_mut(),
+        lpszProgressTitle: std::ptr::null(),
+    };
+    let code = unsafe { SHFileOperationW(&mut op) };
+    if code != 0 || op.fAnyOperationsAborted != 0 {
+        return Err(io::Error::other(format!("SHFileOperation 失败（代码 {code}）")));
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn trash_impl(path: &str) -> io::Result<()> {
+    let home = std::env::var_os("HOME").ok_or_else(|| io::Error::other("找不到用户目录"))?;
+    let trash = PathBuf::from(home).join(".Trash");
+    let target = unique_target(&trash, file_name(path)?);
+    std::fs::rename(path, target)
+}
+
+/// freedesktop Trash 规范：文件移入 `Trash/files`，在 `Trash/info` 写入原路径与删除时间。
+/// 与主目录不在同一文件系统时 `rename` 失败，直接返回错误而不是复制后删除。
+#[cfg(all(not(windows), not(target_os = "macos")))]
+fn trash_impl(path: &str) -> io::Result<()> {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))
+        .ok_or_else(|| io::Error::other("找不到用户目录"))?;
+    let trash = data_home.join("Trash");
+    let files = trash.join("files");
+    let info = trash.join("info");
+    std::fs::create_dir_all(&files)?;
+    std::fs::create_dir_all(&info)?;
+
+    let target = unique_target(&files, file_name(path)?);
+    let trashed_name = target.file_name().unwrap_or_default().to_string_lossy().into_owned();
+    let absolute = std::fs::canonicalize(path)?;
+    let info_path = info.join(format!("{trashed_name}.trashinfo"));
+    std::fs::write(
+        &info_path,
+        format!(
+            "[Trash Info]\nPath={}\nDeletionDate={}\n",
+            percent_encode(&absolute.to_string_lossy()),
+            chrono::Local::now().format("%Y-%m-%dT%H:%M:%S")
+        ),
+    )?;
+    std::fs::rename(path, &target).inspect_err(|_| {
+        let _ = std::fs::remove_file(&info_path);
+    })
+}
+
+#[cfg(not(windows))]
+fn file_name(path: &str) -> io::Result<&str> {
+    Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "无效的路径"))
+}
+
+/// `dir/name` 已存在时依次尝试 "name (2)"、"name (3)"……
+#[cfg(not(windows))]
+fn unique_target(dir: &Path, name: &str) -> PathBuf {
+    let (stem, ext) = crate::entry_store::split_extension(name);
+    (1..)
+        .map(|n| match (n, ext) {
+            (1, _) => dir.join(name),
+            (_, "") => dir.join(format!("{stem} ({n})")),
+            _ => dir.join(format!("{stem} ({n}).{ext}")),
+        })
+        .find(|p| std::fs::symlink_metadata(p).is_err())
+        .unwrap_or_else(|| dir.join(name))
+}
+
+/// 按 UTF-8 字节百分号编码，保留路径分隔符与盘符冒号。
+fn percent_encode(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' | b':' => out.push(b as char),
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+/// 在原目录内重命名，返回新路径（`/` 分隔）。新名称不能为空、不能含路径分隔符，目标已存在时不覆盖。
+pub fn rename(path: &str, new_name: &str) -> io::Result<String> {
+    let new_name = new_name.trim();
+    if new_name.is_empty() || new_name.contains(['/', '\\']) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "文件名不能为空或包含路径分隔符"));
+    }
+    let new_path = match path.trim_end_matches('/').rfind('/') {
+        Some(i) => format!("{}{new_name}", &path[..=i]),
+        None => new_name.to_string(),
+    };
+    // 仅大小写不同（Windows 上视为同一文件）时允许
+    if std::fs::symlink_metadata(&new_path).is_ok() && !new_path.eq_ignore_ascii_case(path) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "目标名称已存在"));
+    }
+    std::fs::rename(path, &new_path)?;
+    Ok(new_path)
+}
+
+/// 目标文件夹中的同名路径（`/` 分隔）；已存在时返回 `AlreadyExists`，不覆盖。
+fn target_in(path: &str, folder: &str) -> io::Result<String> {
+    let name = path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|n| !n.is_empty())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "无效的路径"))?;
+    let folder = folder.trim().replace('\\', "/");
+    let folder = folder.trim_end_matches('/');
+    if format!("{folder}/").starts_with(&format!("{}/", path.trim_end_matches('/'))) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "不能复制或移动到自身之内"));
+    }
+    let target = format!("{folder}/{name}");
+    if std::fs::symlink_metadata(&target).is_ok() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "目标文件夹中已有同名项"));
+    }
+    Ok(target)
+}
+
+/// 复制到目标文件夹（目录递归复制），返回新路径。
+pub fn copy_into(path: &str, folder: &str) -> io::Result<String> {
+    let target = target_in(path, folder)?;
+    copy_recursive(std::path::Path::new(path), std::path::Path::new(&target))?;
+    Ok(target)
+}
+
+fn copy_recursive(from: &std::path::Path, to: &std::path::Path) -> io::Result<()> {
+    if !std::fs::symlink_metadata(from)?.is_dir() {
+        return std::fs::copy(from, to).map(|_| ());
+    }
+    std::fs::create_dir(to)?;
+    for child in std::fs::read_dir(from)? {
+        let child = child?;
+        copy_recursive(&child.path(), &to.join(child.file_name()))?;
+    }
+    Ok(())
+}
+
+/// 移动到目标文件夹，返回新路径。跨卷时先复制再删除原文件。
+pub fn move_into(path: &str, folder: &str) -> io::Result<String> {
+    let target = target_in(path, folder)?;
+    match std::fs::rename(path, &target) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            copy_recursive(std::path::Path::new(path), std::path::Path::new(&target))?;
+            if std::fs::symlink_metadata(path)?.is_dir() {
+                std::fs::remove_dir_all(path)?;
+            } else {
+                std::fs::remove_file(path)?;
+            }
+        }
+        other => other?,
+    }
+    Ok(target)
+}
+
+#[cfg(windows)]
+pub use crate::windows_shell::{copy_files_to_clipboard, open_with, show_context_menu, show_properties};
+
+// 以下几项只在 Windows 上由资源管理器提供；其他平台返回 `Unsupported`，由界面改用替代做法。
+
+#[cfg(not(windows))]
+fn unsupported() -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, "当前平台不支持")
+}
+
+#[cfg(not(windows))]
+pub fn copy_files_to_clipboard(_paths: &[String]) -> io::Result<()> {
+    Err(unsupported())
+}
+
+#[cfg(not(windows))]
+pub fn open_with(_path: &str) -> io::Result<()> {
+    Err(unsupported())
+}
+
+#[cfg(not(windows))]
+pub fn show_context_menu(_path: &str) -> io::Result<()> {
+    Err(unsupported())
+}
+
+#[cfg(not(windows))]
+pub fn show_properties(_path: &str) -> io::Result<()> {
+    Err(unsupported())
+}
+
+/// `file://` URI，非 Windows 平台“复制为文件”时使用。
+pub fn file_uri(path: &str) -> String {
+    let slash = if path.starts_with('/') { "" } else { "/" };
+    format!("file://{slash}{}", percent_encode(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn file_uris_are_percent_encoded() {
+        assert_eq!(file_uri("/home/u/报告 1.txt"), "file:///home/u/%E6%8A%A5%E5%91%8A%201.txt");
+        assert_eq!(file_uri("C:/a b"), "file:///C:/a%20b");
+    }
+
+    #[test]
+    fn copy_and_move_into_a_folder_keep_existing_files() {
+        let dir = std::env::temp_dir().join(format!("rust_search_copy_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src/sub")).unwrap();
+        std::fs::create_dir_all(dir.join("dest")).unwrap();
+        let dir_str = dir.to_string_lossy().replace('\\', "/");
+        std::fs::write(dir.join("src/sub/a.txt"), b"a").unwrap();
+        std::fs::write(dir.join("b.txt"), b"b").unwrap();
+        let dest = format!("{dir_str}/dest");
+
+        assert_eq!(copy_into(&format!("{dir_str}/src"), &dest).unwrap(), format!("{dest}/src"));
+        assert!(dir.join("dest/src/sub/a.txt").exists());
+        assert!(dir.join("src/sub/a.txt").exists());
+        let moved = move_into(&format!("{dir_str}/b.txt"), &dest).unwrap();
+        assert_eq!(moved, format!("{dest}/b.txt"));
+        assert!(!dir.join("b.txt").exists());
+        std::fs::write(dir.join("b.txt"), b"again").unwrap();
+        let err = move_into(&format!("{dir_str}/b.txt"), &dest).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn rename_refuses_to_overwrite_and_rejects_separators() {
+        let dir = std::env::temp_dir().join(format!("rust_search_rename_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().replace('\\', "/");
+        let a = format!("{dir_str}/a.txt");
+        std::fs::write(&a, b"a").unwrap();
+        std::fs::write(format!("{dir_str}/b.txt"), b"b").unwrap();
+
+        assert_eq!(rename(&a, "b.txt").unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(rename(&a, "x/y.txt").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        let renamed = rename(&a, " c.txt ").unwrap();
+        assert_eq!(renamed, format!("{dir_str}/c.txt"));
+        assert!(Path::new(&renamed).exists());
+        assert!(!Path::new(&a).exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,131 @@
+//! 搜索用的文本折叠：Unicode 规范化（NFC）、全角/半角折叠与大小写折叠。
+//!
+//! 索引的小写列与查询串经过同一折叠，比较时不必再区分 NFC/NFD（macOS 创建的文件名多为 NFD）、
+//! 全角/半角以及大小写。
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use icu_normalizer::ComposingNormalizerBorrowed;
+
+/// 折叠为搜索用形式；结果与原文相同时原样借用。
+pub fn fold_for_search(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        if s.bytes().any(|b| b.is_ascii_uppercase()) {
+            return Cow::Owned(s.to_ascii_lowercase());
+        }
+        return Cow::Borrowed(s);
+    }
+
+    let nfc = ComposingNormalizerBorrowed::new_nfc().normalize(s);
+    let narrowed = fold_width(&nfc);
+    let mut out = String::with_capacity(narrowed.len());
+    for c in narrowed.chars() {
+        for lower in c.to_lowercase() {
+            match lower {
+                'ß' => out.push_str("ss"),
+                'ς' => out.push('σ'),
+                'ſ' => out.push('s'),
+                _ => out.push(lower),
+            }
+        }
+    }
+
+    if out == s {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(out)
+    }
+}
+
+/// 全角 ASCII、全角空格、半角片假名/谚文等宽度变体。
+fn is_width_variant(c: char) -> bool {
+    c == '\u{3000}' || ('\u{FF01}'..='\u{FFEE}').contains(&c)
+}
+
+/// 只对宽度变体做 NFKC（如 "ＡＢＣ" → "ABC"、"ｶﾞ" → "ガ"），避免把 "①"、"ﬁ" 之类也一并兼容分解。
+fn fold_width(s: &str) -> Cow<'_, str> {
+    if !s.chars().any(is_width_variant) {
+        return Cow::Borrowed(s);
+    }
+
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find(is_width_variant) {
+        out.push_str(&rest[..start]);
+        let run = &rest[start..];
+        let end = run.find(|c| !is_width_variant(c)).unwrap_or(run.len());
+        out.push_str(&nfkc.normalize(&run[..end]));
+        rest = &run[end..];
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+/// 组合附加符号与（半角）浊音符等会与前一个字符合成的字符。
+fn is_combining(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{3099}'..='\u{309A}'
+        | '\u{FF9E}'..='\u{FF9F}')
+}
+
+/// 逐个“字符 + 其后组合符号”分段折叠，同时记录每个折叠后字符来自原文的哪段字符（按字符下标的半开区间），
+/// 用于把在折叠文本上得到的匹配位置映射回原文（高亮显示）。
+pub fn fold_with_origins(s: &str) -> (String, Vec<Range<usize>>) {
+    let mut folded = String::with_capacity(s.len());
+    let mut origins = Vec::with_capacity(s.len());
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let mut end = i + 1;
+        while end < chars.len() && is_combining(chars[end].1) {
+            end += 1;
+        }
+        let byte_end = chars.get(end).map_or(s.len(), |&(b, _)| b);
+        let segment = fold_for_search(&s[chars[i].0..byte_end]);
+        for c in segment.chars() {
+            folded.push(c);
+            origins.push(i..end);
+        }
+        i = end;
+    }
+    (folded, origins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_case_normalization_and_width() {
+        assert_eq!(fold_for_search("ÄNDERUNG.txt"), "änderung.txt");
+        // NFD：a + U+0308
+        assert_eq!(fold_for_search("A\u{308}nderung.txt"), "änderung.txt");
+        assert_eq!(fold_for_search("STRASSE"), fold_for_search("Straße"));
+        assert_eq!(fold_for_search("ΟΔΟΣ"), fold_for_search("οδος"));
+        assert_eq!(fold_for_search("Ｒｅｐｏｒｔ　２０２４.pdf"), "report 2024.pdf");
+        assert_eq!(fold_for_search("ｶﾞｲﾄﾞ"), "ガイド");
+        assert_eq!(fold_for_search("①ﬁle"), "①ﬁle");
+    }
+
+    #[test]
+    fn unchanged_text_is_borrowed() {
+        assert!(matches!(fold_for_search("readme.md"), Cow::Borrowed(_)));
+        assert!(matches!(fold_for_search("报告.docx"), Cow::Borrowed(_)));
+        assert!(matches!(fold_for_search("Readme.md"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn origins_map_folded_chars_back_to_original() {
+        let (folded, origins) = fold_with_origins("A\u{308}ß.txt");
+        assert_eq!(folded, "äss.txt");
+        assert_eq!(origins[..3], [0..2, 2..3, 2..3]);
+        assert_eq!(origins[3], 3..4);
+    }
+}
@@ -0,0 +1,262 @@
+//! 搜索历史与自动补全：历史按新近顺序保存（有上限、去重、持久化），搜索框中用上/下方向键回溯；
+//! 补全候选来自历史、索引中常见的扩展名（输入以 `.` 开头的词时）以及索引中的文件夹名。
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::entry_store::EntryStore;
+use crate::fold::fold_for_search;
+
+/// 补全中保留的常见扩展名个数。
+const MAX_EXTENSIONS: usize = 200;
+/// 文件夹名补全至少需要的字符数，避免一两个字母就列出大量目录。
+const MIN_FOLDER_PREFIX: usize = 2;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchHistory {
+    /// 最新的在前。
+    pub entries: Vec<String>,
+    pub capacity: usize,
+    /// 方向键回溯的位置；`None` 表示正在编辑新查询。
+    #[serde(skip)]
+    cursor: Option<usize>,
+    /// 开始回溯前搜索框中的内容，回到最新一条之后恢复。
+    #[serde(skip)]
+    draft: String,
+}
+
+impl Default for SearchHistory {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            capacity: 100,
+            cursor: None,
+            draft: String::new(),
+        }
+    }
+}
+
+impl SearchHistory {
+    /// 记录一次查询：已有的相同查询移到最前，超出上限时丢弃最旧的。
+    pub fn push(&mut self, query: &str) {
+        self.cursor = None;
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        self.entries.retain(|q| q != query);
+        self.entries.insert(0, query.to_string());
+        self.entries.truncate(self.capacity);
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.entries.truncate(capacity);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cursor = None;
+    }
+
+    /// 上方向键：取更早的一条；第一次回溯时记住当前输入。
+    pub fn older(&mut self, current: &str) -> Option<&str> {
+        let next = match self.cursor {
+            None => {
+                self.draft = current.to_string();
+                0
+            }
+            Some(i) => i + 1,
+        };
+        let query = self.entries.get(next)?;
+        self.cursor = Some(next);
+        Some(query)
+    }
+
+    /// 下方向键：取更新的一条；越过最新一条时恢复回溯前的输入。
+    pub fn newer(&mut self) -> Option<&str> {
+        match self.cursor? {
+            0 => {
+                self.cursor = None;
+                Some(&self.draft)
+            }
+            i => {
+                self.cursor = Some(i - 1);
+                self.entries.get(i - 1).map(String::as_str)
+            }
+        }
+    }
+
+    /// 用户手动编辑后结束回溯。
+    pub fn reset_recall(&mut self) {
+        self.cursor = None;
+    }
+}
+
+/// 从索引提取的补全词表；索引更新后在后台重建。
+#[derive(Default)]
+pub struct Completions {
+    /// (折叠后的名称, 原名称)，按折叠后的名称排序去重，便于二分查找前缀。
+    folders: Vec<(String, String)>,
+    /// 按出现次数从多到少。
+    extensions: Vec<String>,
+}
+
+impl Completions {
+    pub fn build(entries: &EntryStore) -> Self {
+        let mut folders = Vec::new();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for entry in entries.iter() {
+            if entry.is_dir() {
+                folders.push((entry.name_lower().to_string(), entry.name().to_string()));
+            } else if !entry.extension_lower().is_empty() {
+                *counts.entry(entry.extension_lower()).or_default() += 1;
+            }
+        }
+        folders.sort_unstable();
+        folders.dedup_by(|a, b| a.0 == b.0);
+
+        let mut extensions: Vec<(&str, usize)> = counts.into_iter().collect();
+        extensions.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        extensions.truncate(MAX_EXTENSIONS);
+        Self {
+            folders,
+            extensions: extensions.into_iter().map(|(e, _)| e.to_string()).collect(),
+        }
+    }
+
+    fn folders_with_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let start = self.folders.partition_point(|(lower, _)| lower.as_str() < prefix);
+        self.folders[start..]
+            .iter()
+            .take_while(move |(lower, _)| lower.starts_with(prefix))
+            .map(|(_, name)| name.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuggestionKind {
+    History,
+    Extension,
+    Folder,
+}
+
+impl SuggestionKind {
+    pub fn icon(self) -> &'static str {
+        match self {
+            SuggestionKind::History => "🕘",
+            SuggestionKind::Extension => "📄",
+            SuggestionKind::Folder => "📁",
+        }
+    }
+}
+
+/// 补全候选；`text` 为采纳后搜索框的完整内容。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    pub text: String,
+    pub kind: SuggestionKind,
+}
+
+/// 历史中以输入开头的查询优先，其次是包含输入的查询；
+/// 最后一个词以 `.` 开头时补全扩展名，否则按文件夹名补全最后一个词。
+pub fn suggest(history: &SearchHistory, completions: &Completions, input: &str, limit: usize) -> Vec<Suggestion> {
+    let mut out: Vec<Suggestion> = Vec::new();
+    let mut add = |text: String, kind: SuggestionKind| {
+        if out.len() < limit && text != input && !out.iter().any(|s| s.text == text) {
+            out.push(Suggestion { text, kind });
+        }
+    };
+
+    let folded = fold_for_search(input.trim());
+    let (starts, contains): (Vec<&String>, Vec<&String>) = history
+        .entries
+        .iter()
+        .filter(|q| fold_for_search(q).contains(folded.as_ref()))
+        .partition(|q| fold_for_search(q).starts_with(folded.as_ref()));
+    for query in starts.into_iter().chain(contains) {
+        add(query.clone(), SuggestionKind::History);
+    }
+
+    let head_len = input.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let (head, token) = input.split_at(head_len);
+    if let Some(ext) = token.strip_prefix('.') {
+        let ext = ext.to_lowercase();
+        for candidate in completions.extensions.iter().filter(|e| e.starts_with(&ext)) {
+            add(format!("{head}.{candidate}"), SuggestionKind::Extension);
+        }
+    } else if token.chars().count() >= MIN_FOLDER_PREFIX {
+        let prefix = fold_for_search(token);
+        for name in completions.folders_with_prefix(&prefix) {
+            add(format!("{head}{name}"), SuggestionKind::Folder);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::FileEntry;
+
+    fn entry(name: &str, is_dir: bool) -> FileEntry {
+        FileEntry {
+            name: name.to_string(),
+            path: format!("C:/{name}"),
+            drive: 0,
+            frn: 0,
+            parent_frn: 0,
+            size: 0,
+            modified_ms: 0,
+            is_dir,
+            is_hidden: false,
+        }
+    }
+
+    #[test]
+    fn history_is_bounded_deduplicated_and_recalled_in_order() {
+        let mut history = SearchHistory::default();
+        history.set_capacity(3);
+        for q in ["a", "b", "c", "b", "d", "  "] {
+            history.push(q);
+        }
+        assert_eq!(history.entries, ["d", "b", "c"]);
+
+        assert_eq!(history.older("typing"), Some("d"));
+        assert_eq!(history.older("d"), Some("b"));
+        assert_eq!(history.older("b"), Some("c"));
+        assert_eq!(history.older("c"), None);
+        assert_eq!(history.newer(), Some("b"));
+        assert_eq!(history.newer(), Some("d"));
+        assert_eq!(history.newer(), Some("typing"));
+        assert_eq!(history.newer(), None);
+    }
+
+    #[test]
+    fn suggestions_come_from_history_extensions_and_folders() {
+        let store = EntryStore::from_entries(vec![
+            entry("Projects", true),
+            entry("projection", true),
+            entry("report.log", false),
+            entry("app.log", false),
+            entry("photo.png", false),
+        ]);
+        let completions = Completions::build(&store);
+        let mut history = SearchHistory::default();
+        history.push("my project plan");
+        history.push("project notes");
+
+        let texts = |input: &str| -> Vec<String> {
+            suggest(&history, &completions, input, 10).into_iter().map(|s| s.text).collect()
+        };
+        assert_eq!(
+            texts("proj"),
+            ["project notes", "my project plan", "projection", "Projects"]
+        );
+        assert_eq!(texts("error .l"), ["error .log"]);
+        assert_eq!(texts("."), [".log", ".png"]);
+        assert_eq!(texts("p"), ["project notes", "my project plan"]);
+    }
+}
@@ -0,0 +1,281 @@
+//! 结果列表的图标：优先使用系统外壳按扩展名提供的图标（Windows），否则绘制内置图标。
+//!
+//! 缩略图模式下图片显示缩略图：后台线程只解码当前可见行请求的图片，结果按“路径 + 修改时间”
+//! 缓存为纹理，超过容量时淘汰最久未显示的项。
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use eframe::egui::{self, Color32, Rect, Stroke};
+
+use crate::preview;
+
+/// 普通模式的图标边长。
+pub const ICON_SIZE: f32 = 16.0;
+/// 缩略图模式的图标边长。
+pub const THUMBNAIL_SIZE: f32 = 40.0;
+/// 缩略图解码时的像素边长（高分屏下仍然清晰）。
+const THUMBNAIL_PIXELS: u32 = 96;
+/// 最多缓存的缩略图纹理数。
+const THUMBNAIL_CAPACITY: usize = 512;
+/// 每帧最多上传的缩略图纹理数，避免一次上传过多造成卡顿。
+const UPLOADS_PER_FRAME: usize = 16;
+
+/// 一行的图标所需的信息。
+pub struct IconRequest<'a> {
+    pub path: &'a str,
+    /// 小写、不含点。
+    pub extension: &'a str,
+    pub is_dir: bool,
+    pub modified_ms: u64,
+    /// 文件类型分类的 `id`，决定内置图标的颜色。
+    pub category: Option<&'a str>,
+}
+
+type ThumbnailKey = (String, u64);
+
+/// 后台线程与界面共享的缩略图队列。
+#[derive(Default)]
+struct ThumbnailQueue {
+    /// 本帧可见、尚未缓存的图片（每帧整体替换，滚走的行不再解码）。
+    wanted: Vec<ThumbnailKey>,
+    busy: Option<ThumbnailKey>,
+    /// 解码完成待上传的结果；失败为 `None`，之后改用图标。
+    done: Vec<(ThumbnailKey, Option<egui::ColorImage>)>,
+}
+
+struct Thumbnail {
+    texture: Option<egui::TextureHandle>,
+    last_used: u64,
+}
+
+#[derive(Default)]
+pub struct IconCache {
+    /// (扩展名, 是否目录, 是否大图标) → 系统图标；`None` 表示系统没有提供，改用内置图标。
+    shell: HashMap<(String, bool, bool), Option<egui::TextureHandle>>,
+    thumbnails: HashMap<ThumbnailKey, Thumbnail>,
+    requested: Vec<ThumbnailKey>,
+    frame: u64,
+    queue: Option<Arc<(Mutex<ThumbnailQueue>, Condvar)>>,
+}
+
+impl IconCache {
+    /// 每帧绘制结果列表前调用：上传已解码的缩略图并淘汰多余的缓存。
+    pub fn begin_frame(&mut self, ctx: &egui::Context) {
+        self.frame += 1;
+        self.requested.clear();
+        let Some(queue) = &self.queue else {
+            return;
+        };
+        let done: Vec<(ThumbnailKey, Option<egui::ColorImage>)> = {
+            let mut queue = queue.0.lock().unwrap();
+            let take = queue.done.len().min(UPLOADS_PER_FRAME);
+            queue.done.drain(..take).collect()
+        };
+        if done.is_empty() {
+            return;
+        }
+        for (key, image) in done {
+            let texture = image.map(|image| ctx.load_texture(format!("thumb:{}", key.0), image, egui::TextureOptions::LINEAR));
+            self.thumbnails.insert(
+                key,
+                Thumbnail {
+                    texture,
+                    last_used: self.frame,
+                },
+            );
+        }
+        if self.thumbnails.len() > THUMBNAIL_CAPACITY {
+            let mut by_age: Vec<(u64, ThumbnailKey)> =
+                self.thumbnails.iter().map(|(k, t)| (t.last_used, k.clone())).collect();
+            by_age.sort_unstable_by_key(|(used, _)| *used);
+            let excess = self.thumbnails.len() - THUMBNAIL_CAPACITY;
+            for (_, key) in by_age.into_iter().take(excess) {
+                self.thumbnails.remove(&key);
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// 每帧绘制结果列表后调用：把本帧可见的未缓存图片交给后台线程。
+    pub fn end_frame(&mut self, ctx: &egui::Context) {
+        if self.requested.is_empty() && self.queue.is_none() {
+            return;
+        }
+        let queue = self.queue.get_or_insert_with(|| spawn_worker(ctx.clone()));
+        let mut shared = queue.0.lock().unwrap();
+        let requested = std::mem::take(&mut self.requested);
+        shared.wanted = requested
+            .into_iter()
+            .filter(|key| shared.busy.as_ref() != Some(key) && !shared.done.iter().any(|(k, _)| k == key))
+            .collect();
+        queue.1.notify_one();
+    }
+
+    /// 在 `rect` 中绘制图标：缩略图（仅缩略图模式下的图片）> 系统图标 > 内置图标。
+    pub fn paint(&mut self, ui: &egui::Ui, rect: Rect, item: &IconRequest<'_>, thumbnails: bool) {
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+        if thumbnails && !item.is_dir && is_image(item.extension) {
+            let key = (item.path.to_string(), item.modified_ms);
+            match self.thumbnails.get_mut(&key) {
+                Some(thumbnail) => {
+                    thumbnail.last_used = self.frame;
+                    if let Some(texture) = &thumbnail.texture {
+                        paint_texture(ui, rect, texture);
+                        return;
+                    }
+                }
+                None => self.requested.push(key),
+            }
+        }
+
+        let large = rect.height() > ICON_SIZE * 1.5;
+        let extension = if item.is_dir { "" } else { item.extension };
+        let shell = self
+            .shell
+            .entry((extension.to_string(), item.is_dir, large))
+            .or_insert_with(|| {
+                shell_icon(extension, item.is_dir, large).map(|image| {
+                    ui.ctx().load_texture(
+                        format!("shell_icon:{extension}:{}:{large}", item.is_dir),
+                        image,
+                        egui::TextureOptions::LINEAR,
+                    )
+                })
+            });
+        match shell {
+            Some(texture) => paint_texture(ui, rect, texture),
+            None => paint_bundled(ui.painter(), rect, item),
+        }
+    }
+}
+
+fn is_image(extension: &str) -> bool {
+    image::ImageFormat::from_extension(extension).is_some_and(|f| f.reading_enabled())
+}
+
+/// 解码线程：取队列中的第一项（最靠上的可见行）解码，队列为空时等待。
+fn spawn_worker(ctx: egui::Context) -> Arc<(Mutex<ThumbnailQueue>, Condvar)> {
+    let queue = Arc::new((Mutex::new(ThumbnailQueue::default()), Condvar::new()));
+    let shared = Arc::clone(&queue);
+    thread::spawn(move || {
+        let (lock, ready) = &*shared;
+        loop {
+            let key = {
+                let mut queue = ready.wait_while(lock.lock().unwrap(), |q| q.wanted.is_empty()).unwrap();
+                let key = queue.wanted.remove(0);
+                queue.busy = Some(key.clone());
+                key
+            };
+            let image = preview::decode_image(&key.0, THUMBNAIL_PIXELS).ok().map(|(image, _)| image);
+            let mut queue = lock.lock().unwrap();
+            queue.busy = None;
+            queue.done.push((key, image));
+            ctx.request_repaint();
+        }
+    });
+    queue
+}
+
+/// 保持宽高比居中绘制。
+fn paint_texture(ui: &egui::Ui, rect: Rect, texture: &egui::TextureHandle) {
+    let size = texture.size_vec2();
+    let scale = (rect.width() / size.x).min(rect.height() / size.y);
+    let fitted = Rect::from_center_size(rect.center(), size * scale);
+    let uv = Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
+    ui.painter().image(texture.id(), fitted, uv, Color32::WHITE);
+}
+
+#[cfg(windows)]
+fn shell_icon(extension: &str, is_dir: bool, large: bool) -> Option<egui::ColorImage> {
+    crate::windows_shell::extension_icon(extension, is_dir, large)
+        .map(|(size, rgba)| egui::ColorImage::from_rgba_unmultiplied(size, &rgba))
+}
+
+#[cfg(not(windows))]
+fn shell_icon(_extension: &str, _is_dir: bool, _large: bool) -> Option<egui::ColorImage> {
+    None
+}
+
+/// 内置图标的颜色：内置分类用固定颜色，其余按扩展名散列到调色板。
+fn bundled_color(category: Option<&str>, extension: &str) -> Color32 {
+    const PALETTE: [Color32; 6] = [
+        Color32::from_rgb(0x3b, 0x82, 0xc4),
+        Color32::from_rgb(0x2e, 0x9d, 0x8f),
+        Color32::from_rgb(0x8e, 0x5c, 0xc2),
+        Color32::from_rgb(0xc2, 0x6b, 0x3a),
+        Color32::from_rgb(0x5b, 0x8c, 0x3a),
+        Color32::from_rgb(0xb0, 0x4a, 0x6a),
+    ];
+    match category {
+        Some("document") => Color32::from_rgb(0x2b, 0x57, 0x9a),
+        Some("image") => Color32::from_rgb(0x8e, 0x44, 0xad),
+        Some("video") => Color32::from_rgb(0xc0, 0x39, 0x2b),
+        Some("audio") => Color32::from_rgb(0xe6, 0x7e, 0x22),
+        Some("code") => Color32::from_rgb(0x27, 0xae, 0x60),
+        Some("archive") => Color32::from_rgb(0xb7, 0x95, 0x0b),
+        Some("executable") => Color32::from_rgb(0x4a, 0x4a, 0x4a),
+        Some("model") => Color32::from_rgb(0x16, 0xa0, 0x85),
+        _ if extension.is_empty() => Color32::from_gray(140),
+        _ => {
+            let hash = extension.bytes().fold(0usize, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+            PALETTE[hash % PALETTE.len()]
+        }
+    }
+}
+
+/// 内置图标：文件夹，或带彩色标签（空间足够时写上扩展名）的文档。
+fn paint_bundled(painter: &egui::Painter, rect: Rect, item: &IconRequest<'_>) {
+    let side = rect.width().min(rect.height());
+    let rect = Rect::from_center_size(rect.center(), egui::vec2(side, side));
+    let rounding = side * 0.08;
+    if item.is_dir {
+        let tab = Rect::from_min_size(
+            rect.left_top() + egui::vec2(0.0, side * 0.12),
+            egui::vec2(side * 0.45, side * 0.2),
+        );
+        let body = Rect::from_min_max(rect.left_top() + egui::vec2(0.0, side * 0.24), rect.right_bottom() - egui::vec2(0.0, side * 0.08));
+        painter.rect_filled(tab, rounding, Color32::from_rgb(0xd9, 0xa4, 0x1e));
+        painter.rect_filled(body, rounding, Color32::from_rgb(0xf4, 0xc4, 0x3b));
+        return;
+    }
+
+    let page = Rect::from_center_size(rect.center(), egui::vec2(side * 0.72, side * 0.94));
+    painter.rect_filled(page, rounding, Color32::WHITE);
+    painter.rect_stroke(page, rounding, Stroke::new(1.0, Color32::from_gray(150)));
+    let band = Rect::from_min_max(
+        egui::pos2(rect.left(), page.bottom() - page.height() * 0.42),
+        egui::pos2(page.right(), page.bottom() - page.height() * 0.1),
+    );
+    painter.rect_filled(band, rounding, bundled_color(item.category, item.extension));
+    // 小图标上的文字无法辨认，只画色块
+    if band.height() >= 8.0 && !item.extension.is_empty() {
+        let label: String = item.extension.chars().take(4).collect::<String>().to_uppercase();
+        painter.text(
+            band.center(),
+            egui::Align2::CENTER_CENTER,
+            label,
+            egui::FontId::proportional(band.height() * 0.75),
+            Color32::WHITE,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnails_only_for_decodable_images_and_colors_are_stable() {
+        assert!(is_image("png"));
+        assert!(is_image("jpg"));
+        assert!(!is_image("txt"));
+        assert!(!is_image(""));
+        assert_eq!(bundled_color(None, "xyz"), bundled_color(None, "xyz"));
+        assert_eq!(bundled_color(Some("image"), "png"), bundled_color(Some("image"), "bmp"));
+        assert_eq!(bundled_color(None, ""), Color32::from_gray(140));
+    }
+}
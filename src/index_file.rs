@@ -0,0 +1,623 @@
+//! RSIX v4：可直接 mmap 的索引缓存格式。
+//!
+//! 文件布局（全部小端，section 按 8 字节对齐）：
+//!
+//! ```text
+//! RSIX(4) + version=4(u8) + encoding=RAW(u8) + reserved=0(u16)
+//! entry_count(u64) + section_count(u32) + flags(u32)
+//! section_count × { offset(u64), len(u64), crc32(u32), reserved(u32) }
+//! header_crc32(u32) + padding(u32)          // 覆盖以上全部字节
+//! section 数据...
+//! ```
+//!
+//! section 依次为 `EntryStore` 的 10 列（字符串表、偏移表、定长字段表、父目录下标）
+//! 以及每卷一条、定长 32 字节的 USN 状态。加载时字符串/表格直接引用映射内存，不做反序列化。
+//!
+//! 压缩编码（LZ4/zstd）时，通用头部之后是上述完整映像（含其自身头部与校验和）的压缩流。
+
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{Error, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+
+use crate::entry_store::{Column, EntryStore, Pod, StoreColumns, StrColumn};
+use crate::indexer::{CACHE_HEADER_LEN, CACHE_MAGIC, UsnDriveState};
+
+pub const CACHE_V4: u8 = 4;
+/// 定长小端原始布局（与 v2/v3 的 bincode varint 编码区分）
+pub const CACHE_ENCODING_RAW: u8 = 2;
+/// 原始布局整体经 LZ4 frame 压缩
+pub const CACHE_ENCODING_LZ4: u8 = 3;
+/// 原始布局整体经 zstd 压缩
+pub const CACHE_ENCODING_ZSTD: u8 = 4;
+
+const ZSTD_LEVEL: i32 = 3;
+
+/// 头部 flags：小写列按 Unicode 折叠规则（`fold_for_search`）写入；旧文件缺少该位时加载后重建小写列。
+const FLAG_UNICODE_FOLD: u32 = 1 << 0;
+
+/// 写入缓存时使用的编码。原始编码可直接 mmap，压缩编码体积更小但加载需解压。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CacheCodec {
+    #[default]
+    Raw,
+    Lz4,
+    Zstd,
+}
+
+impl CacheCodec {
+    pub const ALL: [CacheCodec; 3] = [CacheCodec::Raw, CacheCodec::Lz4, CacheCodec::Zstd];
+
+    pub fn encoding(self) -> u8 {
+        match self {
+            CacheCodec::Raw => CACHE_ENCODING_RAW,
+            CacheCodec::Lz4 => CACHE_ENCODING_LZ4,
+            CacheCodec::Zstd => CACHE_ENCODING_ZSTD,
+        }
+    }
+
+    pub fn from_encoding(encoding: u8) -> Option<Self> {
+        match encoding {
+            CACHE_ENCODING_RAW => Some(CacheCodec::Raw),
+            CACHE_ENCODING_LZ4 => Some(CacheCodec::Lz4),
+            CACHE_ENCODING_ZSTD => Some(CacheCodec::Zstd),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CacheCodec::Raw => "不压缩（mmap 直接加载）",
+            CacheCodec::Lz4 => "LZ4（快）",
+            CacheCodec::Zstd => "zstd（小）",
+        }
+    }
+}
+
+const STORE_SECTIONS: usize = 10;
+const SECTION_COUNT: usize = STORE_SECTIONS + 1;
+const SECTION_DESC_LEN: usize = 24;
+const USN_RECORD_LEN: usize = 32;
+const TABLE_START: usize = CACHE_HEADER_LEN + 16;
+const HEADER_CRC_OFFSET: usize = TABLE_START + SECTION_COUNT * SECTION_DESC_LEN;
+const DATA_START: usize = HEADER_CRC_OFFSET + 8;
+
+const SECTION_NAMES: [&str; SECTION_COUNT] = [
+    "文件名",
+    "文件名偏移",
+    "小写文件名",
+    "小写文件名偏移",
+    "路径",
+    "路径偏移",
+    "小写路径",
+    "小写路径偏移",
+    "条目字段",
+    "父目录",
+    "USN 状态",
+];
+
+/// `verify_cache` 的结果。
+#[derive(Clone, Debug, Default)]
+pub struct CacheReport {
+    pub version: u8,
+    pub encoding: u8,
+    pub file_bytes: u64,
+    pub entry_count: usize,
+    pub usn_states: Vec<UsnDriveState>,
+    /// 文件是否带校验和（v4 起）；旧格式只能通过能否完整解析来判断
+    pub checksummed: bool,
+    /// 发现的问题；为空表示缓存完整
+    pub problems: Vec<String>,
+}
+
+impl CacheReport {
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// 格式说明，如 "v4 · LZ4（快） · 带校验和"；v1–v3 均为 bincode 编码。
+    pub fn format_label(&self) -> String {
+        let encoding = match CacheCodec::from_encoding(self.encoding) {
+            Some(codec) if self.version >= CACHE_V4 => codec.label(),
+            _ => "bincode",
+        };
+        let checksum = if self.checksummed { "带校验和" } else { "无校验和" };
+        format!("v{} · {encoding} · {checksum}", self.version)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Section {
+    offset: u64,
+    len: u64,
+    crc: u32,
+}
+
+fn invalid(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg.to_string())
+}
+
+fn align8(n: usize) -> usize {
+    (n + 7) & !7
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap())
+}
+
+fn encode_usn_states(states: &[UsnDriveState]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(states.len() * USN_RECORD_LEN);
+    for s in states {
+        out.push(s.drive);
+        out.extend_from_slice(&[0u8; 7]);
+        out.extend_from_slice(&s.journal_id.to_le_bytes());
+        out.extend_from_slice(&s.root_frn.to_le_bytes());
+        out.extend_from_slice(&s.last_usn.to_le_bytes());
+    }
+    out
+}
+
+fn decode_usn_states(bytes: &[u8]) -> std::io::Result<Vec<UsnDriveState>> {
+    if !bytes.len().is_multiple_of(USN_RECORD_LEN) {
+        return Err(invalid("USN 状态区长度不正确"));
+    }
+    Ok(bytes
+        .chunks_exact(USN_RECORD_LEN)
+        .map(|r| UsnDriveState {
+            drive: r[0],
+            journal_id: read_u64(r, 8),
+            root_frn: read_u64(r, 16),
+            last_usn: read_u64(r, 24) as i64,
+        })
+        .collect())
+}
+
+/// 写出 v4 缓存。压缩编码时外层只有通用头部，其后是整个原始映像的压缩流。
+pub fn write_v4<W: Write>(
+    out: &mut W,
+    entries: &EntryStore,
+    usn_states: &[UsnDriveState],
+    codec: CacheCodec,
+) -> std::io::Result<()> {
+    if codec == CacheCodec::Raw {
+        return write_raw(out, entries, usn_states);
+    }
+    out.write_all(&CACHE_MAGIC)?;
+    out.write_all(&[CACHE_V4, codec.encoding(), 0, 0])?;
+    match codec {
+        CacheCodec::Raw => unreachable!(),
+        CacheCodec::Lz4 => {
+            let mut enc = lz4_flex::frame::FrameEncoder::new(out);
+            write_raw(&mut enc, entries, usn_states)?;
+            enc.finish().map_err(Error::other)?;
+        }
+        CacheCodec::Zstd => {
+            let mut enc = zstd::stream::write::Encoder::new(out, ZSTD_LEVEL)?;
+            write_raw(&mut enc, entries, usn_states)?;
+            enc.finish()?;
+        }
+    }
+    Ok(())
+}
+
+/// 写出未压缩映像。各列按内存布局原样写入，因此只支持小端平台。
+fn write_raw<W: Write>(
+    out: &mut W,
+    entries: &EntryStore,
+    usn_states: &[UsnDriveState],
+) -> std::io::Result<()> {
+    if cfg!(target_endian = "big") {
+        return Err(Error::new(ErrorKind::Unsupported, "v4 缓存仅支持小端平台"));
+    }
+
+    let parents: Cow<'_, [u32]> = entries.parents_column();
+    let usn_bytes = encode_usn_states(usn_states);
+    let columns = entries.column_bytes();
+    // SAFETY: u32 切片按原始字节视图读取
+    let parent_bytes = unsafe {
+        std::slice::from_raw_parts(parents.as_ptr() as *const u8, std::mem::size_of_val(&*parents))
+    };
+
+    let mut payloads: Vec<&[u8]> = Vec::with_capacity(SECTION_COUNT);
+    payloads.extend_from_slice(&columns);
+    payloads.push(parent_bytes);
+    payloads.push(&usn_bytes);
+
+    let mut sections = Vec::with_capacity(SECTION_COUNT);
+    let mut offset = DATA_START;
+    for data in &payloads {
+        sections.push(Section {
+            offset: offset as u64,
+            len: data.len() as u64,
+            crc: crc32fast::hash(data),
+        });
+        offset = align8(offset + data.len());
+    }
+
+    let mut header = Vec::with_capacity(DATA_START);
+    header.extend_from_slice(&CACHE_MAGIC);
+    header.push(CACHE_V4);
+    header.push(CACHE_ENCODING_RAW);
+    header.extend_from_slice(&[0, 0]);
+    header.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+    header.extend_from_slice(&(SECTION_COUNT as u32).to_le_bytes());
+    header.extend_from_slice(&FLAG_UNICODE_FOLD.to_le_bytes());
+    for s in &sections {
+        header.extend_from_slice(&s.offset.to_le_bytes());
+        header.extend_from_slice(&s.len.to_le_bytes());
+        header.extend_from_slice(&s.crc.to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+    }
+    let header_crc = crc32fast::hash(&header);
+    header.extend_from_slice(&header_crc.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes());
+    debug_assert_eq!(header.len(), DATA_START);
+    out.write_all(&header)?;
+
+    let mut written = DATA_START;
+    for (data, s) in payloads.iter().zip(&sections) {
+        let pad = s.offset as usize - written;
+        out.write_all(&[0u8; 8][..pad])?;
+        out.write_all(data)?;
+        written = s.offset as usize + data.len();
+    }
+    Ok(())
+}
+
+/// 校验头部并返回各 section 描述。
+fn parse_header(bytes: &[u8]) -> std::io::Result<(usize, u32, Vec<Section>)> {
+    if bytes.len() < DATA_START {
+        return Err(invalid("缓存文件过短"));
+    }
+    if !bytes.starts_with(&CACHE_MAGIC) {
+        return Err(invalid("缓存魔数不匹配"));
+    }
+    if bytes[4] != CACHE_V4 {
+        return Err(invalid("缓存版本不支持"));
+    }
+    if bytes[5] != CACHE_ENCODING_RAW {
+        return Err(invalid("缓存编码不支持"));
+    }
+    if bytes[6] != 0 || bytes[7] != 0 {
+        return Err(invalid("缓存保留字段非零"));
+    }
+    if crc32fast::hash(&bytes[..HEADER_CRC_OFFSET]) != read_u32(bytes, HEADER_CRC_OFFSET) {
+        return Err(invalid("缓存头部校验失败"));
+    }
+    let entry_count = usize::try_from(read_u64(bytes, CACHE_HEADER_LEN))
+        .map_err(|_| invalid("缓存条目数过大"))?;
+    if read_u32(bytes, CACHE_HEADER_LEN + 8) as usize != SECTION_COUNT {
+        return Err(invalid("缓存分区数量不匹配"));
+    }
+    let flags = read_u32(bytes, CACHE_HEADER_LEN + 12);
+
+    let mut sections = Vec::with_capacity(SECTION_COUNT);
+    for i in 0..SECTION_COUNT {
+        let at = TABLE_START + i * SECTION_DESC_LEN;
+        let s = Section {
+            offset: read_u64(bytes, at),
+            len: read_u64(bytes, at + 8),
+            crc: read_u32(bytes, at + 16),
+        };
+        let end = s.offset.checked_add(s.len);
+        if s.offset < DATA_START as u64 || end.is_none_or(|end| end > bytes.len() as u64) {
+            return Err(invalid("缓存分区越界"));
+        }
+        sections.push(s);
+    }
+    Ok((entry_count, flags, sections))
+}
+
+fn section_bytes(bytes: &[u8], s: Section) -> &[u8] {
+    &bytes[s.offset as usize..(s.offset + s.len) as usize]
+}
+
+/// 未压缩的 v4 映像：原始编码直接映射文件，压缩编码则先解压到内存。
+enum Image {
+    Mapped(Arc<Mmap>),
+    Decoded(Vec<u8>),
+}
+
+impl Image {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Image::Mapped(map) => map,
+            Image::Decoded(bytes) => bytes,
+        }
+    }
+}
+
+/// 打开缓存文件并返回未压缩映像、外层编码与文件大小。
+fn open_image(cache_path: &Path) -> std::io::Result<(Image, CacheCodec, u64)> {
+    if cfg!(target_endian = "big") {
+        return Err(Error::new(ErrorKind::Unsupported, "v4 缓存仅支持小端平台"));
+    }
+    let file = File::open(cache_path)?;
+    // SAFETY: 缓存文件只由本程序以“写临时文件 + 重命名”的方式替换，映射期间内容不会被原地改写。
+    let map = unsafe { Mmap::map(&file)? };
+    let file_bytes = map.len() as u64;
+    if map.len() < CACHE_HEADER_LEN || !map.starts_with(&CACHE_MAGIC) {
+        return Err(invalid("缓存魔数不匹配"));
+    }
+    if map[4] != CACHE_V4 {
+        return Err(invalid("缓存版本不支持"));
+    }
+    let codec = CacheCodec::from_encoding(map[5]).ok_or_else(|| invalid("缓存编码不支持"))?;
+    if map[6] != 0 || map[7] != 0 {
+        return Err(invalid("缓存保留字段非零"));
+    }
+
+    let payload = &map[CACHE_HEADER_LEN..];
+    let mut decoded = Vec::new();
+    match codec {
+        CacheCodec::Raw => return Ok((Image::Mapped(Arc::new(map)), codec, file_bytes)),
+        CacheCodec::Lz4 => {
+            lz4_flex::frame::FrameDecoder::new(payload).read_to_end(&mut decoded)?;
+        }
+        CacheCodec::Zstd => {
+            zstd::stream::read::Decoder::new(payload)?.read_to_end(&mut decoded)?;
+        }
+    }
+    Ok((Image::Decoded(decoded), codec, file_bytes))
+}
+
+fn column<T: Pod>(image: &Image, s: Section) -> std::io::Result<Column<T>> {
+    let mapped = match image {
+        Image::Mapped(map) => Column::mapped(map, s.offset as usize, s.len as usize),
+        Image::Decoded(_) => None,
+    };
+    mapped
+        .or_else(|| Column::copied(section_bytes(image.bytes(), s)))
+        .ok_or_else(|| invalid("缓存分区长度不正确"))
+}
+
+fn str_column(image: &Image, s: Section) -> std::io::Result<StrColumn> {
+    StrColumn::new(column(image, s)?).ok_or_else(|| invalid("缓存字符串表不是合法 UTF-8"))
+}
+
+fn load_image(image: &Image) -> std::io::Result<(EntryStore, Vec<UsnDriveState>)> {
+    let bytes = image.bytes();
+    let (entry_count, flags, sections) = parse_header(bytes)?;
+    for s in &sections {
+        if crc32fast::hash(section_bytes(bytes, *s)) != s.crc {
+            return Err(invalid("缓存数据校验失败"));
+        }
+    }
+
+    let s = &sections;
+    let mut store = EntryStore::from_columns(StoreColumns {
+        names: str_column(image, s[0])?,
+        name_spans: column(image, s[1])?,
+        name_lowers: str_column(image, s[2])?,
+        name_lower_spans: column(image, s[3])?,
+        paths: str_column(image, s[4])?,
+        path_spans: column(image, s[5])?,
+        path_lowers: str_column(image, s[6])?,
+        path_lower_spans: column(image, s[7])?,
+        meta: column(image, s[8])?,
+        parents: column(image, s[9])?,
+    })
+    .ok_or_else(|| invalid("缓存条目表不一致"))?;
+    if store.len() != entry_count {
+        return Err(invalid("缓存条目数不匹配"));
+    }
+    if flags & FLAG_UNICODE_FOLD == 0 {
+        store.refold_lowers();
+    }
+
+    let usn_states = decode_usn_states(section_bytes(bytes, s[STORE_SECTIONS]))?;
+    Ok((store, usn_states))
+}
+
+/// 加载 v4 缓存。原始编码时返回的 `EntryStore` 在被修改前一直引用映射内存；
+/// 压缩编码时先流式解压，再复制为自有列。
+pub fn load_v4(cache_path: &Path) -> std::io::Result<(EntryStore, Vec<UsnDriveState>)> {
+    let (image, _, _) = open_image(cache_path)?;
+    load_image(&image)
+}
+
+/// 逐个 section 校验 CRC 并尝试完整加载；头部无法解析或解压失败时返回错误。
+pub fn verify_v4(cache_path: &Path) -> std::io::Result<CacheReport> {
+    let (image, codec, file_bytes) = open_image(cache_path)?;
+    let bytes = image.bytes();
+    let (entry_count, _, sections) = parse_header(bytes)?;
+
+    let mut report = CacheReport {
+        version: CACHE_V4,
+        encoding: codec.encoding(),
+        file_bytes,
+        entry_count,
+        checksummed: true,
+        ..CacheReport::default()
+    };
+    for (s, name) in sections.iter().zip(SECTION_NAMES) {
+        if crc32fast::hash(section_bytes(bytes, *s)) != s.crc {
+            report.problems.push(format!("{name}分区校验失败"));
+        }
+    }
+    match decode_usn_states(section_bytes(bytes, sections[STORE_SECTIONS])) {
+        Ok(states) => report.usn_states = states,
+        Err(e) => report.problems.push(e.to_string()),
+    }
+    if report.problems.is_empty()
+        && let Err(e) = load_image(&image)
+    {
+        report.problems.push(e.to_string());
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry_store::EntryMeta;
+    use crate::indexer::FileIndexer;
+
+    fn sample_store() -> EntryStore {
+        let mut store = EntryStore::new();
+        store.push("", "", EntryMeta::new(b'C', 5, 5, 0, 0, true, false));
+        store.push("Docs", "", EntryMeta::new(b'C', 10, 5, 0, 0, true, false));
+        store.push("报告.docx", "", EntryMeta::new(b'C', 11, 10, 42, 7, false, true));
+        store.push("readme.md", "/tmp/readme.md", EntryMeta::default());
+        store
+    }
+
+    fn temp_cache(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("rust_search_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn v4_round_trip_keeps_entries_parents_and_usn_states() {
+        let path = temp_cache("round_trip.idx");
+        let usn = vec![UsnDriveState {
+            drive: b'C',
+            journal_id: 1,
+            root_frn: 5,
+            last_usn: -3,
+        }];
+        FileIndexer::save_cache(&path, &sample_store(), &usn, CacheCodec::Raw).unwrap();
+
+        let (store, states) = FileIndexer::load_cache(&path).unwrap();
+        assert_eq!(store.len(), 4);
+        assert_eq!(store.name(1), "Docs");
+        assert_eq!(store.name_lower(1), "docs");
+        assert_eq!(store.name(2), "报告.docx");
+        assert_eq!(store.path(3), "/tmp/readme.md");
+        assert_eq!(store.meta(2).size, 42);
+        assert!(store.meta(2).is_hidden());
+        assert_eq!(store.parent(2), Some(1));
+        assert_eq!(store.parent(0), None);
+        assert!(store.memory_stats().mapped_bytes > 0);
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].last_usn, -3);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn v4_rejects_corrupted_sections() {
+        let path = temp_cache("corrupted.idx");
+        FileIndexer::save_cache(&path, &sample_store(), &[], CacheCodec::Raw).unwrap();
+        let mut bytes = std::fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        std::fs::write(&path, &bytes).unwrap();
+
+        assert!(FileIndexer::load_cache(&path).is_err());
+        let report = FileIndexer::verify_cache(&path).unwrap();
+        assert_eq!(report.entry_count, 4);
+        assert_eq!(report.problems, vec!["父目录分区校验失败".to_string()]);
+        assert_eq!(report.format_label(), "v4 · 不压缩（mmap 直接加载） · 带校验和");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn save_rotates_backup_and_load_falls_back_to_it() {
+        let path = temp_cache("rotate.idx");
+        let backup = crate::indexer::cache_backup_path(&path);
+        let _ = std::fs::remove_file(&backup);
+        FileIndexer::save_cache(&path, &sample_store(), &[], CacheCodec::Raw).unwrap();
+        assert!(!backup.exists());
+
+        let mut smaller = sample_store();
+        smaller.truncate(2);
+        FileIndexer::save_cache(&path, &smaller, &[], CacheCodec::Raw).unwrap();
+        assert!(FileIndexer::verify_cache(&backup).unwrap().is_ok());
+        assert_eq!(FileIndexer::verify_cache(&path).unwrap().entry_count, 2);
+
+        // 主文件被截断后回退到上一代备份
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+        let (store, _) = FileIndexer::load_cache(&path).unwrap();
+        assert_eq!(store.len(), 4);
+
+        // 原文件无法替换时写出的待切换缓存，下次加载前换上
+        let pending = path.with_extension("new");
+        std::fs::copy(&backup, &pending).unwrap();
+        assert_eq!(crate::indexer::current_cache_path(&path), pending);
+        let (store, _) = FileIndexer::load_cache(&path).unwrap();
+        assert_eq!(store.len(), 4);
+        assert!(!pending.exists());
+        assert!(FileIndexer::verify_cache(&path).unwrap().is_ok());
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup);
+    }
+
+    #[test]
+    fn compressed_codecs_round_trip_and_report_encoding() {
+        for codec in [CacheCodec::Lz4, CacheCodec::Zstd] {
+            let path = temp_cache(&format!("codec_{}.idx", codec.encoding()));
+            FileIndexer::save_cache(&path, &sample_store(), &[], codec).unwrap();
+
+            let (store, _) = FileIndexer::load_cache(&path).unwrap();
+            assert_eq!(store.len(), 4);
+            assert_eq!(store.name(2), "报告.docx");
+            assert_eq!(store.parent(2), Some(1));
+            assert_eq!(store.memory_stats().mapped_bytes, 0);
+
+            let report = FileIndexer::verify_cache(&path).unwrap();
+            assert!(report.is_ok());
+            assert_eq!(CacheCodec::from_encoding(report.encoding), Some(codec));
+            let _ = std::fs::remove_file(&path);
+            let _ = std::fs::remove_file(crate::indexer::cache_backup_path(&path));
+        }
+    }
+
+    /// 对比各编码与 v3（bincode）旧格式的体积与读写耗时：
+    /// `cargo test --release codec_benchmark -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn codec_benchmark() {
+        let mut store = EntryStore::new();
+        for i in 0..500_000u64 {
+            let dir = i / 200;
+            let name = format!("Report_{i:06}.txt");
+            let path = format!("C:/Users/demo/Documents/Project{}/Sub{dir}/{name}", dir % 50);
+            store.push(&name, &path, EntryMeta::new(b'C', i + 100, dir + 1, i * 37, i, false, false));
+        }
+
+        let print_row = |label: &str, size: u64, save_ms: u128, load_ms: u128| {
+            println!(
+                "{label}: {:.1} MB, 写入 {save_ms} ms, 加载 {load_ms} ms",
+                size as f64 / (1024.0 * 1024.0)
+            );
+        };
+
+        // 基准：v3 bincode 缓存
+        let path = temp_cache("bench_v3.idx");
+        let t = std::time::Instant::now();
+        crate::indexer::save_cache_v3(&path, &store, &[]).unwrap();
+        let save_ms = t.elapsed().as_millis();
+        let size = std::fs::metadata(&path).unwrap().len();
+        let t = std::time::Instant::now();
+        let (loaded, _) = FileIndexer::load_cache(&path).unwrap();
+        let load_ms = t.elapsed().as_millis();
+        assert_eq!(loaded.len(), store.len());
+        print_row("v3 bincode", size, save_ms, load_ms);
+        let _ = std::fs::remove_file(&path);
+
+        for codec in CacheCodec::ALL {
+            let path = temp_cache(&format!("bench_{}.idx", codec.encoding()));
+            let _ = std::fs::remove_file(&path);
+            let t = std::time::Instant::now();
+            FileIndexer::save_cache(&path, &store, &[], codec).unwrap();
+            let save_ms = t.elapsed().as_millis();
+            let size = std::fs::metadata(&path).unwrap().len();
+            let t = std::time::Instant::now();
+            let (loaded, _) = FileIndexer::load_cache(&path).unwrap();
+            let load_ms = t.elapsed().as_millis();
+            assert_eq!(loaded.len(), store.len());
+            print_row(&format!("v4 {codec:?}"), size, save_ms, load_ms);
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
-#[cfg(windows)]
-use std::hash::{BuildHasherDefault, Hasher};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -11,18 +10,22 @@ use walkdir::WalkDir;
 use bincode::Options;
 use serde::{Deserialize, Serialize};
 
-const CACHE_MAGIC: [u8; 4] = *b"RSIX";
-const CACHE_HEADER_LEN: usize = 8;
+use crate::entry_store::{EntryMeta, EntryRef, EntryStore};
+use crate::fold::fold_for_search;
+use crate::index_file::{self, CACHE_V4, CacheCodec, CacheReport};
+
+pub(crate) const CACHE_MAGIC: [u8; 4] = *b"RSIX";
+pub(crate) const CACHE_HEADER_LEN: usize = 8;
 const CACHE_V2: u8 = 2;
 const CACHE_V3: u8 = 3;
 const CACHE_ENCODING_VARINT: u8 = 1;
 
+/// 测试中逐项构造索引用的条目。
+#[cfg(test)]
 #[derive(Clone, Debug)]
 pub struct FileEntry {
     pub name: String,
-    pub name_lower: String,
     pub path: String,
-    pub path_lower: String,
     pub drive: u8,
     pub frn: u64,
     pub parent_frn: u64,
@@ -41,14 +44,15 @@ pub struct UsnDriveState {
 }
 
 pub struct FileIndexer {
-    entries: Arc<Vec<FileEntry>>,
+    entries: Arc<EntryStore>,
     name_index: HashMap<String, Vec<usize>>,
     total_files: Arc<AtomicUsize>,
     is_indexing: Arc<AtomicBool>,
     progress: Arc<AtomicUsize>,
     usn_states: Vec<UsnDriveState>,
-    #[cfg(windows)]
-    windows_dir_index: WindowsDirIndex,
+    /// 索引版本：重建或加载缓存时加一。同一版本内的改动（`edit`）只追加条目或改写单个条目，
+    /// 已有下标始终指向同一文件，结果与操作目标用它确认下标仍然有效。
+    generation: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -73,44 +77,6 @@ pub struct IndexBuildStats {
     pub roots: Vec<IndexRootStats>,
 }
 
-#[cfg(windows)]
-#[derive(Default)]
-struct WindowsDirHasher(u64);
-
-#[cfg(windows)]
-impl Hasher for WindowsDirHasher {
-    fn write(&mut self, bytes: &[u8]) {
-        let mut x = self.0;
-        for &b in bytes {
-            x ^= b as u64;
-            x = x.wrapping_mul(0x100_0000_01B3);
-        }
-        self.0 = x;
-    }
-
-    fn write_u64(&mut self, i: u64) {
-        let mut x = i ^ self.0;
-        x ^= x >> 33;
-        x = x.wrapping_mul(0xff51afd7ed558ccd);
-        x ^= x >> 33;
-        x = x.wrapping_mul(0xc4ceb9fe1a85ec53);
-        x ^= x >> 33;
-        self.0 = x;
-    }
-
-    fn write_u128(&mut self, i: u128) {
-        self.write_u64((i >> 64) as u64);
-        self.write_u64(i as u64);
-    }
-
-    fn finish(&self) -> u64 {
-        self.0
-    }
-}
-
-#[cfg(windows)]
-type WindowsDirIndex = HashMap<u128, usize, BuildHasherDefault<WindowsDirHasher>>;
-
 #[derive(Clone)]
 pub struct IndexerHandles {
     pub total_files: Arc<AtomicUsize>,
@@ -166,97 +132,50 @@ struct DiskEntryV3 {
     parent_frn: u64,
 }
 
-#[derive(Serialize)]
-struct IndexCachePayloadV3Ref<'a> {
-    entries: Vec<DiskEntryV3Ref<'a>>,
-    usn_states: &'a [UsnDriveState],
-}
-
-#[derive(Serialize)]
-struct DiskEntryV3Ref<'a> {
-    path: &'a str,
-    size: u64,
-    modified_ms: u64,
-    flags: u8,
-    drive: u8,
-    frn: u64,
-    parent_frn: u64,
-}
-
-impl<'a> DiskEntryV3Ref<'a> {
-    fn from_entry(entry: &'a FileEntry) -> Self {
-        let mut flags = 0u8;
-        if entry.is_dir {
-            flags |= 1 << 0;
-        }
-        if entry.is_hidden {
-            flags |= 1 << 1;
-        }
-        Self {
-            path: entry.path.as_str(),
-            size: entry.size,
-            modified_ms: entry.modified_ms,
-            flags,
-            drive: entry.drive,
-            frn: entry.frn,
-            parent_frn: entry.parent_frn,
-        }
-    }
-}
-
 impl DiskEntryV2 {
-    fn to_entry(&self) -> FileEntry {
+    fn push_into(&self, store: &mut EntryStore) {
         let name = file_name_from_normalized_path(&self.path);
-        let name_lower = lowercase_for_index_field(&name);
-        let path_lower = lowercase_for_index_field(&self.path);
-        FileEntry {
-            name,
-            name_lower,
-            path: self.path.clone(),
-            path_lower,
-            drive: 0,
-            frn: 0,
-            parent_frn: 0,
-            size: self.size,
-            modified_ms: self.modified_ms,
-            is_dir: (self.flags & (1 << 0)) != 0,
-            is_hidden: (self.flags & (1 << 1)) != 0,
-        }
+        store.push(
+            &name,
+            &self.path,
+            EntryMeta {
+                size: self.size,
+                modified_ms: self.modified_ms,
+                flags: self.flags,
+                ..EntryMeta::default()
+            },
+        );
     }
 }
 
 impl DiskEntryV3 {
-    fn to_entry(&self) -> FileEntry {
+    fn push_into(&self, store: &mut EntryStore) {
         let name = file_name_from_normalized_path(&self.path);
-        let name_lower = lowercase_for_index_field(&name);
-        let path_lower = lowercase_for_index_field(&self.path);
-        FileEntry {
-            name,
-            name_lower,
-            path: self.path.clone(),
-            path_lower,
-            drive: self.drive,
-            frn: self.frn,
-            parent_frn: self.parent_frn,
-            size: self.size,
-            modified_ms: self.modified_ms,
-            is_dir: (self.flags & (1 << 0)) != 0,
-            is_hidden: (self.flags & (1 << 1)) != 0,
-        }
+        store.push(
+            &name,
+            &self.path,
+            EntryMeta {
+                frn: self.frn,
+                parent_frn: self.parent_frn,
+                size: self.size,
+                modified_ms: self.modified_ms,
+                drive: self.drive,
+                flags: self.flags,
+            },
+        );
     }
 }
 
 impl FileIndexer {
     pub fn new() -> Self {
         Self {
-            entries: Arc::new(Vec::new()),
+            entries: Arc::new(EntryStore::new()),
             name_index: HashMap::new(),
             total_files: Arc::new(AtomicUsize::new(0)),
             is_indexing: Arc::new(AtomicBool::new(false)),
             progress: Arc::new(AtomicUsize::new(0)),
             usn_states: Vec::new(),
-            #[cfg(windows)]
-            windows_dir_index: WindowsDirIndex::default(),
+            generation: 0,
         }
     }
 
@@ -271,11 +190,11 @@ impl FileIndexer {
         )
     }
 
-    pub fn get_entries(&self) -> &Vec<FileEntry> {
+    pub fn get_entries(&self) -> &EntryStore {
         &self.entries
     }
 
-    pub fn entries_arc(&self) -> Arc<Vec<FileEntry>> {
+    pub fn entries_arc(&self) -> Arc<EntryStore> {
         Arc::clone(&self.entries)
     }
 
@@ -293,22 +212,40 @@ impl FileIndexer {
         self.total_files.store(0, Ordering::SeqCst);
     }
 
-    pub fn replace_index(&mut self, all_entries: Vec<FileEntry>, usn_states: Vec<UsnDriveState>) {
+    pub fn replace_index(&mut self, mut all_entries: EntryStore, usn_states: Vec<UsnDriveState>) {
+        if !all_entries.parents_linked() {
+            all_entries.link_parents();
+        }
         let count = all_entries.len();
         self.entries = Arc::new(all_entries);
+        self.generation += 1;
         self.name_index = HashMap::new();
         self.usn_states = usn_states;
-        #[cfg(windows)]
-        self.rebuild_windows_dir_index();
         self.total_files.store(count, Ordering::SeqCst);
         self.progress.store(count, Ordering::SeqCst);
         self.is_indexing.store(false, Ordering::SeqCst);
     }
 
-    pub fn entries_mut(&mut self) -> &mut Vec<FileEntry> {
+    #[cfg(test)]
+    pub fn entries_mut(&mut self) -> &mut EntryStore {
         Arc::make_mut(&mut self.entries)
     }
 
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// 就地改写索引（重命名、删除标记、追加）；`generation` 之后重建过时下标不再对应，放弃并返回 `false`。
+    /// 搜索结果只保存各自页面的条目副本，平时没有其他强引用，`make_mut` 不会复制整个索引。
+    pub fn edit(&mut self, generation: u64, apply: impl FnOnce(&mut EntryStore)) -> bool {
+        if generation != self.generation {
+            return false;
+        }
+        apply(Arc::make_mut(&mut self.entries));
+        self.total_files.store(self.entries.len(), Ordering::SeqCst);
+        true
+    }
+
     pub fn usn_states(&self) -> &[UsnDriveState] {
         &self.usn_states
     }
@@ -317,35 +254,41 @@ impl FileIndexer {
         self.usn_states.clone()
     }
 
-    pub fn set_cache(&mut self, entries: Vec<FileEntry>, usn_states: Vec<UsnDriveState>) {
+    pub fn set_cache(&mut self, mut entries: EntryStore, usn_states: Vec<UsnDriveState>) {
+        // v4 缓存自带父目录下标，直接沿用映射数据
+        if !entries.parents_linked() {
+            entries.link_parents();
+        }
         self.entries = Arc::new(entries);
+        self.generation += 1;
         // 当前 UI 搜索走 `Searcher` 全量扫描，不依赖 `name_index`；
         // 这里避免构建 HashMap 以加速启动/加载缓存。
         self.name_index = HashMap::new();
         self.usn_states = usn_states;
-        #[cfg(windows)]
-        self.rebuild_windows_dir_index();
         self.total_files.store(self.entries.len(), Ordering::SeqCst);
         self.progress.store(self.entries.len(), Ordering::SeqCst);
         self.is_indexing.store(false, Ordering::SeqCst);
     }
 
+    #[cfg(test)]
     pub fn set_entries_from_cache(&mut self, entries: Vec<FileEntry>) {
-        self.set_cache(entries, Vec::new());
+        self.set_cache(EntryStore::from_entries(entries), Vec::new());
     }
 
     #[cfg(windows)]
     pub fn try_apply_usn_incremental(&mut self, handles: &IndexerHandles) -> std::io::Result<bool> {
-        if self.usn_states.is_empty() || self.entries.is_empty() {
+        if self.usn_states.is_empty() || self.entries.len() == 0 {
             self.is_indexing.store(false, Ordering::SeqCst);
             return Ok(false);
         }
 
+        // 直接在列式存储上按 FRN 增删改：删除只打标记、新建追加到末尾，已有条目的下标不变
         let mut usn_states = std::mem::take(&mut self.usn_states);
-        {
-            let entries = self.entries_mut();
-            crate::windows_usn::try_apply_usn_incremental(entries, &mut usn_states, handles)?;
+        let entries = Arc::make_mut(&mut self.entries);
+        if !entries.parents_linked() {
+            entries.link_parents();
         }
+        crate::windows_usn::try_apply_usn_incremental(entries, &mut usn_states, handles)?;
         self.usn_states = usn_states;
 
         let count = self.entries.len();
@@ -358,10 +301,10 @@ impl FileIndexer {
     pub fn build_index_snapshot(
         root_paths: Vec<PathBuf>,
         handles: Option<&IndexerHandles>,
-    ) -> (Vec<FileEntry>, Vec<UsnDriveState>) {
+    ) -> (EntryStore, Vec<UsnDriveState>) {
         #[cfg(windows)]
         {
-            let mut all_entries: Vec<FileEntry> = Vec::new();
+            let mut all_entries = EntryStore::new();
             let mut usn_states: Vec<UsnDriveState> = Vec::new();
             let mut count: usize = 0;
 
@@ -373,14 +316,14 @@ impl FileIndexer {
                 if crate::windows_usn::is_drive_root(root_path).is_some() {
                     let is_indexing = handles.map(|h| &*h.is_indexing);
                     let progress = handles.map(|h| &*h.progress);
-                    if let Ok((mut entries, state)) = crate::windows_usn::try_enumerate_drive_root(
+                    if let Ok(state) = crate::windows_usn::try_enumerate_drive_root(
                         root_path,
                         count,
                         is_indexing,
                         progress,
+                        &mut all_entries,
                     ) {
-                        count = count.saturating_add(entries.len());
-                        all_entries.append(&mut entries);
+                        count = all_entries.len();
                         usn_states.push(state);
                         if let Some(handles) = handles {
                             handles.progress.store(count, Ordering::SeqCst);
@@ -396,6 +339,7 @@ impl FileIndexer {
                 handles.progress.store(count, Ordering::SeqCst);
             }
 
+            all_entries.shrink_to_fit();
             return (all_entries, usn_states);
         }
 
@@ -408,13 +352,13 @@ impl FileIndexer {
     pub fn build_index_snapshot_with_stats(
         root_paths: Vec<PathBuf>,
         handles: Option<&IndexerHandles>,
-    ) -> (Vec<FileEntry>, Vec<UsnDriveState>, IndexBuildStats) {
+    ) -> (EntryStore, Vec<UsnDriveState>, IndexBuildStats) {
         #[cfg(windows)]
         {
             let mut stats = IndexBuildStats::default();
             let total_start = Instant::now();
 
-            let mut all_entries: Vec<FileEntry> = Vec::new();
+            let mut all_entries = EntryStore::new();
             let mut usn_states: Vec<UsnDriveState> = Vec::new();
             let mut count: usize = 0;
 
@@ -428,16 +372,17 @@ impl FileIndexer {
                     let start = Instant::now();
                     let is_indexing = handles.map(|h| &*h.is_indexing);
                     let progress = handles.map(|h| &*h.progress);
+                    let before = all_entries.len();
                     match crate::windows_usn::try_enumerate_drive_root(
                         root_path,
                         count,
                         is_indexing,
                         progress,
+                        &mut all_entries,
                     ) {
-                        Ok((mut entries, state)) => {
-                            let appended = entries.len();
+                        Ok(state) => {
+                            let appended = all_entries.len().saturating_sub(before);
                             count = count.saturating_add(appended);
-                            all_entries.append(&mut entries);
                             usn_states.push(state);
                             if let Some(handles) = handles {
                                 handles.progress.store(count, Ordering::SeqCst);
@@ -464,7 +409,6 @@ impl FileIndexer {
                                     ""
                                 }
                             );
-                            let before = all_entries.len();
                             append_walkdir_entries_for_paths(
                                 &[root_path.clone()],
                                 handles,
@@ -506,6 +450,7 @@ impl FileIndexer {
                 handles.progress.store(count, Ordering::SeqCst);
             }
 
+            all_entries.shrink_to_fit();
             stats.total_ms = total_start.elapsed().as_millis();
             stats.total_entries = all_entries.len();
             return (all_entries, usn_states, stats);
@@ -515,7 +460,7 @@ impl FileIndexer {
         {
             let mut stats = IndexBuildStats::default();
             let total_start = Instant::now();
-            let mut all_entries: Vec<FileEntry> = Vec::new();
+            let mut all_entries = EntryStore::new();
             let mut count: usize = 0;
             for root_path in &root_paths {
                 if !root_path.exists() {
@@ -541,56 +486,52 @@ impl FileIndexer {
             if let Some(handles) = handles {
                 handles.progress.store(count, Ordering::SeqCst);
             }
+            all_entries.shrink_to_fit();
             stats.total_ms = total_start.elapsed().as_millis();
             stats.total_entries = all_entries.len();
             (all_entries, Vec::new(), stats)
         }
     }
 
-    #[cfg(windows)]
-    pub fn display_path_for(&self, entry: &FileEntry) -> String {
-        if !entry.path.is_empty() {
-            return entry.path.clone();
+    /// 完整路径：条目自带路径时直接返回；否则沿父目录链接向上，直到带路径的祖先（WalkDir 根）
+    /// 或 USN 卷根，再依次拼接各级名称。
+    pub fn display_path_for(&self, index: usize) -> String {
+        let Some(entry) = self.entries.get(index) else {
+            return String::new();
+        };
+        if !entry.path().is_empty() {
+            return entry.path().to_string();
         }
-
-        if entry.drive == 0 || entry.frn == 0 {
-            return entry.path.clone();
+        if entry.frn() == 0 {
+            return String::new();
         }
 
-        let Some(root_frn) = self
+        let root_frn = self
             .usn_states
             .iter()
-            .find(|s| s.drive == entry.drive)
-            .map(|s| s.root_frn)
-        else {
-            let drive = entry.drive as char;
-            if entry.name.is_empty() {
-                return format!("{drive}:/");
-            }
-            return format!("{drive}:/{name}", name = entry.name);
-        };
-
-        let drive = entry.drive as char;
-        if entry.frn == root_frn || entry.name.is_empty() {
-            return format!("{drive}:/");
+            .find(|s| entry.drive() != 0 && s.drive == entry.drive())
+            .map(|s| s.root_frn);
+        let is_volume_root =
+            |e: EntryRef<'_>| e.drive() != 0 && (Some(e.frn()) == root_frn || e.name().is_empty());
+        let drive_root = || format!("{}:/", entry.drive() as char);
+        if is_volume_root(entry) {
+            return drive_root();
         }
 
-        let mut parts: Vec<&str> = Vec::new();
-        parts.push(entry.name.as_str());
-
-        let mut cur = entry.parent_frn;
+        let mut parts: Vec<&str> = vec![entry.name()];
+        let mut prefix: Option<&str> = None;
+        let mut cur = entry.parent();
         let mut depth = 0usize;
-        while cur != 0 && cur != root_frn {
-            let key = windows_key(entry.drive, cur);
-            let Some(&idx) = self.windows_dir_index.get(&key) else {
+        while let Some(dir) = cur {
+            if !dir.path().is_empty() {
+                prefix = Some(dir.path());
                 break;
-            };
-            let dir = &self.entries[idx];
-            if dir.name.is_empty() {
+            }
+            if is_volume_root(dir) {
                 break;
             }
-            parts.push(dir.name.as_str());
-            cur = dir.parent_frn;
+            parts.push(dir.name());
+            cur = dir.parent();
 
             depth += 1;
             if depth > 4096 {
@@ -598,11 +539,13 @@ impl FileIndexer {
             }
         }
 
-        let mut path = String::new();
-        path.push(drive);
-        path.push_str(":/");
-        for (i, part) in parts.iter().rev().enumerate() {
-            if i > 0 {
+        let mut path = match prefix {
+            Some(p) => p.to_string(),
+            None if entry.drive() != 0 => drive_root(),
+            None => String::new(),
+        };
+        for part in parts.iter().rev() {
+            if !path.is_empty() && !path.ends_with('/') {
                 path.push('/');
             }
             path.push_str(part);
@@ -610,99 +553,121 @@ impl FileIndexer {
         path
     }
 
-    #[cfg(not(windows))]
-    pub fn display_path_for(&self, entry: &FileEntry) -> String {
-        entry.path.clone()
-    }
-
-    #[cfg(windows)]
-    fn rebuild_windows_dir_index(&mut self) {
-        self.windows_dir_index.clear();
-        self.windows_dir_index
-            .reserve(self.entries.len().saturating_div(8).max(1024));
-        for (idx, entry) in self.entries.iter().enumerate() {
-            if entry.drive == 0 || entry.frn == 0 {
-                continue;
-            }
-            if !entry.is_dir {
-                continue;
+    pub fn load_cache(cache_path: &Path) -> std::io::Result<(EntryStore, Vec<UsnDriveState>)> {
+        // 上次保存时原文件仍被映射、新缓存留在待切换文件中：此时尚未映射任何缓存，先换上
+        let pending = cache_pending_path(cache_path);
+        if pending.exists() && std::fs::rename(&pending, cache_path).is_ok() {
+            sync_parent_dir(cache_path);
+        }
+        match Self::load_cache_file(&current_cache_path(cache_path)) {
+            Ok(loaded) => Ok(loaded),
+            Err(err) => {
+                let backup = cache_backup_path(cache_path);
+                if !backup.exists() {
+                    return Err(err);
+                }
+                Self::load_cache_file(&backup).map_err(|_| err)
             }
-            self.windows_dir_index.insert(windows_key(entry.drive, entry.frn), idx);
         }
     }
 
-    pub fn load_cache(cache_path: &Path) -> std::io::Result<(Vec<FileEntry>, Vec<UsnDriveState>)> {
-        let bytes = std::fs::read(cache_path)?;
-        if bytes.len() >= CACHE_HEADER_LEN && bytes.starts_with(&CACHE_MAGIC) {
-            return load_cache_with_header(&bytes);
+    fn load_cache_file(cache_path: &Path) -> std::io::Result<(EntryStore, Vec<UsnDriveState>)> {
+        // v4 直接映射文件，不整读；先只看通用头部里的版本字节
+        if peek_cache_version(cache_path)? == Some(CACHE_V4) {
+            return index_file::load_v4(cache_path);
         }
 
-        // 兼容旧缓存（v1：纯 bincode + 包含 name_lower/path_lower）
-        let cache: IndexCacheV1 = bincode::deserialize(&bytes).map_err(|e| {
-            std::io::Error::new(std::io::ErrorKind::InvalidData, format!("反序列化失败: {e}"))
-        })?;
-        if cache.version != 1 {
-            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "缓存版本不匹配"));
+        let bytes = std::fs::read(cache_path)?;
+        let (entries, usn_states, version) = load_legacy_cache(&bytes)?;
+        if version == 1 {
+            // 尝试自动升级到 v4 缓存格式（失败则忽略，避免影响启动）
+            let _ = Self::save_cache(cache_path, &entries, &[], CacheCodec::Raw);
         }
-        let entries: Vec<FileEntry> = cache
-            .entries
-            .into_iter()
-            .map(|e| FileEntry {
-                name: e.name,
-                name_lower: e.name_lower,
-                path: e.path,
-                path_lower: e.path_lower,
-                drive: 0,
-                frn: 0,
-                parent_frn: 0,
-                size: e.size,
-                modified_ms: e.modified_ms,
-                is_dir: e.is_dir,
-                is_hidden: e.is_hidden,
-            })
-            .collect();
+        Ok((entries, usn_states))
+    }
 
-        // 尝试自动升级到 v3 缓存格式（失败则忽略，避免影响启动）
-        let _ = Self::save_cache(cache_path, &entries, &[]);
+    /// 检查缓存完整性：版本、校验和、条目数与 USN 状态。只读，不会升级或改写文件。
+    pub fn verify_cache(cache_path: &Path) -> std::io::Result<CacheReport> {
+        if peek_cache_version(cache_path)? == Some(CACHE_V4) {
+            return index_file::verify_v4(cache_path);
+        }
 
-        Ok((entries, Vec::new()))
+        let bytes = std::fs::read(cache_path)?;
+        let (version, encoding) = if bytes.starts_with(&CACHE_MAGIC) && bytes.len() >= CACHE_HEADER_LEN {
+            (bytes[4], bytes[5])
+        } else {
+            (1, 0)
+        };
+        let mut report = CacheReport {
+            version,
+            encoding,
+            file_bytes: bytes.len() as u64,
+            checksummed: false,
+            ..CacheReport::default()
+        };
+        match load_legacy_cache(&bytes) {
+            Ok((entries, usn_states, _)) => {
+                report.entry_count = entries.len();
+                report.usn_states = usn_states;
+            }
+            Err(e) => report.problems.push(e.to_string()),
+        }
+        Ok(report)
     }
 
+    /// 原子地写入缓存：
+    /// 1. 写临时文件并 fsync；
+    /// 2. 把现有缓存硬链接（不支持时复制）为 `.bak`，只看头部，不整份校验；
+    /// 3. 用重命名把临时文件直接替换为主文件，再 fsync 所在目录。
+    ///
+    /// 主文件在任何时刻都存在，且要么是旧缓存、要么是完整的新缓存。
     pub fn save_cache(
         cache_path: &Path,
-        entries: &[FileEntry],
+        entries: &EntryStore,
         usn_states: &[UsnDriveState],
+        codec: CacheCodec,
     ) -> std::io::Result<()> {
         if let Some(parent) = cache_path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        // v3：写入更小的磁盘格式（去掉 name_lower/path_lower 等重复字段），并保存 USN 增量状态（Windows）
-        // 文件格式：RSIX(4) + version(u8) + encoding(u8) + reserved(u16) + bincode(payload)
-        // 这里用借用版 payload，避免对每个 entry 的 path 做 clone（会显著拖慢大索引的缓存写入）。
-        let payload = IndexCachePayloadV3Ref {
-            entries: entries.iter().map(DiskEntryV3Ref::from_entry).collect(),
-            usn_states,
+        // v4：各列按内存布局原样写出，不压缩时加载可直接 mmap（格式见 `index_file`）
+        let tmp_path = cache_path.with_extension("tmp");
+        {
+            let mut file = std::io::BufWriter::new(std::fs::File::create(&tmp_path)?);
+            index_file::write_v4(&mut file, entries, usn_states, codec)?;
+            let file = file.into_inner().map_err(|e| e.into_error())?;
+            file.sync_all()?;
+        }
+
+        // 头部都不对的旧文件不值得备份，也不应覆盖已有的好备份；
+        // 旧备份仍被映射（Windows）删不掉时保留它，它依旧是一份可用的旧缓存
+        let current = current_cache_path(cache_path);
+        let backup = cache_backup_path(cache_path);
+        let backup_free = match std::fs::remove_file(&backup) {
+            Ok(()) => true,
+            Err(e) => e.kind() == std::io::ErrorKind::NotFound,
         };
-        let options = bincode::DefaultOptions::new().with_varint_encoding();
-        let payload_bytes = options.serialize(&payload).map_err(|e| {
-            std::io::Error::new(std::io::ErrorKind::InvalidData, format!("序列化失败: {e}"))
-        })?;
-        let mut bytes = Vec::with_capacity(CACHE_HEADER_LEN + payload_bytes.len());
-        bytes.extend_from_slice(&CACHE_MAGIC);
-        bytes.push(CACHE_V3);
-        bytes.push(CACHE_ENCODING_VARINT);
-        bytes.extend_from_slice(&[0, 0]);
-        bytes.extend_from_slice(&payload_bytes);
+        if backup_free
+            && matches!(peek_cache_version(&current), Ok(Some(_)))
+            && std::fs::hard_link(&current, &backup).is_err()
+        {
+            std::fs::copy(&current, &backup)?;
+        }
 
-        let tmp_path = cache_path.with_extension("tmp");
-        std::fs::write(&tmp_path, bytes)?;
-        let _ = std::fs::remove_file(cache_path);
-        std::fs::rename(tmp_path, cache_path)?;
+        // Windows 上仍被映射（加载后的索引或搜索结果还在使用）的文件不能被替换，
+        // 这时新缓存写到待切换文件，下次加载前再换上
+        let pending = cache_pending_path(cache_path);
+        if std::fs::rename(&tmp_path, cache_path).is_ok() {
+            let _ = std::fs::remove_file(&pending);
+        } else {
+            std::fs::rename(&tmp_path, &pending)?;
+        }
+        sync_parent_dir(cache_path);
         Ok(())
     }
 
-    pub fn search(&self, pattern: &str, case_sensitive: bool, max_results: usize) -> Vec<&FileEntry> {
+    pub fn search(&self, pattern: &str, case_sensitive: bool, max_results: usize) -> Vec<EntryRef<'_>> {
         if pattern.is_empty() {
             return Vec::new();
         }
@@ -710,20 +675,18 @@ impl FileIndexer {
         let pattern = if case_sensitive {
             pattern.to_string()
         } else {
-            lowercase_for_search(pattern)
+            fold_for_search(pattern).into_owned()
         };
 
-        let mut results: Vec<&FileEntry> = Vec::with_capacity(max_results);
+        let mut results: Vec<EntryRef<'_>> = Vec::with_capacity(max_results);
 
         // 兼容：如果 `name_index` 没有构建（默认），则直接扫描 entries。
         if self.name_index.is_empty() {
             for entry in self.entries.iter() {
                 let haystack = if case_sensitive {
-                    entry.name.as_str()
-                } else if entry.name_lower.is_empty() {
-                    entry.name.as_str()
+                    entry.name()
                 } else {
-                    entry.name_lower.as_str()
+                    entry.name_lower()
                 };
                 if haystack.contains(&pattern) {
                     results.push(entry);
@@ -771,7 +734,7 @@ impl FileIndexer {
         let is_indexing = Arc::clone(&self.is_indexing);
 
         thread::spawn(move || {
-            let mut _all_entries: Vec<FileEntry> = Vec::new();
+            let mut _all_entries = EntryStore::new();
             let mut count = 0;
 
             for root_path in &root_paths {
@@ -804,24 +767,8 @@ impl FileIndexer {
                         .to_string();
 
                     let path_str = path.to_string_lossy().replace("\\", "/");
-                    let name_lower = lowercase_for_search(&name);
-                    let path_lower = lowercase_for_search(&path_str);
-
-                    let file_entry = FileEntry {
-                        name,
-                        name_lower,
-                        path: path_str,
-                        path_lower,
-                        drive: 0,
-                        frn: 0,
-                        parent_frn: 0,
-                        size: metadata.len(),
-                        modified_ms: 0,
-                        is_dir,
-                        is_hidden,
-                    };
-
-                    _all_entries.push(file_entry);
+                    let meta = EntryMeta::new(0, 0, 0, metadata.len(), 0, is_dir, is_hidden);
+                    _all_entries.push(&name, &path_str, meta);
 
                     count += 1;
                     if count % 1000 == 0 {
@@ -841,71 +788,11 @@ impl FileIndexer {
     }
 
     pub fn build_index(&mut self, root_paths: Vec<PathBuf>) {
-        self.is_indexing.store(true, Ordering::SeqCst);
-        self.progress.store(0, Ordering::SeqCst);
-        self.total_files.store(0, Ordering::SeqCst);
-
-        let mut all_entries: Vec<FileEntry> = Vec::new();
-        let mut count = 0;
-
-        for root_path in &root_paths {
-            if !root_path.exists() {
-                continue;
-            }
-
-            for entry in WalkDir::new(root_path)
-                .follow_links(false)
-                .same_file_system(true)
-                .into_iter()
-                .filter_map(|e| e.ok())
-            {
-                let path = entry.path();
-                let metadata = match entry.metadata() {
-                    Ok(m) => m,
-                    Err(_) => continue,
-                };
-
-                let is_dir = metadata.is_dir();
-                let is_hidden = is_path_hidden(path, &metadata);
-
-                let name = path.file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("")
-                    .to_string();
-
-                let path_str = path.to_string_lossy().replace("\\", "/");
-                let name_lower = lowercase_for_search(&name);
-                let path_lower = lowercase_for_search(&path_str);
-
-                let file_entry = FileEntry {
-                    name,
-                    name_lower,
-                    path: path_str,
-                    path_lower,
-                    drive: 0,
-                    frn: 0,
-                    parent_frn: 0,
-                    size: metadata.len(),
-                    modified_ms: 0,
-                    is_dir,
-                    is_hidden,
-                };
-
-                all_entries.push(file_entry);
-
-                count += 1;
-                if count % 1000 == 0 {
-                    self.progress.store(count, Ordering::SeqCst);
-                }
-            }
-        }
-
-        self.total_files.store(count, Ordering::SeqCst);
-        self.progress.store(count, Ordering::SeqCst);
-
-        self.entries = Arc::new(all_entries);
-        self.name_index = HashMap::new();
-        self.is_indexing.store(false, Ordering::SeqCst);
+        self.begin_indexing();
+        let handles = self.handles();
+        let (mut all_entries, usn_states) = build_index_snapshot_walkdir(&root_paths, Some(&handles));
+        all_entries.shrink_to_fit();
+        self.replace_index(all_entries, usn_states);
     }
 
     pub fn stop(&self) {
@@ -916,27 +803,37 @@ impl FileIndexer {
 fn build_index_snapshot_walkdir(
     root_paths: &[PathBuf],
     handles: Option<&IndexerHandles>,
-) -> (Vec<FileEntry>, Vec<UsnDriveState>) {
-    let mut all_entries: Vec<FileEntry> = Vec::new();
+) -> (EntryStore, Vec<UsnDriveState>) {
+    let mut all_entries = EntryStore::new();
     let mut count: usize = 0;
     append_walkdir_entries_for_paths(root_paths, handles, &mut count, &mut all_entries);
     if let Some(handles) = handles {
         handles.progress.store(count, Ordering::SeqCst);
     }
+    all_entries.shrink_to_fit();
     (all_entries, Vec::new())
 }
 
+/// 把 `root` 及其下全部条目追加到 `out`：界面中移动或复制后，在后台扫描新位置（再由 `EntryStore::append_walked` 并入索引）。
+pub fn append_subtree(out: &mut EntryStore, root: &Path) {
+    let mut count = 0;
+    append_walkdir_entries_for_paths(&[root.to_path_buf()], None, &mut count, out);
+}
+
 fn append_walkdir_entries_for_paths(
     root_paths: &[PathBuf],
     handles: Option<&IndexerHandles>,
     count: &mut usize,
-    out: &mut Vec<FileEntry>,
+    out: &mut EntryStore,
 ) {
     for root_path in root_paths {
         if !root_path.exists() {
             continue;
         }
 
+        // WalkDir 先序遍历：父目录总是先于子项出现，按深度记录目录的合成 id 即可得到 parent id。
+        // 与 USN 的 frn/parent_frn 一样，路径只为根条目保存，其余条目由 `display_path_for` 沿父链拼接。
+        let mut dir_ids: Vec<u64> = Vec::new();
         for entry in WalkDir::new(root_path)
             .follow_links(false)
             .same_file_system(true)
@@ -958,31 +855,29 @@ fn append_walkdir_entries_for_paths(
             let is_dir = metadata.is_dir();
             let is_hidden = is_path_hidden(path, &metadata);
 
-            let name = path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("")
-                .to_string();
-
-            let path_str = path.to_string_lossy().replace("\\", "/");
-            let name_lower = lowercase_for_index_field(&name);
-            let path_lower = lowercase_for_index_field(&path_str);
-
-            let file_entry = FileEntry {
-                name,
-                name_lower,
-                path: path_str,
-                path_lower,
-                drive: 0,
-                frn: 0,
-                parent_frn: 0,
-                size: metadata.len(),
-                modified_ms: 0,
-                is_dir,
-                is_hidden,
-            };
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
 
-            out.push(file_entry);
+            // 祖先目录被跳过（如读取元数据失败）时栈深度对不上，此时退回保存完整路径
+            let depth = entry.depth();
+            dir_ids.truncate(depth);
+            let id = out.len() as u64 + 1;
+            let parent_id = if depth > 0 && dir_ids.len() == depth {
+                dir_ids[depth - 1]
+            } else {
+                0
+            };
+            let path_str = if parent_id == 0 {
+                path.to_string_lossy().replace("\\", "/")
+            } else {
+                String::new()
+            };
+            let meta = EntryMeta::new(0, id, parent_id, metadata.len(), modified_ms(&metadata), is_dir, is_hidden);
+            // 合成 id 即下标 + 1；已链接父链的索引（界面中补扫子树时）直接挂上父条目
+            let parent = (parent_id != 0).then(|| parent_id as usize - 1);
+            out.push_linked(name, &path_str, meta, parent);
+            if is_dir && dir_ids.len() == depth {
+                dir_ids.push(id);
+            }
 
             *count = count.saturating_add(1);
             if *count % 1000 == 0 {
@@ -994,11 +889,6 @@ fn append_walkdir_entries_for_paths(
     }
 }
 
-#[cfg(windows)]
-fn windows_key(drive: u8, frn: u64) -> u128 {
-    ((drive as u128) << 64) | (frn as u128)
-}
-
 impl Default for FileIndexer {
     fn default() -> Self {
         Self::new()
@@ -1025,7 +915,137 @@ fn is_path_hidden(path: &Path, metadata: &std::fs::Metadata) -> bool {
     }
 }
 
-fn load_cache_with_header(bytes: &[u8]) -> std::io::Result<(Vec<FileEntry>, Vec<UsnDriveState>)> {
+/// 修改时间（Unix 毫秒）；读取失败时为 0，表示未知。
+pub fn modified_ms(metadata: &std::fs::Metadata) -> u64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// 缓存文件的备份路径（上一代完整缓存）。
+pub fn cache_backup_path(cache_path: &Path) -> PathBuf {
+    cache_path.with_extension("bak")
+}
+
+/// 原文件无法替换时新缓存暂存的路径（见 `save_cache`）。
+fn cache_pending_path(cache_path: &Path) -> PathBuf {
+    cache_path.with_extension("new")
+}
+
+/// 最新的缓存文件：有待切换的新缓存时是它，否则是 `cache_path` 本身。
+pub fn current_cache_path(cache_path: &Path) -> PathBuf {
+    let pending = cache_pending_path(cache_path);
+    if pending.exists() { pending } else { cache_path.to_path_buf() }
+}
+
+/// 读取通用头部的版本字节；不是 RSIX 文件（v1 或过短）时返回 `None`。
+fn peek_cache_version(cache_path: &Path) -> std::io::Result<Option<u8>> {
+    let mut header = [0u8; CACHE_HEADER_LEN];
+    let mut file = std::fs::File::open(cache_path)?;
+    if file.read_exact(&mut header).is_err() || !header.starts_with(&CACHE_MAGIC) {
+        return Ok(None);
+    }
+    Ok(Some(header[4]))
+}
+
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) {
+    // 目录 fsync 失败不影响数据本身，只是重命名可能在掉电后丢失
+    if let Some(parent) = path.parent() {
+        let dir = if parent.as_os_str().is_empty() { Path::new(".") } else { parent };
+        if let Ok(dir) = std::fs::File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) {
+    // std 在 Windows 上无法直接打开目录句柄；重命名的元数据更新由 NTFS 日志保证
+}
+
+/// 按 v3（bincode varint）格式写出缓存，供基准测试与 v4 对比；字段顺序与 `DiskEntryV3` 一致。
+#[cfg(test)]
+pub(crate) fn save_cache_v3(
+    cache_path: &Path,
+    entries: &EntryStore,
+    usn_states: &[UsnDriveState],
+) -> std::io::Result<()> {
+    #[derive(Serialize)]
+    struct PayloadRef<'a> {
+        entries: Vec<DiskEntryRef<'a>>,
+        usn_states: &'a [UsnDriveState],
+    }
+    #[derive(Serialize)]
+    struct DiskEntryRef<'a> {
+        path: &'a str,
+        size: u64,
+        modified_ms: u64,
+        flags: u8,
+        drive: u8,
+        frn: u64,
+        parent_frn: u64,
+    }
+
+    let payload = PayloadRef {
+        entries: entries
+            .iter()
+            .map(|e| {
+                let meta = e.meta();
+                DiskEntryRef {
+                    path: e.path(),
+                    size: meta.size,
+                    modified_ms: meta.modified_ms,
+                    flags: meta.flags,
+                    drive: meta.drive,
+                    frn: meta.frn,
+                    parent_frn: meta.parent_frn,
+                }
+            })
+            .collect(),
+        usn_states,
+    };
+    let options = bincode::DefaultOptions::new().with_varint_encoding();
+    let payload_bytes = options
+        .serialize(&payload)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("序列化失败: {e}")))?;
+    let mut bytes = Vec::with_capacity(CACHE_HEADER_LEN + payload_bytes.len());
+    bytes.extend_from_slice(&CACHE_MAGIC);
+    bytes.push(CACHE_V3);
+    bytes.push(CACHE_ENCODING_VARINT);
+    bytes.extend_from_slice(&[0, 0]);
+    bytes.extend_from_slice(&payload_bytes);
+    std::fs::write(cache_path, bytes)
+}
+
+/// 加载 v1–v3 缓存，返回条目、USN 状态与版本号。
+fn load_legacy_cache(bytes: &[u8]) -> std::io::Result<(EntryStore, Vec<UsnDriveState>, u8)> {
+    if bytes.len() >= CACHE_HEADER_LEN && bytes.starts_with(&CACHE_MAGIC) {
+        let (entries, usn_states) = load_cache_with_header(bytes)?;
+        return Ok((entries, usn_states, bytes[4]));
+    }
+
+    // 兼容旧缓存（v1：纯 bincode + 包含 name_lower/path_lower）
+    let cache: IndexCacheV1 = bincode::deserialize(bytes).map_err(|e| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, format!("反序列化失败: {e}"))
+    })?;
+    if cache.version != 1 {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "缓存版本不匹配"));
+    }
+    let mut entries = EntryStore::with_capacity(cache.entries.len());
+    for e in &cache.entries {
+        entries.push(
+            &e.name,
+            &e.path,
+            EntryMeta::new(0, 0, 0, e.size, e.modified_ms, e.is_dir, e.is_hidden),
+        );
+    }
+    Ok((entries, Vec::new(), 1))
+}
+
+fn load_cache_with_header(bytes: &[u8]) -> std::io::Result<(EntryStore, Vec<UsnDriveState>)> {
     if bytes.len() < CACHE_HEADER_LEN {
         return Err(std::io::Error::new(
             std::io::ErrorKind::InvalidData,
@@ -1055,19 +1075,21 @@ fn load_cache_with_header(bytes: &[u8]) -> std::io::Result<(Vec<FileEntry>, Vec<
             let payload: IndexCachePayloadV2 = options.deserialize(payload_bytes).map_err(|e| {
                 std::io::Error::new(std::io::ErrorKind::InvalidData, format!("反序列化失败: {e}"))
             })?;
-            Ok((
-                payload.entries.into_iter().map(|e| e.to_entry()).collect(),
-                Vec::new(),
-            ))
+            let mut entries = EntryStore::with_capacity(payload.entries.len());
+            for e in &payload.entries {
+                e.push_into(&mut entries);
+            }
+            Ok((entries, Vec::new()))
         }
         CACHE_V3 => {
             let payload: IndexCachePayloadV3 = options.deserialize(payload_bytes).map_err(|e| {
                 std::io::Error::new(std::io::ErrorKind::InvalidData, format!("反序列化失败: {e}"))
             })?;
-            Ok((
-                payload.entries.into_iter().map(|e| e.to_entry()).collect(),
-                payload.usn_states,
-            ))
+            let mut entries = EntryStore::with_capacity(payload.entries.len());
+            for e in &payload.entries {
+                e.push_into(&mut entries);
+            }
+            Ok((entries, payload.usn_states))
         }
         _ => Err(std::io::Error::new(
             std::io::ErrorKind::InvalidData,
@@ -1088,23 +1110,78 @@ fn file_name_from_normalized_path(path: &str) -> String {
     }
 }
 
-fn lowercase_for_search(s: &str) -> String {
-    if s.is_ascii() {
-        s.to_ascii_lowercase()
-    } else {
-        s.to_lowercase()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index_dir(root: &Path) -> FileIndexer {
+        let (entries, usn_states, _) = FileIndexer::build_index_snapshot_with_stats(vec![root.to_path_buf()], None);
+        let mut indexer = FileIndexer::new();
+        indexer.replace_index(entries, usn_states);
+        indexer
     }
-}
 
-fn lowercase_for_index_field(s: &str) -> String {
-    if !s.is_ascii() {
-        return String::new();
+    fn find(indexer: &FileIndexer, name: &str) -> usize {
+        indexer
+            .get_entries()
+            .iter()
+            .find(|e| e.name() == name)
+            .map(|e| e.index())
+            .unwrap()
     }
-    let bytes = s.as_bytes();
-    if !bytes.iter().any(|b| (b'A'..=b'Z').contains(b)) {
-        return String::new();
+
+    #[test]
+    fn walkdir_entries_rebuild_paths_from_parent_ids() {
+        let root = std::env::temp_dir().join(format!("rust_search_walk_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("Docs/Sub")).unwrap();
+        std::fs::write(root.join("Docs/Sub/a.txt"), b"x").unwrap();
+
+        let mut indexer = index_dir(&root);
+        let root_str = root.to_string_lossy().replace('\\', "/");
+        let file = find(&indexer, "a.txt");
+        assert!(indexer.get_entries().path(file).is_empty());
+        assert_eq!(indexer.display_path_for(file), format!("{root_str}/Docs/Sub/a.txt"));
+        assert!(indexer.get_entries().meta(file).modified_ms > 0);
+
+        // 重命名目录只改写目录条目本身，子项路径随之变化
+        let docs = find(&indexer, "Docs");
+        indexer.entries_mut().rename(docs, "Papers");
+        assert_eq!(indexer.display_path_for(file), format!("{root_str}/Papers/Sub/a.txt"));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn moved_directory_subtree_is_reindexed() {
+        let root = std::env::temp_dir().join(format!("rust_search_move_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("Docs/Sub")).unwrap();
+        std::fs::write(root.join("Docs/Sub/a.txt"), b"x").unwrap();
+
+        let mut indexer = index_dir(&root);
+        let old_file = find(&indexer, "a.txt");
+        let docs = find(&indexer, "Docs");
+        std::fs::rename(root.join("Docs"), root.join("Moved")).unwrap();
+
+        // 与界面中批量移动写回索引的顺序一致：先在索引外扫描新位置，再追加并标记旧目录删除
+        let mut walked = EntryStore::new();
+        append_subtree(&mut walked, &root.join("Moved"));
+        let store = indexer.entries_mut();
+        store.link_parents();
+        store.append_walked(&walked);
+        store.mark_removed(&[docs]);
+
+        let store = indexer.get_entries();
+        assert!(store.meta(old_file).is_removed());
+        let new_file = store
+            .iter()
+            .find(|e| e.name() == "a.txt" && !e.meta().is_removed())
+            .map(|e| e.index())
+            .unwrap();
+        let root_str = root.to_string_lossy().replace('\\', "/");
+        assert_eq!(indexer.display_path_for(new_file), format!("{root_str}/Moved/Sub/a.txt"));
+
+        let _ = std::fs::remove_dir_all(&root);
     }
-    let mut out = s.to_string();
-    out.make_ascii_lowercase();
-    out
 }
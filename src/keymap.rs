@@ -0,0 +1,204 @@
+//! 结果列表的键盘操作：动作与快捷键的映射可在设置中修改并持久化。
+//!
+//! 搜索框有焦点时不处理这些快捷键（上/下方向键留给搜索历史）。导航时按住 Shift 会连续多选。
+
+use eframe::egui::{self, Key, KeyboardShortcut, Modifiers};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResultAction {
+    Next,
+    Previous,
+    PageDown,
+    PageUp,
+    First,
+    Last,
+    /// 选中全部结果。
+    SelectAll,
+    Open,
+    RevealInFolder,
+    CopyPath,
+    CopyName,
+    MoveToTrash,
+    Rename,
+    OpenWith,
+    CopyAsFile,
+    Properties,
+    SearchInFolder,
+    /// 资源管理器的右键菜单（仅 Windows）。
+    ShellMenu,
+}
+
+impl ResultAction {
+    pub const ALL: [ResultAction; 18] = [
+        ResultAction::Next,
+        ResultAction::Previous,
+        ResultAction::PageDown,
+        ResultAction::PageUp,
+        ResultAction::First,
+        ResultAction::Last,
+        ResultAction::SelectAll,
+        ResultAction::Open,
+        ResultAction::RevealInFolder,
+        ResultAction::CopyPath,
+        ResultAction::CopyName,
+        ResultAction::MoveToTrash,
+        ResultAction::Rename,
+        ResultAction::OpenWith,
+        ResultAction::CopyAsFile,
+        ResultAction::Properties,
+        ResultAction::SearchInFolder,
+        ResultAction::ShellMenu,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ResultAction::Next => "下一项",
+            ResultAction::Previous => "上一项",
+            ResultAction::PageDown => "向下翻页",
+            ResultAction::PageUp => "向上翻页",
+            ResultAction::First => "第一项",
+            ResultAction::Last => "最后一项",
+            ResultAction::SelectAll => "全选",
+            ResultAction::Open => "打开",
+            ResultAction::RevealInFolder => "在文件夹中显示",
+            ResultAction::CopyPath => "复制完整路径",
+            ResultAction::CopyName => "复制文件名",
+            ResultAction::MoveToTrash => "移到回收站",
+            ResultAction::Rename => "重命名",
+            ResultAction::OpenWith => "打开方式…",
+            ResultAction::CopyAsFile => "复制为文件",
+            ResultAction::Properties => "属性",
+            ResultAction::SearchInFolder => "在此文件夹中搜索",
+            ResultAction::ShellMenu => "更多选项（系统菜单）",
+        }
+    }
+
+    /// 导航类动作返回新的选中项（`page` 为一页的行数）；其他动作返回 `None`。
+    pub fn move_selection(self, selected: Option<usize>, len: usize, page: usize) -> Option<usize> {
+        let last = len.checked_sub(1)?;
+        let page = page.max(1);
+        let target = match (self, selected) {
+            (ResultAction::First, _) | (ResultAction::Next | ResultAction::PageDown, None) => 0,
+            (ResultAction::Last, _) | (ResultAction::Previous | ResultAction::PageUp, None) => last,
+            (ResultAction::Next, Some(i)) => i + 1,
+            (ResultAction::Previous, Some(i)) => i.saturating_sub(1),
+            (ResultAction::PageDown, Some(i)) => i + page,
+            (ResultAction::PageUp, Some(i)) => i.saturating_sub(page),
+            _ => return None,
+        };
+        Some(target.min(last))
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Keymap {
+    pub bindings: Vec<(ResultAction, KeyboardShortcut)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let key = |key| KeyboardShortcut::new(Modifiers::NONE, key);
+        let ctrl = |key| KeyboardShortcut::new(Modifiers::COMMAND, key);
+        Self {
+            bindings: vec![
+                (ResultAction::Next, key(Key::ArrowDown)),
+                (ResultAction::Previous, key(Key::ArrowUp)),
+                (ResultAction::PageDown, key(Key::PageDown)),
+                (ResultAction::PageUp, key(Key::PageUp)),
+                (ResultAction::First, key(Key::Home)),
+                (ResultAction::Last, key(Key::End)),
+                (ResultAction::SelectAll, ctrl(Key::A)),
+                (ResultAction::Open, key(Key::Enter)),
+                (ResultAction::RevealInFolder, ctrl(Key::Enter)),
+                (ResultAction::CopyPath, ctrl(Key::C)),
+                (
+                    ResultAction::CopyName,
+                    KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::C),
+                ),
+                (ResultAction::MoveToTrash, key(Key::Delete)),
+                (ResultAction::Rename, key(Key::F2)),
+                (ResultAction::Properties, KeyboardShortcut::new(Modifiers::ALT, Key::Enter)),
+            ],
+        }
+    }
+}
+
+impl Keymap {
+    pub fn shortcut(&self, action: ResultAction) -> Option<KeyboardShortcut> {
+        self.bindings.iter().find(|(a, _)| *a == action).map(|(_, s)| *s)
+    }
+
+    /// 绑定新快捷键；已被其他动作占用时从那个动作上移除。
+    pub fn set(&mut self, action: ResultAction, shortcut: KeyboardShortcut) {
+        self.bindings.retain(|(a, s)| *a != action && *s != shortcut);
+        self.bindings.push((action, shortcut));
+    }
+
+    pub fn unbind(&mut self, action: ResultAction) {
+        self.bindings.retain(|(a, _)| *a != action);
+    }
+
+    /// 取出本帧按下的动作。egui 匹配修饰键时忽略多按的 Shift/Alt，
+    /// 因此先检查修饰键多的组合，避免 Ctrl+Shift+C 被当成 Ctrl+C。
+    pub fn consume(&self, input: &mut egui::InputState) -> Option<ResultAction> {
+        let mut bindings: Vec<&(ResultAction, KeyboardShortcut)> = self.bindings.iter().collect();
+        bindings.sort_by_key(|(_, s)| std::cmp::Reverse(modifier_count(s.modifiers)));
+        bindings
+            .into_iter()
+            .find(|(_, s)| input.consume_shortcut(s))
+            .map(|(a, _)| *a)
+    }
+}
+
+fn modifier_count(m: Modifiers) -> usize {
+    [m.alt, m.ctrl || m.command || m.mac_cmd, m.shift]
+        .into_iter()
+        .filter(|&b| b)
+        .count()
+}
+
+/// 设置界面录制快捷键：返回本帧按下的第一个按键组合（Esc 除外，留作取消）。
+pub fn captured_shortcut(input: &egui::InputState) -> Option<KeyboardShortcut> {
+    input.events.iter().find_map(|event| match event {
+        egui::Event::Key {
+            key,
+            pressed: true,
+            modifiers,
+            ..
+        } if *key != Key::Escape => Some(KeyboardShortcut::new(*modifiers, *key)),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn navigation_is_clamped_to_the_result_list() {
+        use ResultAction::*;
+        assert_eq!(Next.move_selection(None, 5, 3), Some(0));
+        assert_eq!(Next.move_selection(Some(4), 5, 3), Some(4));
+        assert_eq!(Previous.move_selection(Some(0), 5, 3), Some(0));
+        assert_eq!(PageDown.move_selection(Some(1), 5, 3), Some(4));
+        assert_eq!(PageUp.move_selection(Some(4), 5, 3), Some(1));
+        assert_eq!(Last.move_selection(None, 5, 3), Some(4));
+        assert_eq!(Next.move_selection(None, 0, 3), None);
+        assert_eq!(Open.move_selection(Some(1), 5, 3), None);
+    }
+
+    #[test]
+    fn rebinding_moves_a_shortcut_between_actions() {
+        let mut keymap = Keymap::default();
+        let f2 = keymap.shortcut(ResultAction::Rename).unwrap();
+        keymap.set(ResultAction::Open, f2);
+        assert_eq!(keymap.shortcut(ResultAction::Open), Some(f2));
+        assert_eq!(keymap.shortcut(ResultAction::Rename), None);
+
+        let bytes = bincode::serialize(&keymap).unwrap();
+        let back: Keymap = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back.bindings, keymap.bindings);
+    }
+}
@@ -0,0 +1,339 @@
+//! 快速搜索：用全局快捷键从任何地方呼出的精简搜索框。
+//!
+//! 呼出时主窗口临时切换为置顶、无边框的小窗口，关闭（Esc 或失去焦点）后恢复原来的位置、大小与可见性；
+//! 与主窗口共用 `Searcher` 与索引，只用键盘操作。
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use eframe::egui::{self, Key, KeyboardShortcut, Modifiers};
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+
+use crate::indexer::FileIndexer;
+use crate::searcher::{SearchResult, Searcher};
+
+/// 快速搜索最多显示的结果数。
+pub const LAUNCHER_RESULTS: usize = 8;
+const LAUNCHER_SIZE: egui::Vec2 = egui::vec2(640.0, 380.0);
+const ROW_HEIGHT: f32 = 28.0;
+
+/// 把 egui 的快捷键转换为全局快捷键。除 F1..F24 外至少需要一个修饰键，否则会抢走普通输入。
+pub fn global_hotkey(shortcut: &KeyboardShortcut) -> Result<HotKey, String> {
+    let modifiers = shortcut.modifiers;
+    let name = shortcut.logical_key.name();
+    let mut parts = Vec::new();
+    if modifiers.ctrl || (modifiers.command && !modifiers.mac_cmd) {
+        parts.push("control");
+    }
+    if modifiers.mac_cmd {
+        parts.push("super");
+    }
+    if modifiers.alt {
+        parts.push("alt");
+    }
+    if modifiers.shift {
+        parts.push("shift");
+    }
+    let function_key = name.len() > 1 && name.starts_with('F') && name[1..].bytes().all(|b| b.is_ascii_digit());
+    if parts.is_empty() && !function_key {
+        return Err("全局快捷键至少需要一个修饰键（Ctrl/Alt/Shift）".to_string());
+    }
+    parts.push(name);
+    parts
+        .join("+")
+        .parse()
+        .map_err(|_| format!("不支持用作全局快捷键的按键：{name}"))
+}
+
+/// 已注册的全局快捷键。按下时记下一次呼出请求并调用 `wake`，由界面线程在下一帧处理。
+pub struct GlobalHotkey {
+    manager: GlobalHotKeyManager,
+    current: Option<HotKey>,
+    pressed: Arc<AtomicBool>,
+}
+
+impl GlobalHotkey {
+    /// 事件回调全局只能设置一次，因此每个进程只创建一个。
+    pub fn new(wake: impl Fn() + Send + Sync + 'static) -> Result<Self, String> {
+        let manager = GlobalHotKeyManager::new().map_err(|e| format!("无法使用全局快捷键：{e}"))?;
+        let pressed = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&pressed);
+        GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+            if event.state == HotKeyState::Pressed {
+                flag.store(true, Ordering::SeqCst);
+                wake();
+            }
+        }));
+        Ok(Self {
+            manager,
+            current: None,
+            pressed,
+        })
+    }
+
+    /// 改为注册 `shortcut`；`None` 为停用。
+    pub fn set(&mut self, shortcut: Option<&KeyboardShortcut>) -> Result<(), String> {
+        let hotkey = shortcut.map(global_hotkey).transpose()?;
+        if hotkey == self.current {
+            return Ok(());
+        }
+        if let Some(old) = self.current.take() {
+            let _ = self.manager.unregister(old);
+        }
+        if let Some(hotkey) = hotkey {
+            self.manager
+                .register(hotkey)
+                .map_err(|e| format!("无法注册全局快捷键（可能已被其他程序占用）：{e}"))?;
+            self.current = Some(hotkey);
+        }
+        Ok(())
+    }
+
+    pub fn take_pressed(&self) -> bool {
+        self.pressed.swap(false, Ordering::SeqCst)
+    }
+}
+
+/// 呼出前主窗口的状态，关闭快速搜索时恢复。
+struct WindowState {
+    position: Option<egui::Pos2>,
+    size: Option<egui::Vec2>,
+    /// 主窗口此前隐藏在托盘中。
+    hidden: bool,
+    minimized: bool,
+}
+
+/// 需要主程序处理的操作。
+pub enum LauncherEvent {
+    /// 查询改变，需要重新搜索（见 `QuickLauncher::search`）。
+    Search,
+    Open(String),
+    Reveal(String),
+    /// 在主窗口中查看该查询的全部结果。
+    ShowAll(String),
+}
+
+#[derive(Default)]
+pub struct QuickLauncher {
+    restore: Option<WindowState>,
+    query: String,
+    selected: usize,
+    results: Arc<Mutex<Vec<SearchResult>>>,
+    seq: Arc<AtomicU64>,
+    /// 窗口已获得过焦点；之后失去焦点即关闭。
+    focused: bool,
+}
+
+impl QuickLauncher {
+    pub fn is_open(&self) -> bool {
+        self.restore.is_some()
+    }
+
+    /// 把主窗口切换为快速搜索窗口；`hidden` 表示主窗口此前隐藏在托盘中。
+    pub fn open(&mut self, ctx: &egui::Context, hidden: bool) {
+        if self.is_open() {
+            return;
+        }
+        let (position, size, minimized, monitor) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (
+                viewport.outer_rect.map(|r| r.min),
+                viewport.inner_rect.map(|r| r.size()),
+                viewport.minimized.unwrap_or(false),
+                viewport.monitor_size,
+            )
+        });
+        self.restore = Some(WindowState {
+            position,
+            size,
+            hidden,
+            minimized,
+        });
+        self.query.clear();
+        self.selected = 0;
+        self.results.lock().unwrap().clear();
+        self.focused = false;
+
+        use egui::ViewportCommand as Command;
+        ctx.send_viewport_cmd(Command::Visible(true));
+        ctx.send_viewport_cmd(Command::Minimized(false));
+        ctx.send_viewport_cmd(Command::Decorations(false));
+        ctx.send_viewport_cmd(Command::WindowLevel(egui::WindowLevel::AlwaysOnTop));
+        ctx.send_viewport_cmd(Command::InnerSize(LAUNCHER_SIZE));
+        if let Some(monitor) = monitor {
+            let x = (monitor.x - LAUNCHER_SIZE.x) / 2.0;
+            ctx.send_viewport_cmd(Command::OuterPosition(egui::pos2(x.max(0.0), monitor.y / 4.0)));
+        }
+        ctx.send_viewport_cmd(Command::Focus);
+    }
+
+    /// 恢复主窗口；`show_main` 为真时即使主窗口此前隐藏或最小化也显示出来。
+    pub fn close(&mut self, ctx: &egui::Context, show_main: bool) {
+        let Some(state) = self.restore.take() else {
+            return;
+        };
+        self.seq.fetch_add(1, Ordering::SeqCst);
+
+        use egui::ViewportCommand as Command;
+        ctx.send_viewport_cmd(Command::Decorations(true));
+        ctx.send_viewport_cmd(Command::WindowLevel(egui::WindowLevel::Normal));
+        if let Some(size) = state.size {
+            ctx.send_viewport_cmd(Command::InnerSize(size));
+        }
+        if let Some(position) = state.position {
+            ctx.send_viewport_cmd(Command::OuterPosition(position));
+        }
+        if show_main {
+            ctx.send_viewport_cmd(Command::Focus);
+        } else if state.hidden {
+            ctx.send_viewport_cmd(Command::Visible(false));
+        } else if state.minimized {
+            ctx.send_viewport_cmd(Command::Minimized(true));
+        }
+    }
+
+    /// 在后台搜索当前查询，只保留最后一次请求的结果。
+    pub fn search(&self, ctx: &egui::Context, indexer: &Arc<Mutex<FileIndexer>>, mut searcher: Searcher) {
+        searcher.options.max_results = LAUNCHER_RESULTS;
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let current = Arc::clone(&self.seq);
+        let results = Arc::clone(&self.results);
+        let indexer = Arc::clone(indexer);
+        let query = self.query.clone();
+        let ctx = ctx.clone();
+        thread::spawn(move || {
+            let found = searcher.search(&indexer.lock().unwrap(), &query);
+            let mut results = results.lock().unwrap();
+            if current.load(Ordering::SeqCst) == seq {
+                *results = found;
+                ctx.request_repaint();
+            }
+        });
+    }
+
+    /// 绘制快速搜索窗口：↑/↓ 选择，Enter 打开，Ctrl+Enter 在文件夹中显示，Tab 转到主窗口，Esc 关闭。
+    pub fn ui(&mut self, ctx: &egui::Context) -> Option<LauncherEvent> {
+        let (up, down, enter, tab, escape) = ctx.input_mut(|i| {
+            let modifiers = i.modifiers;
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(modifiers, Key::Enter).then_some(modifiers.command),
+                i.consume_key(Modifiers::NONE, Key::Tab),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+        match ctx.input(|i| i.viewport().focused) {
+            Some(true) => self.focused = true,
+            Some(false) if self.focused => {
+                self.close(ctx, false);
+                return None;
+            }
+            _ => {}
+        }
+        if escape {
+            self.close(ctx, false);
+            return None;
+        }
+
+        let results = Arc::clone(&self.results);
+        let results = results.lock().unwrap();
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if down && self.selected + 1 < results.len() {
+            self.selected += 1;
+        }
+        self.selected = self.selected.min(results.len().saturating_sub(1));
+
+        let mut event = None;
+        egui::CentralPanel::default()
+            .frame(egui::Frame::window(&ctx.style()))
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text("快速搜索…")
+                        .font(egui::TextStyle::Heading)
+                        .desired_width(f32::INFINITY),
+                );
+                if !response.has_focus() {
+                    response.request_focus();
+                }
+                if response.changed() {
+                    self.selected = 0;
+                    event = Some(LauncherEvent::Search);
+                }
+                ui.separator();
+
+                for (idx, result) in results.iter().enumerate() {
+                    let fill = if idx == self.selected {
+                        ui.visuals().selection.bg_fill
+                    } else {
+                        egui::Color32::TRANSPARENT
+                    };
+                    egui::Frame::none()
+                        .fill(fill)
+                        .rounding(4.0)
+                        .inner_margin(egui::Margin::symmetric(6.0, 0.0))
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            ui.horizontal(|ui| {
+                                ui.set_min_height(ROW_HEIGHT);
+                                ui.label(egui::RichText::new(result.entry().name()).strong());
+                                ui.add(
+                                    egui::Label::new(egui::RichText::new(&result.display_path).small().weak())
+                                        .truncate(),
+                                );
+                            });
+                        });
+                }
+                if results.is_empty() && !self.query.trim().is_empty() {
+                    ui.weak("没有结果");
+                }
+
+                ui.with_layout(egui::Layout::bottom_up(egui::Align::LEFT), |ui| {
+                    ui.label(
+                        egui::RichText::new("↑↓ 选择　Enter 打开　Ctrl+Enter 在文件夹中显示　Tab 在主窗口中查看全部　Esc 关闭")
+                            .small()
+                            .weak(),
+                    );
+                });
+            });
+
+        if let Some(reveal) = enter
+            && let Some(result) = results.get(self.selected)
+        {
+            let path = result.display_path.clone();
+            event = Some(if reveal { LauncherEvent::Reveal(path) } else { LauncherEvent::Open(path) });
+        }
+        if tab && !self.query.trim().is_empty() {
+            event = Some(LauncherEvent::ShowAll(self.query.clone()));
+        }
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcuts_convert_to_global_hotkeys() {
+        let ctrl_alt = Modifiers {
+            alt: true,
+            ctrl: true,
+            command: true,
+            ..Modifiers::NONE
+        };
+        let hotkey = global_hotkey(&KeyboardShortcut::new(ctrl_alt, Key::Space)).unwrap();
+        assert_eq!(hotkey, "control+alt+Space".parse::<HotKey>().unwrap());
+        assert_eq!(
+            global_hotkey(&KeyboardShortcut::new(Modifiers::SHIFT, Key::Num1)).unwrap(),
+            "shift+Digit1".parse::<HotKey>().unwrap()
+        );
+        assert!(global_hotkey(&KeyboardShortcut::new(Modifiers::NONE, Key::F5)).is_ok());
+        assert!(global_hotkey(&KeyboardShortcut::new(Modifiers::NONE, Key::A)).is_err());
+    }
+}
@@ -1,8 +1,30 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod app;
+mod batch;
+mod categories;
+mod entry_store;
+mod facets;
+mod file_ops;
+mod fold;
+mod history;
+mod icons;
+mod index_file;
 mod indexer;
+mod keymap;
+mod launcher;
+mod pinyin;
+mod preview;
+mod ranking;
+mod rename;
+mod saved_searches;
 mod searcher;
+mod selection;
+mod usage;
+#[cfg(windows)]
+mod windows_shell;
+#[cfg(windows)]
+mod windows_tray;
 #[cfg(windows)]
 mod windows_usn;
 
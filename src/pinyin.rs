@@ -0,0 +1,1356 @@
+//! 汉字拼音表与拼音/首字母匹配。
+//!
+//! 读音表由 Unicode::Collate 的 CJK 拼音排序数据整理而来，覆盖 CJK 基本区（U+4E00..=U+9FFF）
+//! 中的 20892 个汉字，每字只记一个主读音；常见多音字的其它读音见 `EXTRA_READINGS`。
+
+/// 全部拼音音节（不含声调，ü 写作 v），按字母序排列。
+#[rustfmt::skip]
+const SYLLABLES: [&str; 410] = [
+    "a", "ai", "an", "ang", "ao", "ba", "bai", "ban", "bang", "bao", "bei", "ben", "beng",
+    "bi", "bian", "biao", "bie", "bin", "bing", "bo", "bu", "ca", "cai", "can", "cang",
+    "cao", "ce", "cen", "ceng", "cha", "chai", "chan", "chang", "chao", "che", "chen",
+    "cheng", "chi", "chong", "chou", "chu", "chua", "chuai", "chuan", "chuang", "chui",
+    "chun", "chuo", "ci", "cong", "cou", "cu", "cuan", "cui", "cun", "cuo", "da", "dai",
+    "dan", "dang", "dao", "de", "dei", "den", "deng", "di", "dia", "dian", "diao", "die",
+    "ding", "diu", "dong", "dou", "du", "duan", "dui", "dun", "duo", "e", "ei", "en", "eng",
+    "er", "fa", "fan", "fang", "fei", "fen", "feng", "fiao", "fo", "fou", "fu", "ga", "gai",
+    "gan", "gang", "gao", "ge", "gei", "gen", "geng", "gong", "gou", "gu", "gua", "guai",
+    "guan", "guang", "gui", "gun", "guo", "ha", "hai", "han", "hang", "hao", "he", "hei",
+    "hen", "heng", "hong", "hou", "hu", "hua", "huai", "huan", "huang", "hui", "hun", "huo",
+    "ji", "jia", "jian", "jiang", "jiao", "jie", "jin", "jing", "jiong", "jiu", "ju",
+    "juan", "jue", "jun", "ka", "kai", "kan", "kang", "kao", "ke", "ken", "keng", "kong",
+    "kou", "ku", "kua", "kuai", "kuan", "kuang", "kui", "kun", "kuo", "la", "lai", "lan",
+    "lang", "lao", "le", "lei", "leng", "li", "lia", "lian", "liang", "liao", "lie", "lin",
+    "ling", "liu", "lo", "long", "lou", "lu", "luan", "lun", "luo", "lv", "lve", "m", "ma",
+    "mai", "man", "mang", "mao", "me", "mei", "men", "meng", "mi", "mian", "miao", "mie",
+    "min", "ming", "miu", "mo", "mou", "mu", "n", "na", "nai", "nan", "nang", "nao", "ne",
+    "nei", "nen", "neng", "ni", "nian", "niang", "niao", "nie", "nin", "ning", "niu",
+    "nong", "nou", "nu", "nuan", "nun", "nuo", "nv", "nve", "o", "ou", "pa", "pai", "pan",
+    "pang", "pao", "pei", "pen", "peng", "pi", "pian", "piao", "pie", "pin", "ping", "po",
+    "pou", "pu", "qi", "qia", "qian", "qiang", "qiao", "qie", "qin", "qing", "qiong", "qiu",
+    "qu", "quan", "que", "qun", "ran", "rang", "rao", "re", "ren", "reng", "ri", "rong",
+    "rou", "ru", "ruan", "rui", "run", "ruo", "sa", "sai", "san", "sang", "sao", "se",
+    "sen", "seng", "sha", "shai", "shan", "shang", "shao", "she", "shen", "sheng", "shi",
+    "shou", "shu", "shua", "shuai", "shuan", "shuang", "shui", "shun", "shuo", "si", "song",
+    "sou", "su", "suan", "sui", "sun", "suo", "ta", "tai", "tan", "tang", "tao", "te",
+    "teng", "ti", "tian", "tiao", "tie", "ting", "tong", "tou", "tu", "tuan", "tui", "tun",
+    "tuo", "wa", "wai", "wan", "wang", "wei", "wen", "weng", "wo", "wu", "xi", "xia",
+    "xian", "xiang", "xiao", "xie", "xin", "xing", "xiong", "xiu", "xu", "xuan", "xue",
+    "xun", "ya", "yan", "yang", "yao", "ye", "yi", "yin", "ying", "yo", "yong", "you", "yu",
+    "yuan", "yue", "yun", "za", "zai", "zan", "zang", "zao", "ze", "zei", "zen", "zeng",
+    "zha", "zhai", "zhan", "zhang", "zhao", "zhe", "zhen", "zheng", "zhi", "zhong", "zhou",
+    "zhu", "zhua", "zhuai", "zhuan", "zhuang", "zhui", "zhun", "zhuo", "zi", "zong", "zou",
+    "zu", "zuan", "zui", "zun", "zuo",
+];
+
+const HANZI_FIRST: u32 = 0x4E00;
+const HANZI_LAST: u32 = 0x9FFF;
+
+/// 多音字的其它读音（主读音之外），按字符排序以便二分查找。
+const EXTRA_READINGS: &[(char, &[&str])] = &[
+    ('乐', &["yue"]),
+    ('乘', &["sheng"]),
+    ('了', &["liao"]),
+    ('仔', &["zai"]),
+    ('会', &["kuai"]),
+    ('传', &["zhuan"]),
+    ('伯', &["bai"]),
+    ('似', &["si"]),
+    ('便', &["pian"]),
+    ('六', &["lu"]),
+    ('区', &["ou"]),
+    ('单', &["shan", "chan"]),
+    ('卡', &["qia"]),
+    ('厦', &["xia"]),
+    ('参', &["shen", "cen"]),
+    ('吓', &["he"]),
+    ('呵', &["he"]),
+    ('和', &["huo", "hu"]),
+    ('地', &["di"]),
+    ('塞', &["se"]),
+    ('壳', &["qiao"]),
+    ('大', &["dai"]),
+    ('宿', &["xiu"]),
+    ('将', &["qiang"]),
+    ('尾', &["yi"]),
+    ('属', &["zhu"]),
+    ('差', &["chai", "ci"]),
+    ('广', &["an"]),
+    ('弹', &["tan"]),
+    ('强', &["jiang"]),
+    ('得', &["dei"]),
+    ('扎', &["za"]),
+    ('提', &["di"]),
+    ('数', &["shuo"]),
+    ('曾', &["zeng"]),
+    ('朝', &["zhao"]),
+    ('期', &["ji"]),
+    ('朴', &["piao", "po"]),
+    ('柏', &["bo"]),
+    ('校', &["jiao"]),
+    ('模', &["mu"]),
+    ('沈', &["shen"]),
+    ('没', &["mo"]),
+    ('率', &["shuai"]),
+    ('番', &["pan"]),
+    ('的', &["di"]),
+    ('盛', &["cheng"]),
+    ('省', &["xing"]),
+    ('着', &["zhao", "zhuo"]),
+    ('石', &["dan"]),
+    ('种', &["chong"]),
+    ('秘', &["bi"]),
+    ('称', &["chen"]),
+    ('系', &["ji"]),
+    ('给', &["ji"]),
+    ('色', &["shai"]),
+    ('落', &["la", "lao"]),
+    ('蔚', &["yu"]),
+    ('薄', &["bo"]),
+    ('藏', &["zang"]),
+    ('血', &["xie"]),
+    ('行', &["hang"]),
+    ('觉', &["jiao"]),
+    ('角', &["jue"]),
+    ('解', &["xie"]),
+    ('识', &["zhi"]),
+    ('说', &["shui"]),
+    ('调', &["tiao"]),
+    ('车', &["ju"]),
+    ('转', &["zhuai"]),
+    ('还', &["huan"]),
+    ('都', &["du"]),
+    ('重', &["chong"]),
+    ('长', &["chang"]),
+    ('降', &["xiang"]),
+    ('露', &["lou"]),
+];
+
+/// 拼音单元的最高位：该字在 `EXTRA_READINGS` 中还有其它读音。
+const HAS_EXTRA: u16 = 0x8000;
+const SYLLABLE_MASK: u16 = 0x7FFF;
+
+/// 单个字符的拼音单元：0 表示不是收录的汉字（按原字符比较），否则为主读音的 `SYLLABLES` 下标 + 1。
+fn unit_for(c: char) -> u16 {
+    let cp = c as u32;
+    if !(HANZI_FIRST..=HANZI_LAST).contains(&cp) {
+        return 0;
+    }
+    let primary = PRIMARY[(cp - HANZI_FIRST) as usize];
+    if primary == 0 || extra_readings(c).is_empty() {
+        primary
+    } else {
+        primary | HAS_EXTRA
+    }
+}
+
+fn extra_readings(c: char) -> &'static [&'static str] {
+    EXTRA_READINGS
+        .binary_search_by_key(&c, |&(k, _)| k)
+        .map_or(&[], |i| EXTRA_READINGS[i].1)
+}
+
+/// 非零单元对应的全部读音，主读音在前。
+fn unit_readings(unit: u16, c: char) -> impl Iterator<Item = &'static str> {
+    let primary = SYLLABLES[usize::from(unit & SYLLABLE_MASK) - 1];
+    let extra = if unit & HAS_EXTRA != 0 {
+        extra_readings(c)
+    } else {
+        &[]
+    };
+    std::iter::once(primary).chain(extra.iter().copied())
+}
+
+/// 各条目文件名的拼音单元（每个字符一个），由 `EntryStore` 在首次拼音搜索时构建并缓存；
+/// 不含汉字的文件名不占空间。
+#[derive(Clone, Default)]
+pub struct PinyinIndex {
+    offsets: Vec<u32>,
+    units: Vec<u16>,
+}
+
+impl PinyinIndex {
+    pub fn build<'a>(names: impl Iterator<Item = &'a str>) -> Self {
+        let mut offsets = vec![0u32];
+        let mut units = Vec::new();
+        for name in names {
+            let start = units.len();
+            let mut has_hanzi = false;
+            for c in name.chars() {
+                let unit = unit_for(c);
+                has_hanzi |= unit != 0;
+                units.push(unit);
+            }
+            if !has_hanzi {
+                units.truncate(start);
+            }
+            offsets.push(units.len() as u32);
+        }
+        units.shrink_to_fit();
+        Self { offsets, units }
+    }
+
+    /// 第 `index` 个条目的拼音单元；文件名不含汉字时为空。
+    pub fn units(&self, index: usize) -> &[u16] {
+        let start = self.offsets[index] as usize;
+        let end = self.offsets[index + 1] as usize;
+        &self.units[start..end]
+    }
+
+    pub fn heap_bytes(&self) -> usize {
+        self.offsets.capacity() * std::mem::size_of::<u32>()
+            + self.units.capacity() * std::mem::size_of::<u16>()
+    }
+}
+
+/// 拼音匹配覆盖的字符范围（按字符下标，闭区间）。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PinyinMatch {
+    pub first: usize,
+    pub last: usize,
+}
+
+/// 在文件名中找一段与 `query` 连续对应的字符：每个汉字可用任一读音的非空前缀匹配
+/// （完整拼音、首字母或两者混用，如 "baogaowj"），其它字符按原样匹配（ASCII 不区分大小写）。
+/// `units` 为 `PinyinIndex::units` 返回的该文件名单元。返回结束最早的一段，其中取起点最靠后的。
+pub fn match_units(name: &str, units: &[u16], query: &str) -> Option<PinyinMatch> {
+    let q = query.as_bytes();
+    if q.is_empty() || units.is_empty() || !query.is_ascii() {
+        return None;
+    }
+
+    // cur[p]：已匹配 query 前 p 个字节、且下一个字符可以接着匹配时，最靠后的起点。
+    let mut cur: Vec<Option<usize>> = vec![None; q.len() + 1];
+    let mut next = cur.clone();
+    for (k, (c, &unit)) in name.chars().zip(units).enumerate() {
+        cur[0] = Some(k);
+        next.fill(None);
+        for p in 0..q.len() {
+            let Some(start) = cur[p] else {
+                continue;
+            };
+            let rest = &q[p..];
+            if unit == 0 {
+                if c.is_ascii() && (c as u8).eq_ignore_ascii_case(&rest[0]) {
+                    next[p + 1] = next[p + 1].max(Some(start));
+                }
+                continue;
+            }
+            for reading in unit_readings(unit, c) {
+                let common = reading
+                    .bytes()
+                    .zip(rest)
+                    .take_while(|(a, b)| *a == b.to_ascii_lowercase())
+                    .count();
+                for len in 1..=common {
+                    next[p + len] = next[p + len].max(Some(start));
+                }
+            }
+        }
+        if let Some(first) = next[q.len()] {
+            return Some(PinyinMatch { first, last: k });
+        }
+        std::mem::swap(&mut cur, &mut next);
+    }
+    None
+}
+
+/// `HANZI_FIRST..=HANZI_LAST` 中每个字符的主读音：`SYLLABLES` 下标 + 1，0 表示没有收录。
+#[rustfmt::skip]
+static PRIMARY: [u16; 20992] = [
+    365,71,151,256,295,347,116,339,387,286,295,347,133,21,371,202,96,40,40,398,
+    261,247,300,300,265,19,364,50,73,310,37,72,265,176,72,370,176,361,19,287,
+    112,142,100,360,259,393,133,138,90,109,44,32,179,402,395,114,339,59,341,395,
+    140,173,143,250,94,365,365,213,345,142,142,336,197,365,365,392,345,384,125,85,
+    170,366,252,242,260,125,108,37,37,365,366,360,204,142,256,364,346,349,96,142,
+    347,125,302,74,300,133,215,134,143,300,196,125,193,186,403,279,358,361,94,292,
+    212,97,317,371,54,389,97,392,111,97,186,179,365,145,170,192,371,391,300,300,
+    84,41,371,162,371,374,125,256,345,140,310,315,102,102,360,351,360,256,360,133,
+    331,340,150,318,137,115,365,32,122,210,364,349,140,329,176,349,140,364,262,20,
+    370,351,59,175,79,199,274,274,133,133,340,365,298,274,170,71,380,139,255,40,
+    6,387,139,138,19,275,50,92,286,187,19,25,403,300,318,387,94,348,348,336,
+    123,330,274,258,97,100,20,58,180,365,34,33,284,295,365,210,199,274,134,34,
+    362,258,393,247,344,345,135,134,363,90,25,274,340,89,66,87,393,256,244,371,
+    69,78,345,365,352,150,365,133,2,345,133,94,85,355,139,247,59,94,321,393,
+    370,132,130,371,54,44,286,341,44,35,360,348,295,33,187,25,359,352,341,395,
+    380,348,231,20,106,221,221,351,8,356,180,394,298,266,49,13,300,134,247,365,
+    310,365,391,68,116,193,59,395,21,266,14,388,49,341,66,395,410,370,362,325,
+    386,119,14,336,297,371,365,94,410,105,227,330,221,348,266,369,337,258,300,147,
+    10,244,130,119,169,349,100,362,7,85,206,134,84,19,133,121,132,111,267,327,
+    137,49,365,300,353,298,336,149,392,96,166,365,38,158,110,173,366,300,201,395,
+    356,370,3,185,209,84,187,73,30,38,359,104,394,365,279,55,347,310,376,189,
+    318,137,390,27,260,159,31,227,229,139,345,124,141,37,390,410,40,262,189,143,
+    302,329,298,334,20,214,350,15,334,371,346,52,80,265,356,110,157,345,146,365,
+    94,176,406,260,173,369,131,140,258,286,244,313,94,346,173,94,252,10,371,256,
+    347,352,355,371,66,35,40,392,361,174,173,166,310,135,355,94,132,143,350,240,
+    135,16,41,88,90,360,3,11,371,352,14,125,33,392,19,142,363,54,174,339,
+    166,25,404,100,109,11,326,302,302,199,61,320,145,46,353,246,321,124,365,256,
+    325,97,140,138,315,33,138,87,392,155,144,404,143,258,221,187,402,344,188,311,
+    172,131,73,403,12,345,143,213,23,135,385,364,392,292,263,261,367,37,135,361,
+    280,393,47,134,133,341,371,19,283,325,341,248,361,90,321,344,80,351,35,299,
+    149,66,410,30,329,11,351,129,363,386,40,361,370,135,356,384,49,94,14,392,
+    404,202,133,365,351,359,23,76,27,390,238,331,331,11,375,184,138,341,89,33,
+    111,312,392,313,347,94,372,277,173,231,374,136,192,9,68,321,118,138,346,294,
+    258,145,25,41,286,11,350,369,363,320,317,362,85,19,134,58,376,321,106,18,
+    41,234,24,171,54,369,379,404,13,311,5,44,371,385,406,295,45,140,38,292,
+    116,387,263,361,66,351,184,11,249,139,175,185,194,258,348,320,367,73,398,349,
+    294,260,141,334,409,255,346,169,33,110,177,256,37,32,341,133,20,130,44,328,
+    59,137,142,291,89,348,143,80,137,135,330,179,20,106,348,313,348,136,205,364,
+    139,134,260,247,90,394,2,285,365,146,229,32,365,60,140,357,159,135,41,59,
+    137,292,376,24,18,3,279,319,40,31,167,221,139,258,200,345,227,264,221,33,
+    178,171,189,161,10,371,16,377,392,310,370,118,36,36,173,324,341,183,41,32,
+    271,302,130,173,188,377,234,321,361,171,215,84,345,374,377,372,354,39,388,354,
+    348,110,77,152,77,202,332,33,84,77,84,139,332,310,361,361,300,0,60,258,
+    74,89,196,298,74,0,140,173,129,279,340,218,267,176,371,6,104,181,346,116,
+    167,104,326,109,353,19,256,143,68,403,89,362,135,301,133,365,133,32,141,196,
+    270,218,372,196,98,270,27,141,27,376,107,141,196,394,196,105,356,202,201,277,
+    366,351,149,146,229,365,201,300,109,200,393,143,372,206,156,179,94,351,201,19,
+    73,319,98,90,19,125,39,145,125,161,364,172,241,94,205,73,348,178,257,135,
+    140,302,198,332,256,106,401,311,140,176,263,69,180,73,97,135,366,51,365,173,
+    45,206,401,54,310,79,139,179,179,227,346,75,133,86,86,86,90,143,41,391,
+    90,210,392,94,90,252,90,148,129,148,97,65,252,258,354,159,332,5,41,133,
+    60,116,116,379,61,69,61,274,274,45,89,261,365,133,149,258,55,41,342,133,
+    59,353,126,339,145,173,373,178,181,380,98,45,94,41,266,143,294,205,180,393,
+    241,17,138,138,243,173,294,17,32,140,107,103,61,45,162,157,79,84,392,303,
+    267,292,49,152,138,111,49,111,148,79,133,325,140,184,188,380,372,56,358,152,
+    165,258,292,45,107,135,56,173,325,88,254,32,256,45,403,98,339,20,133,79,
+    263,294,75,135,133,20,361,143,132,299,135,79,76,345,107,94,299,135,100,57,
+    148,45,44,32,333,185,173,246,294,249,156,137,107,260,145,126,384,402,175,143,
+    247,181,111,137,111,135,135,321,132,133,135,365,135,392,32,135,208,173,395,173,
+    360,267,8,104,134,345,193,178,139,154,351,392,73,395,231,138,266,296,365,395,
+    208,173,139,169,169,144,156,362,337,350,209,161,138,178,119,300,152,139,99,20,
+    205,38,168,369,369,202,152,359,144,263,185,21,200,38,170,148,202,73,356,356,
+    149,345,365,359,343,299,169,210,185,249,300,133,262,136,34,267,349,365,145,86,
+    144,330,143,59,351,193,359,359,189,173,35,271,267,10,296,374,142,10,105,345,
+    374,342,14,96,96,10,50,365,354,246,143,322,100,255,80,243,94,104,57,142,
+    264,14,126,11,216,300,87,142,365,375,136,150,136,161,125,347,266,15,111,261,
+    378,161,88,125,371,111,162,130,59,111,175,175,314,75,142,145,346,247,266,365,
+    152,361,15,221,266,300,359,258,222,284,406,299,345,130,8,300,346,339,126,351,
+    339,11,406,402,351,59,193,214,59,133,20,304,20,161,15,21,386,147,185,370,
+    185,346,107,344,351,138,138,341,4,264,392,196,366,341,296,133,268,186,38,144,
+    351,356,139,268,345,133,80,263,346,286,33,341,80,329,173,389,116,173,360,360,
+    361,297,66,384,242,360,119,360,392,27,242,325,173,297,124,329,408,56,88,372,
+    27,372,349,361,173,145,292,68,41,142,139,5,111,361,310,173,33,167,173,361,
+    361,372,310,104,179,278,266,266,84,171,75,348,398,286,24,24,24,24,2,58,
+    370,30,133,370,306,86,301,108,6,85,283,300,302,402,266,301,15,356,134,241,
+    312,99,341,312,70,281,50,156,106,143,180,107,61,156,392,137,388,6,71,152,
+    319,38,300,370,265,253,364,118,310,320,38,170,69,133,177,123,204,356,195,38,
+    100,357,363,403,119,133,69,55,330,206,124,173,332,349,384,347,364,189,360,192,
+    238,132,365,146,40,179,335,366,88,14,262,262,138,21,93,6,78,89,80,116,
+    329,154,308,256,123,392,366,345,345,34,217,358,346,46,74,342,124,123,345,99,
+    360,146,189,80,100,198,58,256,37,345,99,94,137,123,38,299,217,335,94,365,
+    58,238,173,11,372,113,342,259,345,80,300,144,245,342,217,191,180,270,370,66,
+    394,300,394,328,346,365,256,252,403,106,49,341,356,1,216,95,244,365,350,298,
+    125,206,57,266,143,116,375,336,79,254,243,17,94,362,119,375,119,115,142,369,
+    94,57,394,337,147,106,147,410,21,183,73,227,318,310,348,132,256,84,80,110,
+    384,346,365,178,403,204,201,392,363,133,394,100,302,377,350,115,130,158,127,322,
+    348,80,357,355,113,361,169,365,2,251,298,330,123,354,79,337,114,376,370,70,
+    240,349,2,102,161,360,57,350,14,130,222,126,353,159,79,89,133,229,209,368,
+    118,372,183,254,195,100,237,38,296,173,212,406,119,157,350,348,169,20,389,384,
+    176,6,204,178,315,94,21,116,122,103,309,100,370,361,106,106,11,116,317,47,
+    365,2,134,332,348,339,173,346,321,410,265,35,345,379,360,74,256,66,262,192,
+    208,104,74,266,169,176,317,379,128,168,292,133,410,344,90,139,125,256,301,341,
+    303,33,84,173,259,3,380,368,222,371,326,166,292,346,336,125,2,388,230,153,
+    402,402,295,66,122,179,1,23,349,335,345,342,54,292,106,256,256,322,59,59,
+    364,403,14,54,43,119,360,256,389,88,176,348,247,292,165,380,367,107,239,389,
+    289,398,225,113,188,361,66,267,32,20,71,168,350,143,321,38,325,3,142,59,
+    147,369,341,214,294,371,389,165,138,124,116,70,394,31,338,234,371,366,375,363,
+    237,202,125,374,44,130,128,128,346,119,133,162,393,341,292,356,129,79,225,357,
+    176,371,287,38,260,361,59,245,24,173,368,384,341,203,367,245,21,162,346,371,
+    138,184,157,379,125,325,363,119,1,355,259,289,369,313,123,351,2,317,192,30,
+    115,152,57,287,36,279,312,337,133,242,345,258,300,100,403,138,188,343,337,310,
+    38,118,317,0,115,317,262,225,119,392,285,211,100,212,67,2,259,330,14,5,
+    5,175,408,389,208,312,312,320,66,256,137,39,137,148,320,294,26,134,2,350,
+    249,184,95,106,350,125,130,113,238,348,380,33,356,253,62,192,192,125,171,75,
+    95,321,364,13,367,285,137,201,350,126,193,270,43,246,169,350,133,395,34,162,
+    408,350,310,118,94,177,260,346,41,32,59,120,359,80,409,86,38,130,377,45,
+    52,59,371,335,29,137,364,346,256,118,175,356,65,130,366,255,145,262,359,225,
+    185,310,361,367,57,386,237,394,139,229,130,351,256,80,379,365,300,137,372,2,
+    369,145,159,371,245,61,95,122,78,60,352,285,247,247,366,408,227,66,167,318,
+    132,279,118,347,364,79,247,40,133,139,118,325,33,359,197,22,325,185,130,20,
+    370,225,366,125,197,123,389,173,181,115,215,350,208,361,173,185,183,208,59,36,
+    251,247,349,132,208,346,79,157,361,32,367,271,68,165,318,350,145,48,128,132,
+    398,225,350,22,173,32,31,173,365,188,215,375,313,346,382,135,375,395,167,225,
+    215,167,182,341,130,366,265,310,226,135,130,352,366,214,333,333,78,150,372,141,
+    248,374,50,125,130,372,80,113,163,50,330,332,341,187,113,269,276,180,106,113,
+    319,113,332,370,113,366,131,255,371,116,372,187,267,371,263,113,44,341,372,267,
+    157,94,372,372,360,332,332,332,333,190,130,365,128,186,186,332,360,332,329,299,
+    255,185,159,360,376,341,100,371,345,111,247,365,62,258,258,390,402,60,257,347,
+    294,161,33,256,225,208,133,134,392,392,8,359,365,262,198,146,277,335,87,12,
+    12,320,149,127,410,154,14,140,66,140,133,159,66,140,135,320,173,6,345,89,
+    400,253,8,321,163,266,320,392,336,97,252,68,107,221,319,247,141,362,92,5,
+    185,265,210,152,105,358,6,38,35,180,395,94,125,392,46,165,183,183,185,5,
+    58,243,205,353,73,133,119,189,49,38,171,96,366,124,77,388,94,110,363,79,
+    79,111,30,362,366,85,105,372,70,351,153,295,301,80,19,68,123,360,158,57,
+    147,60,148,117,216,3,353,348,372,9,94,6,365,366,116,356,46,262,103,2,
+    13,87,268,369,146,134,66,193,168,144,37,294,139,389,178,178,21,37,126,21,
+    300,359,113,141,364,222,66,371,21,360,267,315,247,263,339,143,187,391,155,39,
+    73,58,320,3,23,41,13,149,392,79,365,392,365,244,133,401,256,288,143,221,
+    157,152,321,163,221,135,77,139,98,371,80,246,106,332,172,87,360,258,163,3,
+    298,79,216,332,37,366,131,14,175,113,70,398,124,10,10,371,66,196,138,280,
+    364,103,149,404,371,129,80,363,361,10,49,198,33,75,336,366,90,393,138,139,
+    122,98,47,135,252,171,349,129,172,76,339,357,133,133,159,367,318,37,369,148,
+    313,313,300,201,318,343,37,332,321,268,393,173,393,9,285,378,77,326,345,391,
+    359,100,390,2,104,361,149,326,372,342,351,181,115,168,33,246,13,36,185,185,
+    238,258,198,208,398,306,302,184,38,194,16,140,27,302,392,387,149,369,68,36,
+    392,346,113,259,139,66,295,210,54,361,318,383,258,259,176,341,400,260,383,356,
+    294,294,6,255,159,73,86,268,208,78,78,409,66,299,79,79,320,65,210,89,
+    129,320,57,364,395,135,5,259,133,260,153,365,247,14,68,136,364,369,358,320,
+    167,143,127,60,271,258,359,348,346,119,2,360,61,118,280,139,171,161,185,361,
+    320,341,127,183,183,281,173,179,271,32,359,361,171,6,339,300,274,286,399,399,
+    299,365,193,152,395,399,125,125,163,365,125,356,163,301,195,409,301,365,392,106,
+    41,136,90,11,385,15,315,269,180,94,56,347,354,351,216,347,162,346,338,372,
+    196,313,79,79,364,263,338,105,105,256,200,200,366,132,36,57,380,326,319,94,
+    108,363,362,117,99,300,322,319,331,361,14,365,158,134,79,126,161,374,134,6,
+    82,175,128,66,361,243,144,256,213,90,351,89,68,362,162,405,128,256,148,384,
+    12,365,136,322,378,12,346,129,88,69,359,13,68,5,297,343,114,5,345,5,
+    136,175,79,374,136,300,89,132,14,186,79,235,231,71,213,258,135,318,142,235,
+    30,118,348,86,133,309,279,88,340,123,399,94,192,59,274,94,140,361,115,342,
+    393,239,75,133,154,393,363,139,374,203,93,38,373,399,228,361,212,352,89,14,
+    371,336,90,339,87,345,371,111,75,6,221,394,402,388,57,213,372,331,348,392,
+    80,198,208,256,14,298,261,80,119,356,85,391,205,8,210,94,180,403,403,300,
+    270,294,362,194,138,106,310,353,341,403,143,294,251,274,363,73,136,302,133,96,
+    349,126,144,137,105,169,135,135,365,222,392,133,133,348,122,110,146,158,361,206,
+    178,244,80,370,361,30,298,366,300,111,267,403,311,341,123,337,184,360,272,137,
+    186,252,348,296,173,37,351,195,94,317,198,341,152,48,48,329,223,353,214,371,
+    212,254,218,144,298,392,116,66,399,80,251,334,348,202,345,361,345,2,361,371,
+    310,371,337,173,348,143,266,400,256,348,402,73,33,185,2,80,80,184,202,50,
+    254,143,253,23,180,339,16,350,302,256,130,86,344,281,320,88,88,138,326,221,
+    267,140,131,140,258,68,353,125,339,166,14,366,40,216,94,140,187,3,167,163,
+    366,360,143,173,68,348,126,126,367,32,298,329,60,363,345,214,48,134,331,356,
+    371,341,66,278,198,59,280,262,130,344,258,47,203,94,138,76,365,393,198,129,
+    202,3,367,357,138,341,198,372,391,265,300,351,336,175,196,270,310,248,341,337,
+    142,125,5,261,10,356,331,111,41,363,247,346,372,367,277,279,38,181,198,241,
+    5,192,105,162,262,134,288,390,372,138,277,206,367,133,313,224,348,322,242,168,
+    216,10,2,247,251,365,249,371,171,357,194,365,387,150,369,221,173,66,111,361,
+    139,398,33,380,116,219,169,208,389,125,125,5,219,259,192,250,106,345,260,336,
+    386,196,348,348,208,177,175,126,111,65,392,356,365,126,346,162,272,346,361,32,
+    137,198,86,86,348,365,130,137,94,300,14,294,315,259,175,128,352,224,73,365,
+    24,2,223,227,192,327,40,139,49,371,251,277,279,213,361,319,367,24,224,373,
+    367,202,14,192,298,353,221,75,181,372,167,361,306,180,137,223,167,258,367,306,
+    130,267,201,173,186,361,395,167,403,138,145,145,155,374,192,403,55,316,94,11,
+    403,350,352,200,310,319,10,133,106,231,358,370,398,115,186,316,216,204,50,258,
+    302,24,360,403,221,94,403,173,358,20,279,213,225,225,367,186,202,227,277,318,
+    111,385,264,371,301,3,332,311,339,278,363,123,365,140,401,201,395,60,123,404,
+    109,394,71,339,365,10,300,300,39,298,152,357,300,370,128,365,327,300,348,104,
+    37,269,104,350,376,384,10,115,361,350,134,298,36,277,129,201,156,160,18,313,
+    23,377,133,372,133,366,201,156,263,268,390,135,94,227,19,128,198,262,116,371,
+    300,227,139,227,392,371,10,160,227,262,208,30,143,107,262,125,345,177,300,227,
+    385,298,341,351,160,130,177,146,128,365,365,10,262,39,10,90,55,77,310,359,
+    61,189,77,301,253,90,398,94,297,152,136,136,398,341,409,359,302,77,61,350,
+    138,296,84,84,84,95,135,302,36,295,295,208,95,33,177,348,348,163,370,340,
+    370,177,177,363,195,340,340,340,95,363,79,162,393,142,97,106,97,334,97,97,
+    300,366,38,151,221,139,341,224,143,247,29,346,14,143,138,326,266,325,138,345,
+    69,300,300,252,133,351,390,351,221,386,346,341,194,80,184,252,325,88,302,351,
+    332,189,189,346,29,189,143,351,143,145,177,145,302,346,35,335,221,294,337,348,
+    173,80,61,130,183,365,256,274,345,116,298,371,41,315,256,274,373,8,363,4,
+    360,345,138,80,133,258,89,339,256,28,258,256,30,138,266,98,348,5,167,61,
+    6,410,410,362,143,98,152,105,358,253,173,327,266,361,94,355,134,180,336,247,
+    5,58,161,373,266,125,253,205,3,327,180,38,252,73,116,162,355,196,330,358,
+    365,15,119,6,188,80,94,359,70,185,82,84,96,267,73,365,210,300,3,341,
+    128,392,201,173,133,330,341,370,106,347,173,363,137,391,186,137,80,80,371,351,
+    21,260,269,90,90,216,173,370,348,123,61,298,37,332,103,146,118,347,366,371,
+    168,149,169,166,348,268,155,39,39,318,179,126,143,166,256,205,163,163,406,106,
+    54,360,360,98,187,187,172,145,79,391,113,366,73,116,391,341,350,247,361,311,
+    138,13,406,157,73,386,106,366,403,380,129,371,338,362,90,265,362,325,365,392,
+    300,376,363,80,395,149,189,361,198,116,133,133,128,329,299,198,258,345,371,404,
+    167,152,361,361,341,404,30,315,277,152,262,371,325,184,332,77,346,343,25,60,
+    277,138,148,181,345,311,260,403,341,13,68,56,258,369,225,56,133,300,283,311,
+    404,136,177,150,32,70,28,71,332,184,387,386,386,5,26,266,259,54,408,61,
+    61,346,371,244,183,349,29,20,262,137,361,169,386,179,177,177,139,65,79,409,
+    137,111,363,137,363,145,386,365,358,216,364,364,365,225,348,133,351,152,346,66,
+    5,408,341,365,277,61,180,375,371,373,366,279,138,173,111,183,183,68,277,346,
+    143,32,367,162,361,341,216,267,34,53,186,68,68,225,361,361,361,162,361,44,
+    159,44,394,129,140,359,34,34,178,104,410,260,143,104,143,345,255,255,30,265,
+    265,133,365,310,6,392,388,349,365,139,359,144,6,359,139,94,375,14,300,21,
+    71,304,86,225,300,89,239,392,346,125,59,341,387,321,58,208,244,239,328,20,
+    175,392,394,20,392,66,208,365,365,252,257,144,279,304,58,391,307,260,390,300,
+    269,346,9,58,111,40,252,387,286,339,58,341,33,292,256,380,113,196,75,124,
+    391,356,201,341,344,94,365,9,252,70,104,241,129,322,201,134,324,130,393,294,
+    194,210,16,113,380,210,9,387,140,32,94,392,125,86,45,14,14,387,201,260,
+    32,89,200,9,40,204,41,138,348,167,97,252,222,135,19,19,353,97,363,128,
+    370,370,133,110,247,329,380,110,399,208,263,14,262,78,45,111,360,7,138,356,
+    185,345,399,157,367,66,243,68,360,203,103,49,94,330,242,88,349,365,392,327,
+    392,355,75,410,350,332,111,157,195,329,370,21,19,37,166,14,133,3,302,150,
+    369,336,311,302,263,371,371,203,312,27,349,88,142,80,111,181,292,175,168,312,
+    392,254,263,142,142,139,5,164,184,366,177,58,185,365,41,32,332,310,352,203,
+    33,345,88,110,157,159,14,259,351,179,179,177,185,133,367,348,329,369,173,329,
+    366,359,361,329,66,240,135,130,213,130,104,222,148,15,365,256,229,89,143,361,
+    365,378,14,365,365,84,286,300,84,300,300,104,69,366,125,94,123,345,334,38,
+    136,6,298,66,387,145,322,94,66,201,348,125,34,231,140,390,365,201,267,339,
+    296,283,357,140,69,387,136,259,246,59,259,14,14,297,59,135,105,100,85,14,
+    156,135,17,350,59,113,136,123,201,113,339,145,133,133,111,60,185,185,333,130,
+    392,130,130,365,365,365,365,373,373,294,353,342,330,361,361,371,38,23,16,69,
+    18,246,369,249,387,367,38,38,402,336,133,87,393,365,340,35,14,66,180,94,
+    340,391,52,340,140,58,346,359,121,362,127,189,124,340,37,392,356,140,332,50,
+    392,166,50,62,240,346,73,133,33,392,50,394,166,371,351,138,135,300,134,15,
+    129,94,359,341,242,363,341,346,391,249,325,62,391,391,17,62,39,35,137,130,
+    137,130,198,183,349,10,266,352,352,14,365,170,274,61,71,96,133,274,274,32,
+    320,323,323,97,256,300,55,392,340,195,346,86,367,326,205,342,393,39,345,133,
+    345,346,134,370,339,50,311,159,371,15,392,256,54,36,319,335,258,222,131,354,
+    228,161,348,352,150,125,148,89,127,319,311,345,238,33,45,143,365,10,34,205,
+    244,410,382,362,143,8,231,216,391,239,21,328,125,125,143,57,175,310,40,66,
+    58,365,332,370,94,133,246,353,372,221,108,94,346,14,370,261,357,50,19,129,
+    356,41,14,302,346,320,369,404,77,208,392,365,300,219,359,300,346,169,122,161,
+    209,392,351,175,327,129,70,118,155,111,122,346,137,302,310,125,265,362,130,130,
+    38,134,365,354,108,179,130,403,356,38,295,235,121,82,152,73,326,104,267,346,
+    257,373,246,153,62,130,80,350,330,361,148,27,216,374,195,369,369,372,247,163,
+    260,373,371,332,138,346,389,179,325,116,118,261,325,21,365,258,130,346,11,194,
+    365,122,311,267,37,162,345,345,370,173,176,128,50,365,373,173,226,216,80,268,
+    357,258,345,205,50,88,11,79,54,33,199,286,133,109,109,353,61,256,155,326,
+    187,346,149,112,221,263,40,78,113,386,140,339,372,139,133,167,371,132,119,267,
+    320,325,325,225,340,48,125,131,346,33,352,341,130,80,317,404,135,369,68,143,
+    24,37,62,11,261,24,59,109,79,216,374,349,400,70,129,47,264,273,353,27,
+    15,205,404,325,260,40,11,357,341,100,258,341,371,371,14,357,128,205,14,365,
+    202,369,148,60,366,80,36,196,257,152,371,2,261,361,234,97,374,404,285,172,
+    89,367,162,162,268,104,374,313,313,256,363,311,129,133,106,143,45,221,351,148,
+    391,369,26,359,298,20,148,372,346,131,369,362,173,288,322,366,49,356,258,319,
+    129,374,298,206,104,297,50,249,210,210,113,38,24,24,24,54,205,323,387,330,
+    5,306,194,109,268,379,142,130,148,175,238,311,262,366,189,295,341,333,194,258,
+    297,369,263,150,66,392,184,144,256,256,371,252,177,50,370,39,392,330,37,256,
+    266,246,11,17,264,137,383,38,175,252,162,130,260,37,366,366,346,346,59,320,
+    79,77,77,313,145,27,350,86,89,169,169,39,116,256,348,205,140,177,345,24,
+    145,52,348,320,299,247,365,41,348,216,59,320,140,311,116,137,341,357,73,262,
+    262,143,26,153,351,367,5,196,365,179,289,146,127,199,167,2,179,361,164,347,
+    38,371,366,58,200,2,200,77,256,208,167,199,40,392,234,234,361,362,20,392,
+    161,161,370,94,181,204,37,130,32,200,167,127,357,271,32,133,143,128,297,365,
+    175,214,201,321,145,98,98,399,100,373,345,135,356,302,277,346,37,344,138,100,
+    135,259,132,259,386,73,256,134,70,381,134,133,392,149,133,162,96,65,386,259,
+    100,135,138,371,135,361,185,125,386,346,346,48,58,266,125,125,125,80,300,325,
+    196,125,173,87,317,15,68,141,295,365,365,294,125,88,361,301,301,23,384,265,
+    170,255,6,57,275,86,279,376,336,387,69,150,371,157,97,298,30,336,106,156,
+    345,64,258,392,274,164,199,288,362,228,8,35,272,346,258,8,134,371,94,5,
+    346,247,392,392,80,64,388,37,133,361,161,15,34,143,342,125,373,145,6,262,
+    59,391,374,339,217,365,302,396,254,331,74,150,389,254,94,243,6,5,380,333,
+    156,187,259,374,125,10,19,392,246,320,21,247,319,363,390,384,362,10,119,221,
+    364,66,38,247,134,208,198,36,360,40,266,205,41,134,94,384,395,59,31,210,
+    222,165,94,243,8,240,179,212,108,258,143,318,6,336,336,5,143,402,241,388,
+    7,7,66,221,143,164,183,135,257,369,167,227,20,380,258,121,164,300,138,391,
+    226,104,104,267,305,55,375,151,365,351,27,130,251,397,300,212,7,38,107,392,
+    164,79,79,392,261,3,229,390,100,137,158,73,212,327,178,384,189,70,337,145,
+    178,143,392,186,360,344,318,351,216,60,137,391,133,130,348,371,2,336,234,56,
+    20,103,325,390,37,284,284,154,198,183,143,246,135,365,329,294,279,339,351,30,
+    90,137,345,146,142,330,163,132,332,402,254,189,6,116,296,225,144,380,302,364,
+    145,21,339,21,409,365,385,189,312,336,169,316,9,135,128,61,341,339,262,246,
+    297,178,205,199,94,7,143,61,344,2,144,373,404,36,46,138,332,12,212,222,
+    283,410,344,346,348,37,68,288,187,263,98,79,301,69,254,66,387,131,133,322,
+    257,256,240,302,258,180,364,360,145,391,176,107,365,132,294,391,80,23,320,35,
+    19,138,325,155,334,361,56,394,143,326,258,153,7,239,138,185,108,206,103,392,
+    59,200,24,288,109,246,372,234,135,391,142,135,371,361,162,214,123,278,247,341,
+    285,405,357,203,325,225,30,300,404,390,365,359,369,15,362,128,361,377,3,356,
+    360,344,152,43,133,325,165,165,36,148,142,142,332,138,130,102,39,350,70,351,
+    372,258,364,30,384,11,363,341,13,167,342,262,32,100,184,404,103,137,105,262,
+    277,268,40,43,386,316,316,20,41,277,9,56,288,152,363,61,392,231,165,135,
+    312,265,99,348,309,287,139,204,80,46,234,294,318,384,321,241,8,57,173,322,
+    125,392,337,126,258,342,259,326,390,80,351,234,267,30,384,100,345,82,297,150,
+    297,302,7,363,18,312,320,284,32,317,142,39,45,108,19,90,304,66,256,312,
+    385,175,37,38,109,185,188,184,404,96,125,384,45,321,126,54,213,208,136,111,
+    367,392,5,392,225,194,32,156,41,297,333,137,208,208,389,24,154,16,136,366,
+    105,258,177,133,367,145,250,250,169,78,348,280,111,377,365,348,37,37,284,216,
+    123,310,116,110,57,409,222,179,391,130,399,137,133,26,59,59,35,20,35,145,
+    94,177,12,94,260,20,56,402,398,341,255,262,78,222,126,351,185,137,53,318,
+    116,260,344,135,97,369,171,215,185,294,402,380,255,48,133,60,289,26,263,263,
+    128,138,262,159,59,351,147,247,7,5,143,364,80,200,312,201,133,319,402,61,
+    353,167,22,143,364,279,364,364,221,344,133,18,227,100,392,392,164,208,135,351,
+    178,320,7,312,185,190,272,325,241,362,171,22,302,377,222,348,146,132,173,165,
+    128,367,185,183,258,258,377,258,167,348,367,198,271,32,367,53,351,297,188,146,
+    201,173,377,186,320,407,173,68,337,60,137,145,167,173,215,392,111,111,256,359,
+    255,315,301,151,370,96,365,104,97,8,87,391,253,68,156,205,345,106,119,27,
+    350,201,41,100,66,356,137,205,36,142,298,79,371,38,5,7,356,137,79,175,
+    225,14,33,68,79,365,97,286,152,361,78,133,331,350,79,137,140,362,347,205,
+    302,2,260,2,391,66,390,94,302,177,266,354,365,137,294,137,402,365,175,14,
+    173,350,350,342,358,256,256,385,18,145,385,168,88,8,8,167,371,167,341,74,
+    299,177,134,125,351,134,371,390,137,344,327,74,139,38,366,94,259,386,266,402,
+    386,76,56,310,352,402,402,262,179,402,41,76,395,87,32,117,371,300,244,370,
+    198,242,256,386,196,189,244,247,181,94,87,357,140,140,221,406,388,365,181,296,
+    135,371,365,256,392,86,249,86,386,159,315,371,345,133,133,133,132,276,59,142,
+    392,379,351,327,359,356,95,165,97,116,319,66,356,32,300,161,362,300,340,205,
+    205,335,47,345,374,11,4,380,8,138,163,299,125,87,118,111,33,357,206,131,
+    89,262,125,365,346,352,361,380,87,320,298,143,362,377,19,353,367,357,253,390,
+    180,47,118,198,410,208,15,356,131,388,404,300,300,371,88,70,196,221,33,342,
+    73,2,19,4,394,183,348,161,327,34,300,129,129,357,162,356,137,139,392,139,
+    295,330,123,361,96,349,293,350,364,374,130,116,116,146,339,348,163,394,346,37,
+    299,21,389,389,345,116,130,118,36,339,326,402,408,394,255,140,346,294,221,346,
+    263,256,140,111,391,365,392,3,339,179,176,33,340,350,377,88,357,103,365,347,
+    374,130,356,205,162,364,367,302,341,302,263,196,214,135,232,3,362,47,363,317,
+    139,206,137,148,99,343,33,256,118,361,173,2,133,133,199,377,351,118,210,208,
+    50,221,387,130,10,116,357,44,177,348,59,140,250,179,335,346,365,133,129,58,
+    364,364,173,320,330,350,88,298,388,118,365,349,353,298,137,10,140,361,2,364,
+    279,302,200,359,363,255,173,36,161,70,177,361,132,185,346,277,183,215,188,186,
+    293,321,361,395,373,373,266,364,103,364,125,119,302,26,26,299,194,29,29,325,
+    408,24,356,130,366,261,89,247,373,370,280,246,89,94,180,88,266,325,235,327,
+    309,390,168,168,408,206,129,340,335,34,133,256,367,404,340,330,168,169,200,183,
+    210,65,341,208,12,384,302,302,210,395,274,6,255,79,79,61,173,111,133,142,
+    14,355,37,49,292,279,375,267,258,371,97,345,30,294,359,86,345,403,173,353,
+    23,55,274,16,336,66,387,195,38,365,96,104,75,173,256,302,98,327,136,294,
+    339,166,142,195,362,192,203,310,372,117,88,11,138,73,99,363,348,41,47,239,
+    302,126,352,40,395,40,311,8,311,133,344,139,105,133,196,247,14,340,4,87,
+    89,365,94,214,346,125,360,74,352,390,363,179,281,80,198,388,113,392,50,374,
+    408,74,302,379,79,173,185,135,37,311,259,90,214,350,348,157,252,319,346,392,
+    108,350,134,134,105,10,208,365,364,364,300,225,14,79,365,180,19,221,165,119,
+    8,86,393,58,49,362,94,7,209,97,256,270,278,196,296,311,389,347,370,298,
+    111,336,384,214,227,369,66,392,384,30,59,106,21,142,5,94,135,6,79,152,
+    213,395,14,181,31,294,310,41,244,300,108,384,363,37,142,300,392,181,198,173,
+    277,384,379,16,386,392,183,73,185,299,173,167,369,302,359,305,256,390,256,173,
+    365,349,390,173,289,107,149,12,274,350,7,274,19,403,40,365,49,356,395,135,
+    408,84,84,370,85,104,151,169,386,178,366,362,119,102,365,300,100,376,186,94,
+    138,122,111,322,110,341,161,279,3,3,144,365,402,157,392,264,330,287,287,128,
+    143,142,358,79,400,371,377,0,367,138,181,386,360,272,390,60,256,260,126,111,
+    136,399,359,317,292,390,11,329,164,140,253,12,94,281,330,145,346,168,181,90,
+    256,342,146,97,313,176,265,329,370,198,9,183,246,399,66,357,332,379,5,106,
+    14,66,116,403,392,274,11,103,135,128,339,234,134,327,133,350,189,131,296,28,
+    89,311,200,345,173,173,74,262,367,317,143,325,351,163,402,302,32,86,341,140,
+    173,18,347,92,322,392,166,175,135,402,180,173,256,19,187,50,258,202,256,256,
+    23,112,32,62,88,240,9,9,131,404,37,379,133,173,246,371,371,106,146,73,
+    321,98,340,66,56,86,37,386,256,372,361,371,267,365,290,274,46,172,256,402,
+    94,152,166,405,405,388,109,89,89,298,263,221,339,113,185,118,138,365,40,143,
+    143,37,410,176,259,392,46,360,143,11,137,402,403,18,246,71,41,33,199,126,
+    135,111,346,75,258,61,111,68,188,392,267,206,94,103,246,386,365,336,290,79,
+    364,94,341,341,76,134,404,135,365,298,346,361,361,44,135,47,371,119,384,344,
+    248,14,363,132,356,283,362,165,361,12,130,162,138,162,310,90,351,336,392,135,
+    210,196,41,125,125,175,172,329,214,371,370,198,311,357,357,362,390,248,364,133,
+    138,364,41,78,371,405,341,198,325,133,138,148,265,367,278,129,184,170,267,349,
+    251,300,96,320,167,342,371,36,189,143,298,41,247,351,134,365,386,94,234,201,
+    168,277,106,135,143,318,363,390,9,292,372,403,206,313,134,363,138,129,97,88,
+    384,258,192,316,372,351,277,300,392,54,374,329,181,277,321,268,385,310,299,318,
+    152,346,106,256,99,99,316,241,322,100,359,68,230,133,309,105,46,259,30,258,
+    127,198,356,98,99,402,336,260,362,68,134,149,408,61,183,18,395,287,346,133,
+    175,130,369,258,113,96,96,333,126,256,290,54,246,370,125,136,125,128,111,225,
+    365,99,150,111,111,26,194,139,66,399,170,168,36,50,173,355,263,306,86,330,
+    109,380,313,171,185,176,201,184,34,313,152,41,321,16,185,142,389,384,302,387,
+    194,208,224,362,327,246,395,292,346,267,122,135,50,133,361,259,358,367,84,359,
+    392,260,408,50,255,302,126,162,390,409,373,294,346,47,68,85,97,208,345,260,
+    272,179,181,260,348,282,86,386,336,169,374,308,78,37,321,200,143,37,313,145,
+    145,68,130,133,234,349,336,227,281,395,330,383,89,264,270,122,258,106,181,169,
+    99,41,346,299,403,286,133,74,140,185,135,41,372,318,302,136,320,179,229,366,
+    346,315,294,408,357,37,97,143,408,365,262,255,361,171,90,130,60,133,315,20,
+    252,37,41,396,111,133,138,134,263,385,135,259,61,365,16,311,297,179,173,30,
+    200,366,322,319,202,256,333,18,132,133,258,221,227,365,99,149,366,230,263,361,
+    256,201,388,111,47,133,162,253,65,41,100,202,370,392,129,258,171,171,284,185,
+    173,53,189,204,130,238,185,392,99,75,372,173,88,402,312,175,136,41,263,395,
+    185,361,173,395,36,138,80,313,127,225,371,183,166,137,348,111,143,350,180,367,
+    135,366,370,367,349,229,20,32,167,143,306,297,341,50,267,266,25,142,371,188,
+    173,53,186,60,145,361,167,167,395,171,173,6,215,371,180,110,258,49,128,352,
+    371,365,258,238,356,34,41,256,148,365,145,346,356,119,371,162,168,160,309,346,
+    2,365,256,42,38,262,160,149,160,149,44,292,107,366,352,351,371,258,350,364,
+    100,345,320,139,238,125,325,128,356,245,346,350,42,297,294,116,41,365,80,371,
+    48,128,392,391,49,21,345,256,21,21,338,143,258,38,289,38,289,393,315,315,
+    173,56,371,173,111,58,80,310,135,389,208,208,363,208,52,362,326,299,58,295,
+    356,359,302,24,145,249,257,265,313,263,374,175,365,93,392,364,24,131,59,133,
+    70,390,374,342,40,18,325,139,295,366,69,142,130,53,365,59,75,136,175,18,
+    75,135,135,302,238,76,395,366,263,365,292,260,152,350,359,68,130,130,106,260,
+    133,365,238,130,76,365,350,345,109,210,198,198,2,138,75,371,14,14,14,247,
+    247,14,32,196,118,23,247,178,134,386,285,210,336,359,84,277,348,143,210,118,
+    265,74,292,320,244,143,79,54,14,286,286,196,285,302,371,336,119,135,318,286,
+    189,210,196,330,277,33,255,185,386,288,386,200,185,266,70,300,66,205,145,195,
+    256,250,213,256,61,348,44,89,362,218,18,94,298,73,263,256,366,346,115,362,
+    3,360,152,263,360,73,59,189,263,362,374,374,307,307,391,19,369,60,307,170,
+    221,335,86,111,329,392,265,18,380,202,53,130,69,116,30,402,44,339,86,57,
+    346,336,195,265,256,294,251,116,258,345,345,359,310,279,104,136,38,345,332,142,
+    321,392,392,258,201,106,340,140,140,281,146,123,319,267,133,15,15,97,342,393,
+    87,354,145,125,228,256,89,356,356,262,365,344,374,372,117,361,36,36,59,370,
+    78,125,132,256,210,235,198,57,202,201,39,242,14,292,392,244,241,400,375,105,
+    181,198,380,90,238,173,187,25,90,341,125,208,198,302,143,377,336,336,336,119,
+    173,201,365,85,88,370,326,392,388,106,386,361,310,161,141,143,351,265,365,134,
+    393,267,253,130,201,12,380,395,170,370,106,123,97,85,196,310,125,252,49,86,
+    392,313,227,37,180,243,20,256,310,221,143,284,395,299,171,357,145,94,241,205,
+    319,362,133,369,109,13,358,183,185,59,188,351,253,380,140,366,241,138,365,130,
+    130,376,37,366,341,124,135,362,178,310,133,84,353,94,284,289,392,366,345,346,
+    151,395,136,188,188,3,73,325,209,171,365,201,267,139,253,341,350,351,123,356,
+    313,161,322,261,143,84,394,279,252,359,354,392,110,128,206,132,337,257,240,345,
+    266,181,365,134,140,258,136,137,390,300,402,27,85,130,133,181,32,131,125,229,
+    359,139,178,265,341,389,146,116,9,195,402,370,346,20,74,128,123,365,255,367,
+    167,118,168,116,173,103,94,345,175,47,90,365,371,330,169,115,139,134,39,141,
+    198,315,37,244,348,298,332,163,252,225,116,140,350,297,222,332,369,350,348,329,
+    80,313,335,144,28,325,173,307,310,171,307,322,75,169,166,175,341,344,374,128,
+    66,122,282,135,387,289,94,109,353,301,305,360,48,387,364,155,344,116,336,73,
+    119,344,143,297,176,131,318,402,68,261,62,144,403,346,350,256,106,113,361,179,
+    321,394,246,118,33,302,256,87,392,185,216,143,322,50,171,389,252,88,311,326,
+    247,59,371,221,371,185,97,201,140,180,187,366,54,266,127,371,222,298,16,47,
+    125,372,166,131,263,361,258,326,203,392,366,201,12,372,342,283,88,263,372,152,
+    133,297,372,289,185,403,75,256,135,202,247,346,371,372,298,298,278,128,395,135,
+    232,371,265,329,266,75,86,384,20,344,344,66,341,342,279,351,27,341,119,98,
+    361,123,357,201,152,196,367,361,370,123,203,299,198,376,131,213,111,38,80,240,
+    198,175,256,256,198,326,51,341,24,333,202,130,208,356,133,245,135,135,125,90,
+    349,365,366,386,300,138,37,129,320,371,14,205,300,332,299,369,143,73,333,137,
+    137,265,361,321,183,132,372,214,8,370,267,399,176,32,361,47,225,403,339,300,
+    194,367,165,162,90,135,356,184,341,96,347,367,253,139,361,321,372,317,372,175,
+    363,200,401,37,152,319,318,337,181,105,288,206,384,300,365,187,192,255,341,173,
+    23,345,346,342,259,380,300,313,2,262,312,374,355,366,277,131,313,317,221,318,
+    300,279,2,241,41,41,242,343,25,204,100,68,118,129,346,403,66,392,353,94,
+    138,126,100,403,322,324,315,14,137,130,112,366,99,183,392,361,297,194,367,47,
+    189,167,186,350,18,320,371,355,125,14,16,392,136,156,298,295,66,201,5,185,
+    125,125,370,32,86,369,112,194,263,371,249,133,360,34,256,346,133,185,184,183,
+    139,113,50,184,392,96,259,173,361,26,137,50,47,333,238,324,364,346,201,321,
+    208,295,116,175,167,337,38,97,90,357,365,194,403,195,150,188,246,302,387,387,
+    399,356,128,132,135,361,306,177,54,325,362,136,50,367,123,359,302,109,367,350,
+    404,163,356,175,392,341,247,371,137,253,60,130,138,345,239,133,241,341,313,258,
+    258,346,185,346,359,78,129,205,282,313,169,390,50,365,389,339,294,320,34,359,
+    162,364,296,332,395,284,120,14,294,32,32,302,330,255,179,341,289,289,37,141,
+    37,126,137,169,35,97,55,123,310,302,246,116,374,181,123,94,118,119,348,135,
+    294,346,371,185,167,227,371,179,202,379,60,128,380,351,371,173,300,358,180,339,
+    403,369,130,24,175,68,364,5,128,390,32,194,59,59,365,315,247,143,318,262,
+    133,402,175,229,113,139,89,289,133,315,130,41,318,311,71,289,395,166,18,175,
+    201,300,302,201,227,367,367,200,139,256,14,133,118,279,54,344,322,366,366,77,
+    49,132,140,167,146,2,255,402,341,18,106,258,367,18,164,88,25,197,135,341,
+    188,377,189,173,370,362,185,310,392,367,75,340,130,351,241,298,16,32,208,181,
+    135,255,289,37,106,18,132,348,185,262,116,367,277,173,140,350,367,315,341,351,
+    127,358,395,183,166,77,86,125,166,302,180,367,201,133,175,135,367,89,179,365,
+    135,373,32,58,271,135,167,86,306,372,402,90,297,171,167,50,266,369,258,85,
+    109,268,361,118,367,284,377,186,361,173,201,294,320,60,137,32,367,118,6,395,
+    167,167,215,339,186,359,348,361,97,361,371,132,16,204,110,65,130,350,350,130,
+    123,180,379,398,142,384,351,38,402,376,376,24,362,256,393,89,228,141,342,253,
+    365,185,46,247,148,241,361,148,242,210,34,177,111,150,78,110,352,392,110,110,
+    341,259,15,57,347,391,395,152,388,94,6,351,79,180,402,357,143,320,243,141,
+    243,319,319,19,362,330,116,395,384,68,341,300,175,38,129,394,125,309,167,329,
+    137,356,122,267,178,128,362,355,355,348,366,345,394,363,300,341,330,204,376,148,
+    123,169,347,395,357,391,253,361,130,110,35,130,151,36,86,296,364,130,0,321,
+    139,273,178,346,94,141,351,255,329,402,329,339,115,246,168,361,356,90,38,277,
+    125,346,302,119,359,157,144,350,346,361,116,399,146,66,351,133,345,361,189,116,
+    361,128,199,143,61,11,89,179,163,131,335,346,54,345,123,34,94,344,137,50,
+    90,252,264,283,346,264,352,34,361,361,365,145,371,98,270,247,354,340,299,33,
+    296,354,222,103,341,36,119,162,393,76,347,130,90,175,357,353,129,137,135,14,
+    367,395,341,333,294,346,232,232,32,361,141,141,371,198,292,341,384,352,264,278,
+    198,128,356,388,341,86,265,315,362,178,395,138,99,107,10,125,374,347,300,176,
+    15,105,334,321,34,294,82,20,129,351,346,345,346,374,119,119,346,374,354,213,
+    294,264,363,359,201,175,367,345,277,104,361,259,181,346,14,16,50,185,135,302,
+    365,184,246,315,365,324,145,404,374,125,365,392,5,341,181,116,238,273,141,194,
+    163,295,53,383,135,346,346,346,365,350,38,129,32,364,320,270,361,348,260,146,
+    65,78,298,137,89,310,177,371,179,330,296,89,86,361,359,167,198,321,365,140,
+    199,140,137,367,371,365,358,167,319,379,24,315,346,268,50,175,130,395,351,180,
+    341,365,351,388,130,57,229,167,279,348,151,359,139,40,61,363,119,167,16,277,
+    173,208,10,283,189,165,5,359,161,309,177,173,185,145,177,361,346,351,183,364,
+    24,271,373,167,50,145,39,109,143,35,201,321,167,395,167,180,53,371,388,388,
+    239,391,243,37,372,2,341,116,145,145,94,364,6,70,364,363,406,306,84,241,
+    45,152,378,70,259,369,259,248,8,241,34,135,240,75,45,371,384,15,70,9,
+    20,45,370,370,75,360,37,228,228,251,142,209,318,210,169,274,195,87,196,210,
+    98,345,361,100,11,310,135,106,370,100,299,210,66,258,267,267,403,323,346,195,
+    154,258,345,106,346,173,173,254,133,98,392,12,267,47,75,143,134,135,90,248,
+    152,143,151,41,346,11,188,138,192,286,341,196,78,330,260,136,346,173,75,178,
+    240,249,20,346,40,341,162,40,267,267,6,86,265,133,23,402,3,100,399,110,
+    192,370,150,20,124,360,366,128,399,374,161,228,66,263,393,210,11,247,143,365,
+    299,243,347,336,125,180,88,247,221,363,370,105,358,143,59,20,157,348,227,128,
+    121,137,119,388,133,359,294,318,277,301,330,169,75,347,300,159,391,371,316,371,
+    14,195,346,144,173,347,366,314,168,11,392,361,292,173,116,348,140,240,88,350,
+    7,256,221,16,366,166,178,135,259,163,361,113,404,201,33,365,392,391,360,200,
+    23,52,297,178,68,188,125,404,111,341,90,344,372,353,395,196,341,44,348,333,
+    360,216,351,134,124,15,370,370,198,30,363,316,20,206,126,372,312,192,372,58,
+    371,300,118,259,365,390,25,118,194,140,136,208,387,32,5,5,118,54,12,145,
+    14,14,129,255,179,356,330,363,177,309,350,301,78,137,100,144,75,130,159,348,
+    351,318,348,359,227,251,132,230,200,178,216,110,301,185,318,348,201,271,128,216,
+    188,348,256,145,357,203,403,189,185,371,313,340,265,95,71,170,6,133,123,66,
+    44,97,142,371,256,371,33,192,104,345,94,342,138,360,18,15,9,373,145,199,
+    145,339,135,198,59,251,341,128,348,259,180,58,365,3,252,68,94,357,346,20,
+    49,105,134,296,253,49,152,270,299,298,365,406,134,205,294,181,14,390,390,145,
+    85,183,139,137,135,173,110,348,394,104,361,355,362,356,188,313,395,262,366,359,
+    10,84,349,363,347,117,111,39,356,8,244,169,60,367,130,342,80,37,66,345,
+    345,37,146,198,11,329,348,41,116,357,361,265,357,168,173,355,94,181,360,346,
+    180,173,139,175,317,317,90,339,68,251,386,289,205,371,143,36,166,342,299,341,
+    326,41,410,13,37,125,256,80,163,33,256,13,339,185,50,109,361,69,11,179,
+    262,247,239,268,402,262,85,139,264,75,138,131,371,196,198,47,357,325,353,58,
+    278,205,135,341,280,128,351,44,135,398,33,175,267,347,76,372,360,216,125,367,
+    371,129,281,289,181,300,277,317,363,342,345,390,139,367,192,322,181,321,173,168,
+    111,390,259,56,145,388,363,2,18,302,33,163,398,50,139,365,54,50,256,173,
+    367,317,265,357,5,175,199,387,366,126,367,341,185,345,65,355,383,359,266,60,
+    179,177,264,313,129,111,255,140,86,139,181,133,130,140,2,14,24,266,379,60,
+    137,112,320,130,128,289,315,326,41,371,139,185,18,302,342,408,167,346,403,357,
+    280,344,96,171,75,173,392,278,173,377,264,325,111,315,165,183,185,173,377,167,
+    367,201,349,264,109,61,377,128,107,20,70,20,125,392,249,8,271,173,337,0,
+    349,258,8,245,87,59,343,238,0,0,337,125,180,365,252,49,7,144,33,38,
+    0,60,200,21,400,252,15,394,390,0,49,367,256,348,184,66,238,200,398,13,
+    179,383,345,247,59,343,367,361,97,58,298,326,326,116,33,299,263,298,32,32,
+    281,299,313,298,369,304,185,94,369,13,90,227,326,370,134,298,384,68,94,214,
+    68,252,329,126,329,390,376,200,14,256,181,359,181,33,210,374,86,94,103,326,
+    138,138,267,341,94,326,210,79,241,136,337,57,214,181,12,390,41,210,210,27,
+    326,96,14,57,392,80,256,190,241,365,86,126,297,371,210,146,365,181,297,70,
+    40,126,60,400,133,339,136,37,33,335,171,133,30,181,70,333,179,136,136,40,
+    247,70,70,247,138,59,302,302,392,365,217,213,71,14,138,177,98,100,142,394,
+    347,294,356,236,173,362,36,370,6,138,145,256,347,54,14,365,173,404,45,90,
+    395,243,247,97,152,49,358,392,59,390,85,392,324,143,133,88,143,294,134,357,
+    384,19,225,391,369,140,267,324,330,365,138,341,130,320,362,38,392,121,360,198,
+    74,140,350,330,332,195,247,350,314,94,173,392,56,79,345,292,169,301,128,348,
+    365,13,387,109,320,88,192,179,38,133,326,3,38,14,14,205,106,77,80,341,
+    371,54,360,395,52,59,298,393,38,371,124,90,165,362,36,332,371,113,342,128,
+    157,134,366,365,184,288,145,38,346,109,365,342,133,45,8,130,181,31,301,236,
+    68,57,17,320,387,16,298,52,188,365,404,40,387,385,312,289,268,69,184,184,
+    208,262,366,367,129,94,177,183,260,181,169,348,88,59,366,119,2,8,348,109,
+    111,229,371,341,365,369,247,171,173,302,59,179,68,179,166,17,133,38,362,357,
+    138,391,197,173,132,166,133,68,357,367,366,266,369,320,68,188,186,186,20,20,
+    111,6,85,65,85,7,7,261,133,379,379,196,62,239,138,129,111,49,180,99,
+    208,133,137,246,99,2,80,118,116,14,339,40,258,346,2,350,118,129,118,380,
+    54,118,350,364,253,118,137,2,353,129,173,249,119,137,247,97,243,394,146,265,
+    55,268,384,106,146,146,394,384,106,388,75,205,256,367,371,11,388,393,245,119,
+    367,119,365,20,339,119,4,386,361,135,119,371,162,86,96,61,241,94,265,299,
+    61,185,386,200,173,139,356,135,241,109,3,185,356,394,60,3,106,173,210,71,
+    97,356,195,340,392,256,372,326,349,78,352,346,241,90,78,205,206,299,300,374,
+    202,241,87,203,59,198,196,149,348,156,300,362,391,363,298,132,57,390,161,143,
+    298,365,299,198,208,395,390,390,202,300,372,70,221,403,403,34,384,357,19,201,
+    183,315,330,201,70,66,217,206,357,38,161,144,209,390,327,362,361,208,393,208,
+    389,391,198,317,296,116,128,66,37,56,144,80,194,348,346,163,166,135,294,326,
+    112,339,172,300,264,178,360,140,391,173,166,315,144,307,315,75,14,247,210,131,
+    221,185,365,138,23,394,371,131,192,347,353,130,112,376,47,135,198,75,124,357,
+    326,162,99,281,196,356,85,344,203,40,162,201,343,156,60,36,152,312,347,264,
+    208,206,194,307,380,387,365,69,156,208,308,50,184,38,194,249,37,111,200,128,
+    282,250,346,260,255,395,65,298,308,177,35,348,149,364,356,330,209,179,111,135,
+    364,2,130,386,135,106,388,266,198,40,288,227,359,363,132,200,202,251,202,173,
+    161,145,357,202,132,185,200,183,109,194,346,41,321,149,395,196,139,179,371,309,
+    380,145,300,365,298,392,124,298,367,143,394,137,56,76,2,137,383,373,6,300,
+    71,256,133,403,97,345,389,157,98,346,86,161,60,192,292,59,145,173,94,205,
+    80,132,150,392,256,149,138,18,80,360,247,389,361,315,398,35,78,241,361,139,
+    90,85,208,384,143,371,152,336,336,66,385,390,80,94,210,395,165,15,231,252,
+    246,180,243,170,253,20,253,298,375,2,173,183,330,369,173,161,41,154,267,395,
+    161,111,80,216,257,185,341,2,100,348,353,361,73,246,346,169,123,309,347,260,
+    263,341,260,365,154,350,268,32,168,123,371,350,347,195,188,369,35,35,344,181,
+    367,195,268,361,292,163,371,38,126,185,36,135,236,311,402,154,246,361,400,155,
+    37,256,404,263,179,146,20,71,205,69,135,119,185,2,315,268,172,11,366,77,
+    345,256,187,339,68,216,11,256,36,280,361,70,71,75,336,138,367,15,152,14,
+    341,309,390,76,347,60,325,216,246,135,66,320,30,326,256,78,90,357,268,268,
+    192,104,222,313,80,49,181,310,321,9,126,247,341,287,171,56,326,347,346,175,
+    241,341,374,77,389,152,165,398,263,112,398,32,256,5,246,181,185,149,45,36,
+    366,171,16,256,208,256,54,404,263,48,187,133,294,169,266,383,65,135,346,179,
+    71,320,129,241,375,260,66,173,135,137,346,387,260,78,135,371,400,119,152,380,
+    171,152,41,364,268,60,365,136,247,247,371,251,80,2,152,135,371,280,200,243,
+    49,20,362,192,22,348,161,171,171,392,173,173,86,268,243,367,173,183,183,208,
+    20,306,109,167,377,361,300,300,173,275,297,373,310,256,318,192,351,363,348,256,
+    256,392,13,77,393,274,365,300,370,392,327,94,94,201,406,392,314,198,410,266,
+    125,395,298,315,49,31,201,189,371,349,345,327,249,395,111,347,392,133,99,390,
+    99,307,139,298,96,163,66,61,132,322,256,106,109,408,180,185,19,139,61,392,
+    185,32,11,389,130,370,346,366,403,132,390,94,372,345,348,362,392,365,198,310,
+    66,11,402,390,369,133,99,321,310,192,318,94,357,256,371,346,133,310,32,59,
+    111,315,173,229,201,61,173,271,373,325,377,171,278,371,371,173,351,262,119,332,
+    355,310,274,332,403,30,97,365,348,19,222,265,265,393,89,118,374,152,203,392,
+    140,14,392,371,201,157,8,247,221,173,370,406,247,20,180,208,37,222,262,362,
+    410,392,392,302,143,403,132,133,37,330,392,132,119,366,403,392,138,274,75,365,
+    395,130,229,94,346,151,168,94,359,307,189,163,97,140,325,37,332,296,307,360,
+    187,185,106,410,274,401,9,7,133,392,392,163,172,246,152,19,40,408,371,313,
+    190,349,365,346,15,133,94,247,234,138,393,404,356,37,61,342,348,403,371,133,
+    356,390,392,61,134,133,99,99,106,277,315,277,133,150,210,24,198,392,133,185,
+    313,133,367,342,265,289,119,365,129,261,133,315,350,255,137,402,393,408,189,315,
+    229,289,130,271,234,371,251,133,334,342,37,132,161,189,16,289,271,402,173,53,
+    358,337,142,264,346,264,155,371,298,140,363,44,401,332,169,261,385,363,15,10,
+    363,19,337,395,137,260,69,345,111,363,392,45,363,327,137,45,141,350,37,156,
+    53,344,59,157,152,402,356,313,109,162,74,402,359,344,337,360,371,143,264,363,
+    363,327,34,371,326,69,143,177,346,345,162,45,388,160,160,183,37,54,249,379,
+    53,260,264,74,379,183,261,173,41,300,94,258,41,123,256,118,299,89,302,203,
+    266,386,395,180,183,19,140,140,387,7,310,146,123,330,311,140,69,365,302,140,
+    266,138,252,76,296,398,29,65,55,338,140,149,140,395,395,170,246,371,38,97,
+    195,395,339,75,133,350,6,314,133,262,388,316,360,400,372,125,117,350,28,14,
+    14,135,365,73,294,299,57,66,395,212,38,106,173,261,205,10,327,310,94,27,
+    12,244,57,403,66,180,380,231,94,105,86,134,97,86,300,196,253,325,135,264,
+    183,205,15,188,111,266,38,366,363,348,14,264,164,65,350,139,267,316,279,85,
+    161,395,330,133,57,117,27,393,156,166,14,293,60,391,27,94,374,332,239,173,
+    168,143,109,135,116,330,347,392,37,314,300,395,410,350,296,329,27,361,99,159,
+    97,40,161,98,374,237,258,350,135,254,166,405,14,14,14,100,319,108,371,135,
+    61,106,38,391,263,292,394,185,20,133,179,314,146,94,384,106,155,258,258,146,
+    46,109,372,27,406,20,380,261,336,188,59,350,283,135,357,15,316,349,348,252,
+    390,353,125,365,395,373,47,189,345,73,309,133,138,129,353,198,86,44,398,248,
+    90,395,129,261,124,265,203,258,106,162,300,184,374,119,321,373,40,99,88,283,
+    391,105,225,258,350,53,183,246,75,173,14,402,41,293,38,395,259,183,167,135,
+    21,173,130,14,66,50,361,246,24,398,247,249,74,371,204,333,380,293,111,365,
+    125,32,156,52,252,379,133,111,313,184,27,185,222,317,53,69,317,170,76,176,
+    350,20,201,293,60,177,59,68,94,135,205,162,58,137,65,129,316,169,377,350,
+    185,300,377,256,240,256,240,97,143,75,185,361,20,60,285,396,183,258,175,21,
+    394,166,300,167,162,371,373,118,390,319,325,225,40,133,365,256,324,398,394,86,
+    312,394,258,402,324,185,185,135,336,367,371,166,183,261,175,167,258,373,393,266,
+    175,15,76,407,173,310,188,367,373,402,371,201,66,86,298,389,298,235,119,171,
+    348,403,221,55,387,258,385,14,8,345,292,150,278,89,14,54,366,389,38,319,
+    125,6,173,97,143,253,208,52,386,394,173,313,327,173,346,313,123,330,403,27,
+    373,394,179,399,7,169,89,84,266,119,176,348,94,176,24,140,173,373,185,143,
+    256,54,7,387,179,404,140,113,126,286,286,321,15,278,202,124,356,404,125,135,
+    377,49,173,351,94,234,11,106,355,99,321,265,134,26,399,321,201,286,89,379,
+    150,136,208,286,286,234,346,176,136,159,20,128,302,404,348,234,333,225,173,410,
+    66,225,327,167,201,310,142,346,104,391,142,370,133,30,394,359,373,123,371,119,
+    339,274,342,342,265,212,403,331,228,93,133,302,47,247,390,292,123,392,133,89,
+    374,274,59,139,313,87,317,54,142,375,6,139,94,392,49,403,40,123,375,171,
+    346,94,351,298,20,395,266,180,395,296,97,362,94,336,390,58,41,300,393,348,
+    406,141,8,266,208,302,408,161,140,274,117,351,138,395,40,107,7,145,161,125,
+    49,128,103,322,138,157,137,267,96,188,357,13,348,94,101,73,277,327,366,171,
+    351,144,356,96,70,330,310,136,349,130,145,392,135,144,38,202,390,189,37,265,
+    302,9,330,350,128,262,103,355,325,331,351,123,346,94,329,315,77,163,94,140,
+    125,392,361,141,90,133,356,274,404,36,79,173,189,176,40,267,296,256,256,401,
+    256,339,258,348,301,341,256,322,339,98,340,13,400,23,113,54,187,181,256,386,
+    14,48,180,202,256,261,326,404,112,405,346,403,353,176,139,88,281,205,371,404,
+    86,189,356,367,295,256,356,349,135,152,348,280,202,133,76,39,66,205,203,372,
+    351,10,310,265,15,128,103,50,202,341,94,341,331,105,203,351,175,404,15,374,
+    366,325,107,392,374,37,32,58,347,372,404,356,367,341,103,357,367,139,365,400,
+    221,9,106,241,394,135,49,267,306,374,347,54,346,277,322,94,374,36,99,279,
+    125,376,324,348,313,390,404,322,129,23,14,90,52,173,317,361,346,404,171,144,
+    258,194,392,189,210,249,175,201,357,404,133,294,315,86,189,13,365,288,209,363,
+    259,131,348,133,292,355,270,357,315,260,383,410,392,294,286,179,371,86,177,48,
+    409,135,272,32,281,355,130,126,407,346,259,374,57,299,130,346,289,135,136,128,
+    379,50,351,137,14,59,365,229,315,365,293,356,133,18,258,167,255,359,407,256,
+    246,363,208,171,351,407,161,370,356,171,348,32,137,185,32,367,23,271,348,408,
+    407,188,173,61,167,171,175,310,142,371,123,394,348,100,373,133,339,161,133,274,
+    341,374,123,47,247,292,98,212,274,404,187,89,392,342,87,395,390,228,302,348,
+    97,351,94,175,406,298,346,392,393,394,8,94,41,296,365,140,58,9,277,138,
+    157,272,70,117,130,101,357,136,188,145,137,330,103,350,144,355,346,315,322,133,
+    325,133,356,180,367,356,256,88,48,295,112,299,341,202,301,13,40,322,181,267,
+    404,386,339,189,400,403,152,349,135,202,167,325,203,133,374,130,310,79,76,15,
+    348,105,400,128,66,189,15,205,372,139,94,279,390,90,54,99,32,173,365,135,
+    18,249,194,171,367,317,209,288,351,177,294,383,136,258,260,128,137,407,93,351,
+    98,93,268,93,256,20,252,349,388,98,367,367,263,347,109,409,320,25,256,343,
+    367,171,320,185,109,340,340,98,340,116,188,188,94,201,85,106,395,143,196,106,
+    205,98,6,107,325,144,94,298,361,388,408,107,402,371,392,3,85,167,302,310,
+    247,192,181,6,85,173,34,341,14,133,383,39,181,133,144,201,388,188,247,133,
+    133,186,362,201,259,57,198,362,370,370,89,6,99,362,106,259,378,99,180,365,
+    395,66,355,259,365,348,277,269,269,259,128,317,348,365,362,259,258,371,103,138,
+    321,372,346,86,294,89,294,175,171,103,230,259,32,371,104,365,39,343,89,123,
+    38,38,54,94,347,12,365,165,365,247,180,181,392,266,346,351,349,346,346,152,
+    260,130,130,350,292,123,136,66,54,88,61,292,38,395,135,357,38,248,404,339,
+    130,124,119,119,116,5,249,365,175,124,5,179,245,260,5,86,365,130,357,61,
+    363,169,169,151,196,389,256,105,105,105,70,70,84,303,280,213,213,76,171,329,
+    403,103,34,118,374,6,247,365,310,266,134,143,132,41,169,187,133,321,238,184,
+    230,136,242,384,184,133,169,132,370,208,127,84,365,71,364,57,311,262,374,38,
+    59,59,123,103,392,241,225,59,390,35,180,391,370,337,177,183,392,227,327,84,
+    360,328,107,356,175,118,299,178,251,140,143,14,66,113,342,356,252,50,71,221,
+    329,143,50,162,175,162,50,175,343,162,175,175,50,5,299,311,329,162,225,392,
+    59,227,261,221,329,329,183,371,371,388,310,313,365,313,310,388,388,278,365,170,
+    133,265,153,26,100,20,128,129,365,274,350,279,394,372,75,98,277,97,30,344,
+    33,106,392,116,94,88,89,244,242,135,87,401,370,212,4,153,270,104,371,342,
+    363,256,247,258,346,346,88,153,140,319,298,393,387,351,298,341,394,70,59,88,
+    6,20,266,326,11,107,319,403,157,392,221,252,403,94,242,390,348,410,244,134,
+    299,392,10,210,266,125,152,38,366,356,362,183,73,147,185,140,231,361,242,158,
+    365,110,115,100,73,38,137,354,354,84,3,122,248,220,403,111,37,327,392,54,
+    198,351,54,351,193,193,133,351,226,159,284,378,256,216,201,229,186,339,20,342,
+    339,355,137,140,370,122,56,178,294,329,198,47,298,258,62,144,52,355,352,336,
+    243,37,218,255,74,336,224,216,247,106,188,173,175,387,54,138,176,307,247,16,
+    187,248,171,162,46,59,326,218,140,213,165,364,361,274,298,48,94,94,143,88,
+    259,339,73,247,113,404,71,344,198,221,398,38,51,188,238,66,3,353,216,302,
+    305,214,374,393,278,80,285,332,363,135,341,137,371,134,76,14,33,94,348,221,
+    202,337,324,334,9,258,189,337,312,321,313,400,100,365,20,177,133,247,351,99,
+    189,18,238,33,185,113,242,43,16,136,94,321,208,346,398,189,137,367,189,392,
+    358,47,179,330,246,221,43,177,54,111,350,324,86,392,137,294,125,54,282,349,
+    315,89,367,294,396,59,159,229,335,175,14,369,145,41,365,144,165,175,288,335,
+    106,256,54,18,359,216,344,378,348,16,353,160,165,361,185,132,375,188,266,378,
+    186,221,375,36,258,344,110,378,179,110,403,137,225,40,133,99,40,202,225,392,
+    392,100,135,70,392,355,319,390,142,348,371,30,363,371,39,346,346,142,371,371,
+    353,143,142,352,297,297,297,142,300,320,302,300,326,320,255,255,109,126,326,44,
+    308,347,345,394,61,44,294,365,86,239,319,86,8,44,117,87,8,14,185,393,
+    135,25,180,395,380,79,20,348,100,44,347,185,264,242,346,158,94,379,90,173,
+    296,371,168,329,371,341,20,200,222,143,129,301,152,15,210,70,74,9,30,365,
+    312,25,26,184,58,358,363,39,65,60,259,185,365,133,135,132,200,256,185,185,
+    32,306,102,176,135,135,289,361,94,252,361,361,26,26,365,170,329,137,2,213,
+    327,137,138,246,339,365,31,202,201,97,258,371,371,296,264,75,125,256,195,403,
+    130,315,392,349,247,94,335,341,345,392,256,294,342,258,274,94,156,138,185,356,
+    133,262,256,361,89,6,281,352,133,126,126,87,345,145,105,392,374,262,5,41,
+    196,360,88,275,117,50,366,370,15,365,261,341,173,247,80,348,33,25,395,313,
+    325,372,270,180,319,296,66,203,263,173,369,152,210,11,10,105,205,365,365,143,
+    250,283,157,227,221,20,19,294,355,363,348,12,123,367,384,73,143,70,225,97,
+    125,252,198,94,299,106,14,341,94,402,196,86,134,196,196,6,49,208,403,66,
+    38,133,140,183,50,224,372,358,367,264,100,206,173,277,366,102,258,31,36,371,
+    118,403,178,345,133,111,49,135,49,105,110,195,30,137,137,94,371,395,403,136,
+    130,366,30,85,277,279,39,195,330,393,258,395,359,128,94,267,96,57,140,353,
+    44,26,140,84,3,260,38,274,135,325,129,252,173,139,169,302,399,57,134,272,
+    14,380,260,130,133,60,371,277,131,353,188,367,359,139,316,366,193,123,394,363,
+    75,341,173,74,94,274,366,119,14,21,374,66,332,315,315,37,36,345,17,346,
+    103,173,255,395,208,173,399,410,336,265,292,317,36,246,143,198,200,353,140,35,
+    298,146,361,329,370,56,109,116,370,56,134,340,313,228,296,348,168,94,80,208,
+    342,138,214,210,149,166,175,300,344,332,348,132,370,367,367,104,47,195,195,49,
+    339,140,66,266,73,135,405,106,165,185,143,341,146,225,163,119,255,376,99,113,
+    94,187,33,40,311,46,386,199,23,6,173,332,20,116,10,262,144,346,262,66,
+    138,255,60,139,260,319,103,126,106,180,88,262,3,340,13,394,361,143,135,179,
+    320,302,326,61,125,256,119,54,322,47,14,33,128,88,166,256,200,252,341,59,
+    292,128,361,365,327,256,339,27,213,390,336,142,328,188,14,365,200,20,243,71,
+    367,367,367,350,284,265,152,349,339,371,371,94,175,357,357,214,27,344,47,350,
+    371,15,196,3,80,188,367,164,164,136,202,410,410,406,10,278,346,364,3,266,
+    135,94,189,140,245,90,123,123,124,361,332,389,403,349,274,100,257,263,201,129,
+    298,255,96,73,394,135,341,20,341,239,133,125,378,134,76,363,315,50,267,341,
+    390,162,329,131,346,300,256,167,404,363,372,198,374,302,66,398,109,270,358,32,
+    148,162,126,136,184,341,240,370,312,366,300,47,300,374,390,168,279,200,173,268,
+    314,372,173,143,346,9,41,356,332,181,132,68,258,406,253,56,372,41,371,159,
+    241,255,255,212,309,346,89,374,391,135,133,283,25,82,201,118,316,390,206,312,
+    356,181,346,106,168,277,343,96,56,300,321,188,279,317,357,11,363,111,14,404,
+    112,410,327,27,244,167,59,133,173,298,168,371,180,367,208,69,327,196,330,41,
+    246,3,175,50,346,252,265,139,47,138,341,334,26,371,365,403,177,14,185,356,
+    21,387,171,259,194,361,180,133,16,112,116,66,313,185,297,295,66,204,359,194,
+    20,66,56,389,298,357,341,125,5,201,184,52,393,23,253,136,201,50,224,130,
+    144,366,135,222,302,366,113,36,125,292,156,258,192,378,380,259,74,175,179,156,
+    2,14,173,341,133,258,299,86,200,238,32,68,359,137,281,281,171,371,260,41,
+    126,135,193,374,10,370,266,185,272,130,80,325,88,145,408,85,279,89,162,308,
+    281,360,356,94,145,60,345,73,310,350,346,183,342,296,256,135,374,316,180,371,
+    347,343,133,123,310,229,171,357,374,371,346,118,10,118,2,341,130,130,133,49,
+    349,339,204,365,172,136,24,298,259,175,152,372,57,325,321,358,14,386,316,348,
+    86,71,351,106,351,302,135,118,123,284,352,359,363,7,312,302,359,77,251,341,
+    227,40,193,279,249,319,133,379,36,390,84,221,367,99,50,350,256,85,135,356,
+    162,133,15,69,201,167,139,25,203,264,261,348,177,238,348,313,189,365,356,351,
+    173,365,165,171,137,66,392,11,324,363,208,128,16,86,312,320,334,264,260,341,
+    181,130,238,99,374,10,173,302,41,2,179,379,357,262,166,132,336,345,281,281,
+    256,122,185,313,334,200,374,252,371,359,133,141,357,208,265,313,141,90,225,20,
+    271,365,348,371,143,175,175,366,259,367,183,331,341,373,180,266,363,86,198,116,
+    162,167,133,60,194,171,171,130,90,392,341,162,386,127,173,133,201,171,127,188,
+    133,162,185,135,284,324,171,267,350,365,186,199,17,125,125,185,236,189,310,350,
+    258,41,125,356,56,94,356,356,185,125,371,118,137,143,113,10,361,386,386,162,
+    18,346,302,39,265,69,133,265,71,300,347,145,389,297,371,116,403,123,130,200,
+    100,315,347,31,300,365,192,349,87,80,6,38,258,342,342,281,9,247,373,373,
+    146,256,330,366,256,24,372,145,130,262,256,393,360,118,210,340,89,89,117,104,
+    379,94,270,138,94,38,74,10,348,221,323,265,370,384,252,38,370,119,116,143,
+    173,94,270,384,105,247,247,348,395,69,17,19,106,386,266,297,328,180,106,59,
+    106,367,173,37,266,209,100,49,130,130,195,94,362,337,178,395,365,348,164,137,
+    173,365,252,256,114,297,365,340,208,264,261,111,264,392,194,169,389,134,216,310,
+    256,353,138,265,296,369,134,334,35,7,80,116,302,357,90,298,298,94,348,389,
+    345,94,173,168,14,41,372,370,138,59,361,329,68,334,130,344,392,311,88,143,
+    201,256,256,371,146,165,200,259,310,346,187,173,70,327,322,163,116,116,371,9,
+    88,247,341,78,365,372,317,267,258,281,221,263,341,176,113,339,73,80,8,66,
+    340,24,362,367,113,32,71,165,152,138,351,329,196,356,202,371,138,300,357,129,
+    361,15,278,341,94,372,198,341,94,279,351,370,265,196,347,367,300,39,321,395,
+    404,325,94,372,162,200,165,75,125,265,70,173,344,374,266,214,184,47,277,367,
+    136,8,168,242,310,346,49,346,372,343,175,312,8,277,277,133,345,355,116,262,
+    365,14,126,321,365,75,213,119,125,111,192,206,365,342,367,323,393,25,288,256,
+    194,327,295,300,26,38,66,5,185,341,392,321,36,249,266,247,371,135,188,184,
+    262,393,366,136,304,342,350,339,389,389,192,192,113,181,196,346,50,173,194,350,
+    33,387,195,349,208,408,310,265,323,392,246,246,137,266,17,177,241,111,346,133,
+    398,129,88,169,145,145,130,366,32,137,294,216,350,345,39,359,310,41,37,60,
+    173,351,294,365,140,57,32,256,49,349,297,188,262,367,31,173,381,357,175,395,
+    380,351,195,351,256,277,135,200,118,279,132,402,138,251,119,204,86,171,138,165,
+    205,173,47,173,265,225,185,75,350,395,183,173,183,90,364,13,215,106,144,367,
+    302,346,24,266,267,75,24,194,266,138,395,402,358,129,228,244,235,352,393,193,
+    84,147,204,346,353,361,149,372,266,180,357,302,348,330,349,138,348,360,125,341,
+    61,39,341,61,401,122,266,365,365,21,97,371,16,30,365,294,36,94,112,89,
+    304,138,212,393,59,365,393,393,138,392,351,270,392,274,262,139,146,372,198,31,
+    5,224,130,270,134,336,180,58,10,243,363,410,14,296,320,143,119,358,355,390,
+    365,239,20,66,337,94,112,392,392,270,241,365,196,336,212,105,357,389,266,11,
+    112,346,221,20,20,94,38,38,157,274,136,134,135,20,138,84,100,279,395,111,
+    366,23,178,147,353,399,60,356,163,153,224,302,134,163,37,173,144,298,254,100,
+    365,371,390,181,265,269,133,365,21,399,307,292,269,173,175,175,157,135,93,32,
+    14,163,322,372,180,38,33,40,79,16,176,295,244,244,88,372,188,113,361,75,
+    325,392,143,365,133,392,107,153,256,325,325,94,39,351,15,70,163,76,355,355,
+    119,372,10,10,94,371,333,361,130,11,41,189,243,59,374,318,105,57,127,277,
+    372,279,213,141,317,8,334,38,287,224,367,138,258,127,157,175,167,173,389,300,
+    189,365,70,351,348,341,16,26,133,259,290,10,349,14,94,135,398,135,54,133,
+    59,375,86,20,349,352,17,272,194,167,5,380,111,26,315,229,32,175,14,139,
+    60,302,320,14,167,255,279,392,76,302,337,300,7,351,20,36,166,183,346,348,
+    167,389,58,143,377,300,135,241,365,167,360,346,346,363,90,320,94,91,94,6,
+    119,133,133,135,109,15,361,111,145,248,196,201,201,204,300,310,32,188,145,201,
+    327,175,363,392,146,346,294,341,346,326,371,167,80,75,262,242,133,206,367,105,
+    266,386,139,109,65,135,188,266,135,341,145,266,188,167,298,66,109,135,109,361,
+    111,201,300,32,167,145,133,346,66,326,371,105,139,266,137,265,139,52,145,392,
+    34,133,106,59,403,66,295,126,267,100,300,138,111,104,41,138,131,265,353,313,
+    221,133,185,392,384,14,353,125,295,104,392,358,41,346,365,173,145,346,361,346,
+    361,361,71,94,265,265,137,123,133,86,359,69,123,31,322,356,138,365,274,359,
+    366,294,256,336,133,359,366,80,89,360,363,311,298,366,352,145,350,217,36,370,
+    392,354,87,352,34,297,348,284,401,356,365,365,313,38,119,298,119,356,390,395,
+    391,105,403,403,386,106,94,135,70,180,66,362,173,216,241,394,97,365,143,363,
+    384,365,365,266,388,252,14,354,266,6,57,406,322,395,49,389,369,356,359,365,
+    129,119,300,30,350,300,121,30,105,111,267,130,138,126,96,349,341,298,394,330,
+    201,386,206,80,130,361,354,107,84,19,327,365,171,395,161,158,345,371,324,133,
+    392,274,52,168,80,161,81,300,329,59,11,32,370,154,260,262,303,3,371,350,
+    37,138,348,345,345,99,311,21,130,140,309,390,309,75,126,33,307,138,152,266,
+    50,350,315,340,348,88,38,318,365,221,366,69,247,402,32,36,401,133,256,320,
+    400,341,143,263,73,391,380,405,258,402,176,135,41,118,187,298,16,127,248,371,
+    70,356,248,300,357,300,131,126,80,393,66,351,94,255,329,135,256,371,403,398,
+    346,130,366,3,348,214,36,90,395,362,361,129,357,100,234,256,209,364,341,353,
+    324,394,294,135,253,162,129,132,100,367,201,350,201,346,259,36,358,325,313,9,
+    38,258,300,136,372,351,119,322,363,363,392,371,16,50,263,173,208,208,295,389,
+    207,135,380,138,175,184,24,238,112,346,402,5,5,139,389,365,125,136,194,34,
+    116,126,32,356,383,289,346,384,77,391,216,167,80,367,145,133,409,137,20,130,
+    398,345,382,384,300,260,320,382,255,299,357,379,320,60,315,348,133,137,140,386,
+    215,365,2,386,247,130,126,365,365,294,271,230,258,400,318,125,394,118,2,367,
+    135,371,135,130,75,389,357,377,171,298,341,32,173,365,15,389,361,80,40,341,
+    40,363,32,271,366,167,36,351,225,128,377,365,60,386,361,75,361,133,71,94,
+    274,133,138,123,322,271,294,256,336,359,365,359,133,274,136,130,238,143,360,217,
+    356,80,187,354,311,90,297,87,145,391,106,119,252,406,300,354,384,313,390,66,
+    394,49,266,388,14,365,365,161,171,300,107,300,133,130,37,395,298,126,59,105,
+    267,111,359,365,391,96,349,30,131,356,394,138,345,371,260,345,99,370,130,161,
+    309,311,81,263,395,405,234,75,402,88,152,341,371,307,298,69,32,176,401,315,
+    320,298,365,209,36,70,129,135,351,358,364,341,80,371,357,32,403,3,361,66,
+    201,248,356,208,60,313,351,363,9,300,258,201,139,194,389,135,207,320,382,260,
+    167,255,145,361,258,386,36,106,258,123,347,133,123,116,123,346,346,132,177,116,
+    75,183,74,136,256,300,173,65,339,14,302,348,90,392,392,361,361,300,41,130,
+    335,365,335,365,135,6,124,80,41,349,128,135,153,96,143,94,346,18,118,371,
+    395,134,89,346,20,342,128,18,66,404,89,365,392,10,31,3,247,212,247,105,
+    212,370,69,208,310,355,128,163,119,118,208,116,196,173,221,14,371,134,333,196,
+    247,346,80,143,208,41,320,128,145,11,390,372,94,23,104,323,365,117,339,251,
+    132,86,320,109,380,392,84,395,300,14,403,84,111,248,15,193,58,299,161,88,
+    328,365,38,196,119,14,185,179,130,96,248,403,134,356,381,137,96,378,135,367,
+    359,390,297,18,18,265,297,44,378,394,166,377,49,36,295,326,244,103,348,193,
+    135,315,94,320,50,50,392,133,387,75,139,354,47,374,10,376,166,90,25,133,
+    299,365,398,94,105,285,380,177,365,7,36,339,392,400,16,374,383,59,377,361,
+    255,294,339,367,139,97,348,378,14,75,302,361,295,357,183,97,378,11,390,94,
+    372,104,23,380,348,7,387,132,392,86,320,251,15,105,395,109,84,135,12,300,
+    328,111,161,58,196,88,119,365,381,392,134,130,403,179,185,378,403,96,139,265,
+    390,166,297,94,75,133,302,295,49,14,394,103,244,59,166,90,400,94,398,285,
+    380,361,377,374,383,294,367,97,38,346,297,214,330,346,37,119,37,389,347,321,
+    405,405,173,142,94,388,97,256,294,264,366,348,49,145,262,38,49,36,36,70,
+    143,34,66,346,386,145,373,266,133,38,41,107,358,403,327,79,178,97,317,52,
+    346,388,313,366,143,135,268,321,48,54,185,266,60,265,403,325,266,38,129,260,
+    260,137,379,325,84,377,377,406,239,10,157,152,78,145,94,36,135,87,392,318,
+    373,6,256,373,259,336,319,365,222,180,198,6,70,157,336,134,49,243,257,395,
+    143,68,392,94,241,143,294,20,221,143,173,102,365,133,79,348,137,79,395,267,
+    158,397,111,264,162,349,38,185,248,392,134,327,23,135,318,260,14,348,79,133,
+    143,133,302,332,41,140,225,350,21,358,55,210,302,176,369,137,40,260,209,318,
+    135,256,344,341,48,138,133,225,143,143,187,185,172,127,143,38,339,267,325,20,
+    406,261,365,52,404,23,404,246,392,391,68,392,371,79,78,44,369,393,66,384,
+    36,43,135,107,321,143,94,406,70,248,278,234,325,30,334,135,61,56,256,318,
+    259,222,68,325,133,225,241,181,377,14,39,185,177,52,321,58,313,346,162,133,
+    392,259,66,241,404,175,13,379,222,17,334,143,65,29,348,86,41,393,78,20,
+    52,52,145,145,179,318,260,145,255,177,78,53,161,379,57,14,14,395,143,41,
+    260,78,40,133,345,373,222,179,178,392,173,392,32,41,76,341,183,179,348,341,
+    407,167,351,271,284,225,318,266,138,53,56,346,162,145,179,298,104,59,89,266,
+    325,79,79,104,168,274,188,2,133,143,321,155,169,361,198,150,266,184,169,79,
+    392,361,325,61,367,371,35,360,111,146,341,373,352,58,357,86,274,294,161,302,
+    335,36,58,80,212,256,196,280,274,258,398,123,125,266,161,66,180,58,5,390,
+    86,161,362,246,11,106,106,243,395,277,80,6,394,392,363,152,365,392,300,252,
+    84,104,143,137,110,119,148,267,394,376,392,297,176,371,296,370,339,366,389,339,
+    94,263,394,221,172,389,386,176,403,130,340,48,113,149,365,246,258,112,222,252,
+    109,11,187,240,176,280,278,133,362,348,44,51,47,100,370,123,302,94,403,94,
+    342,12,386,371,342,322,106,390,347,372,185,137,34,398,341,131,358,389,137,386,
+    21,169,89,86,179,100,289,149,128,365,133,400,84,371,135,123,171,244,173,173,
+    185,179,35,360,111,357,58,274,398,80,187,280,123,106,152,185,394,392,365,125,
+    390,173,363,263,300,376,392,137,394,267,185,137,389,94,176,222,11,130,112,340,
+    176,48,403,51,94,133,342,302,244,372,347,222,185,389,179,352,106,49,49,247,
+    408,15,165,165,49,358,8,15,15,15,358,15,8,49,15,15,36,279,229,229,
+    32,48,48,365,275,15,15,300,279,177,57,32,97,258,371,371,256,359,365,113,
+    193,256,375,340,332,401,367,325,374,139,117,360,86,345,57,80,115,389,393,139,
+    372,341,175,38,35,221,327,392,365,141,134,36,58,84,66,253,395,70,380,322,
+    302,336,266,140,130,73,370,201,13,133,213,365,138,400,178,359,334,311,300,322,
+    242,124,221,78,141,357,359,21,370,350,265,331,395,265,66,66,332,140,325,74,
+    365,389,330,110,345,300,37,313,379,269,90,175,317,130,173,106,166,12,56,145,
+    13,128,58,185,370,394,139,371,48,162,341,325,365,57,372,188,14,234,371,60,
+    315,78,315,361,44,38,325,371,300,390,370,374,80,15,113,80,347,129,265,61,
+    57,341,214,365,105,363,40,181,359,318,66,38,372,313,318,258,192,363,109,387,
+    5,300,22,38,313,379,389,78,66,184,38,56,179,409,272,258,357,371,365,345,
+    177,143,300,14,363,193,351,315,115,386,324,84,203,15,15,165,173,372,363,188,
+    173,365,329,65,256,369,294,116,371,195,279,264,339,161,94,150,18,87,353,212,
+    352,298,9,372,55,132,351,9,345,143,370,116,319,265,14,247,19,296,11,337,
+    66,405,364,179,161,111,395,300,157,371,96,119,261,392,133,128,124,353,137,346,
+    111,234,168,134,159,391,168,374,361,37,74,346,189,94,345,94,99,118,168,134,
+    103,146,367,20,346,11,173,374,21,350,256,247,263,113,394,320,405,252,166,221,
+    36,370,21,349,59,143,369,260,365,74,361,198,283,11,80,302,144,371,374,124,
+    162,349,349,312,321,206,346,279,41,403,405,364,345,349,374,118,369,14,196,34,
+    94,177,366,398,125,260,361,387,194,260,356,65,14,359,14,383,341,391,196,294,
+    179,253,59,200,364,26,159,90,200,405,161,175,377,32,370,133,361,32,56,180,
+    128,346,90,377,173,370,71,265,402,244,394,365,97,371,142,361,408,196,390,356,
+    74,390,89,372,94,374,319,326,257,336,52,116,106,313,85,40,376,206,169,48,
+    40,370,330,392,348,136,37,366,332,137,198,157,314,171,255,408,115,361,293,223,
+    341,185,167,361,322,244,386,47,320,408,400,52,163,325,348,75,125,356,353,320,
+    265,47,374,253,152,312,201,267,40,56,374,369,4,384,115,321,136,249,36,371,
+    173,379,169,365,136,21,137,346,320,85,229,365,173,143,361,365,223,279,359,40,
+    361,180,201,201,223,352,137,293,201,361,15,23,300,370,300,300,173,393,364,176,
+    173,139,139,265,365,177,61,388,71,253,265,6,94,390,392,6,186,94,213,69,
+    294,260,156,44,403,86,126,126,116,98,256,195,276,66,310,346,365,31,300,332,
+    346,235,258,265,135,247,364,139,6,87,36,353,74,373,258,94,247,212,352,80,
+    145,78,105,366,258,8,284,274,34,228,89,374,133,262,247,113,123,366,146,300,
+    365,393,346,96,276,132,319,150,372,185,80,342,79,403,221,332,300,205,106,152,
+    180,19,310,106,20,247,371,310,410,21,370,326,134,390,300,300,392,143,32,300,
+    300,357,388,10,119,14,299,41,300,20,395,38,375,253,330,258,94,385,181,258,
+    94,173,373,247,362,8,20,138,105,302,391,210,346,346,66,134,210,320,128,365,
+    310,161,147,11,135,330,353,123,137,38,84,188,19,300,209,134,366,146,394,39,
+    349,330,208,171,133,371,356,274,409,392,264,294,38,348,353,267,247,328,395,349,
+    206,158,363,348,348,355,146,30,169,133,247,279,201,365,366,110,3,72,370,289,
+    151,258,186,310,2,69,116,281,300,154,265,350,389,355,378,325,56,107,123,393,
+    331,189,198,168,339,352,374,11,345,313,371,32,71,20,116,134,123,53,90,32,
+    339,392,310,357,126,371,327,104,402,190,353,262,298,116,190,364,41,383,143,348,
+    328,195,255,173,241,281,37,99,173,323,19,395,390,332,181,408,143,33,372,135,
+    98,69,322,33,187,113,180,11,185,173,259,254,144,205,408,246,3,247,348,360,
+    400,171,152,155,318,163,75,218,46,403,391,12,225,404,47,320,71,256,258,400,
+    133,371,139,109,196,33,326,346,175,322,106,56,302,390,185,200,185,126,16,95,
+    166,153,87,345,213,339,377,125,62,348,248,132,176,85,199,148,367,66,175,113,
+    348,75,332,341,404,94,278,133,80,146,36,325,384,125,362,76,347,371,154,353,
+    129,341,94,388,30,261,300,123,162,326,209,260,260,124,331,50,128,364,205,135,
+    76,135,311,162,125,357,79,138,390,15,393,403,355,364,198,240,2,138,258,198,
+    317,57,9,347,175,317,148,181,363,364,230,343,277,321,317,259,173,309,46,20,
+    241,57,14,287,98,403,345,367,129,327,181,148,316,292,312,339,118,390,390,168,
+    365,372,321,225,346,134,100,192,144,311,406,317,347,90,342,212,185,317,238,406,
+    333,355,109,357,175,301,5,194,208,188,14,341,181,66,286,404,365,185,5,154,
+    259,54,256,33,321,194,369,32,90,140,16,302,184,355,50,183,377,135,26,173,
+    347,346,150,306,13,387,258,37,185,126,133,255,130,259,253,179,289,355,286,37,
+    162,310,181,216,129,250,315,86,260,267,362,321,349,145,137,409,177,261,169,77,
+    352,377,133,135,393,65,360,367,77,145,230,377,255,328,86,387,71,294,148,135,
+    88,315,185,144,130,371,175,402,260,135,402,171,14,328,128,364,79,113,60,143,
+    89,57,11,365,2,404,359,69,395,122,400,133,225,119,132,263,18,367,162,227,
+    356,135,135,258,30,392,204,173,171,133,407,161,295,246,165,75,309,48,189,16,
+    10,185,348,160,183,80,185,352,135,167,20,135,363,32,349,135,346,109,25,225,
+    171,53,266,241,188,407,186,379,225,145,321,302,167,139,95,365,390,71,388,253,
+    177,332,258,44,294,133,86,69,199,235,362,31,353,96,21,319,143,78,34,393,
+    212,11,98,8,258,363,262,146,345,105,150,87,132,331,228,6,371,258,391,258,
+    106,20,80,253,21,20,373,407,210,320,134,68,370,328,20,180,309,258,196,10,
+    300,357,318,14,221,247,79,353,151,169,84,195,360,370,37,134,364,216,392,60,
+    330,189,69,366,148,384,395,346,71,72,348,126,267,292,114,69,100,206,391,289,
+    137,365,32,39,321,3,366,279,395,169,255,345,166,323,175,154,350,317,173,383,
+    41,113,99,80,355,56,190,90,352,181,148,135,281,325,168,262,143,1,259,389,
+    234,56,196,12,256,62,152,163,33,346,106,188,46,400,139,392,348,144,132,244,
+    320,71,135,143,200,403,261,367,148,259,310,80,30,260,393,76,312,129,128,2,
+    75,198,184,403,88,198,208,390,20,100,225,321,144,225,212,181,99,9,365,134,
+    18,277,16,321,194,188,13,369,140,66,406,357,181,32,145,177,255,185,77,167,
+    255,53,259,65,132,171,128,402,175,365,30,16,165,32,349,387,33,142,5,70,
+    266,177,201,387,199,192,305,294,132,199,361,14,116,14,294,148,150,13,123,282,
+    286,348,348,135,205,347,307,74,384,216,386,246,347,180,15,14,282,2,109,100,
+    100,85,41,123,111,205,288,163,168,189,329,292,143,373,373,32,266,179,33,293,
+    163,361,342,361,80,131,371,342,349,10,123,266,363,342,8,3,341,366,164,268,
+    167,75,267,90,326,225,318,148,119,268,45,109,74,256,162,321,109,249,149,346,
+    130,32,247,60,128,318,342,318,199,305,294,361,116,14,342,45,282,341,348,123,
+    135,205,150,199,384,216,111,342,318,205,189,148,85,100,119,163,142,373,168,75,
+    371,361,33,346,342,131,361,80,32,167,266,130,164,268,119,326,57,268,116,128,
+    94,94,170,77,352,258,345,96,392,366,362,74,80,299,8,244,154,374,280,392,
+    247,140,87,362,366,390,138,37,80,266,66,406,410,68,180,1,336,336,11,19,
+    94,133,185,183,36,353,79,184,208,136,302,79,348,84,111,371,96,294,146,260,
+    353,47,94,14,347,294,299,392,255,74,372,390,41,348,61,225,374,348,244,88,
+    405,365,77,187,366,143,46,36,247,180,322,348,185,299,348,366,395,362,275,347,
+    39,361,366,302,66,371,183,341,341,225,77,315,3,129,138,315,366,96,361,130,
+    100,374,345,162,2,346,321,133,387,61,5,346,366,284,272,179,334,65,137,315,
+    315,5,348,89,221,84,133,61,346,366,80,130,183,346,173,173,173,400,125,392,
+    316,144,214,365,268,361,262,258,354,360,133,106,128,392,105,144,49,369,143,41,
+    125,375,188,371,40,69,315,116,344,306,109,41,375,369,133,346,40,181,173,214,
+    358,375,133,133,371,371,358,212,93,289,210,342,89,242,374,173,38,362,180,171,
+    3,10,345,68,60,125,345,69,356,133,210,36,350,384,329,390,244,198,180,256,
+    394,132,292,88,123,386,366,221,395,335,179,180,73,367,345,180,306,180,347,123,
+    366,193,193,374,181,200,18,345,341,164,366,346,365,2,59,324,286,371,185,183,
+    58,133,242,362,6,247,341,90,346,133,193,200,200,171,173,132,2,88,58,183,
+    180,2,90,173,10,119,119,119,19,263,263,140,326,390,140,37,263,140,140,68,
+    140,326,88,88,151,201,202,202,10,364,326,130,364,100,71,30,258,274,66,75,
+    345,274,262,139,358,228,6,366,284,212,208,406,57,8,365,363,322,11,134,123,
+    243,362,19,366,100,322,138,351,3,3,121,104,257,57,260,329,194,367,315,327,
+    260,357,155,13,318,295,19,164,143,165,351,278,9,83,265,265,119,350,210,143,
+    135,15,66,135,342,322,105,318,11,351,241,100,14,164,321,184,111,260,358,133,
+    135,136,32,57,125,348,258,75,337,135,167,341,274,94,198,267,100,341,260,116,
+    33,164,278,374,297,341,100,7,322,105,374,99,14,341,315,75,337,75,341,274,
+    94,116,341,374,322,142,142,348,351,348,133,366,375,374,296,170,246,129,367,374,
+    246,3,366,349,125,364,71,263,162,349,308,116,356,365,356,80,311,162,256,117,
+    371,339,8,78,66,59,241,253,180,35,140,171,119,260,80,80,341,351,164,298,
+    365,298,115,77,371,252,171,94,134,331,130,162,134,188,329,37,367,374,125,116,
+    140,334,334,251,166,334,403,403,46,71,166,320,116,258,152,54,357,262,365,285,
+    325,80,80,361,342,149,369,398,361,348,352,365,372,287,68,68,136,162,171,169,
+    249,338,194,52,363,118,260,106,359,361,130,32,279,200,18,348,251,185,167,225,
+    267,364,71,263,116,349,308,356,356,339,106,78,256,8,311,117,371,185,180,253,
+    140,138,134,329,119,367,141,152,365,251,130,334,116,367,367,152,325,369,80,398,
+    361,80,225,194,68,287,118,171,32,279,251,267,90,16,107,94,347,386,16,284,
+    6,319,178,107,357,296,143,16,310,341,362,363,312,148,312,86,181,346,181,249,
+    249,181,16,16,16,177,16,289,90,355,90,362,386,16,284,143,310,312,363,181,
+    249,16,16,88,86,88,88,300,300,24,133,71,310,336,386,316,349,335,274,371,
+    144,38,366,86,86,316,366,331,365,410,14,138,322,181,49,328,310,10,300,79,
+    115,274,326,137,134,19,363,330,49,349,362,144,84,361,170,346,24,20,218,80,
+    21,146,74,313,371,300,363,131,113,300,135,400,19,348,21,364,320,88,387,341,
+    109,80,232,374,125,129,328,130,135,124,2,321,89,341,106,30,311,321,20,99,
+    346,162,181,312,322,364,374,208,321,194,14,371,355,139,286,162,398,294,38,59,
+    365,133,272,37,369,322,341,349,386,89,115,200,361,208,32,349,188,377,215,300,
+    71,133,336,321,335,346,274,371,38,86,366,135,300,10,310,79,365,84,272,349,
+    119,170,137,346,19,20,74,80,371,218,146,113,131,348,109,30,162,106,312,32,
+    364,208,20,181,355,139,194,286,398,215,301,162,113,349,89,20,221,14,20,332,
+    116,88,135,3,2,94,348,374,352,89,251,352,192,371,90,116,66,336,389,38,
+    359,395,392,244,352,276,284,374,342,392,59,189,370,20,10,145,336,365,266,255,
+    266,141,253,388,372,244,394,143,395,231,143,247,378,134,180,390,319,94,362,300,
+    14,336,336,310,181,192,248,322,392,277,324,73,359,267,298,141,84,115,20,395,
+    366,188,394,59,351,181,143,311,262,195,168,116,332,357,334,146,80,37,353,2,
+    185,400,394,297,248,163,322,166,404,152,256,256,361,88,288,361,100,363,345,248,
+    50,248,258,88,129,258,132,371,325,267,347,404,162,278,310,107,336,111,312,258,
+    37,392,181,246,324,346,26,75,361,372,405,288,294,173,392,306,185,346,188,387,
+    208,5,24,16,50,266,14,392,371,356,126,20,313,350,179,386,78,181,336,29,
+    68,137,328,361,188,386,140,365,364,336,251,394,361,183,189,324,349,133,306,143,
+    346,128,173,16,192,371,336,359,38,266,276,20,189,378,300,310,94,143,405,395,
+    336,231,134,365,58,350,192,366,137,126,188,115,248,16,173,37,361,353,262,146,
+    256,256,152,400,404,313,24,248,392,162,288,345,5,181,258,294,16,188,50,32,
+    394,133,306,349,106,341,341,341,371,97,365,4,331,138,10,11,49,325,66,157,
+    115,260,124,158,100,334,103,248,14,152,257,371,315,184,20,350,9,20,49,160,
+    18,208,177,184,350,75,378,315,325,18,160,185,99,99,260,151,260,169,288,16,
+    163,163,66,87,355,270,196,59,163,18,85,327,247,403,85,270,325,10,14,196,
+    94,84,277,266,104,355,164,133,246,396,296,317,325,173,18,404,66,246,311,391,
+    267,404,308,135,336,125,165,142,256,175,390,18,246,192,286,194,194,291,356,178,
+    258,258,215,128,164,227,18,178,271,74,74,216,123,346,74,116,74,74,142,33,
+    371,371,100,361,94,262,111,404,181,111,295,371,111,198,133,256,95,162,131,6,
+    253,198,356,361,350,176,371,334,256,340,176,341,97,38,249,14,208,133,356,40,
+    361,386,371,61,274,138,6,123,336,69,133,356,80,80,292,117,335,208,138,298,
+    8,372,247,185,342,125,185,375,87,89,212,370,248,208,119,347,266,116,247,180,
+    336,20,265,252,94,14,49,341,143,69,6,370,112,247,222,353,319,10,94,384,
+    143,106,300,73,58,318,138,302,124,349,84,3,341,388,395,366,178,188,330,325,
+    365,19,341,137,157,111,348,100,130,169,94,151,355,79,146,325,202,296,384,317,
+    262,371,218,389,112,103,313,345,265,294,255,128,327,173,292,292,151,200,37,173,
+    405,346,369,221,403,256,391,349,218,47,133,69,261,106,394,73,166,88,221,365,
+    163,185,142,33,140,187,180,405,173,200,404,392,222,125,371,66,300,298,131,325,
+    124,353,395,165,404,381,15,15,128,267,381,341,341,371,47,278,70,129,175,361,
+    265,265,135,14,80,362,94,285,97,347,336,125,300,283,357,342,258,118,345,87,
+    288,181,192,300,300,109,403,324,318,363,80,369,258,256,342,283,298,175,5,170,
+    130,205,133,327,266,135,298,194,346,265,16,133,133,395,136,355,398,369,387,150,
+    358,17,371,266,349,20,137,359,313,129,409,294,294,86,111,179,359,203,346,383,
+    349,89,109,124,159,381,288,386,97,111,367,173,33,171,302,2,279,133,356,125,
+    302,173,178,173,204,390,349,80,185,109,173,348,371,61,133,370,335,185,87,6,
+    119,6,252,222,185,370,384,94,6,10,124,247,319,111,138,151,341,84,330,381,
+    124,159,133,137,348,384,349,359,103,173,175,135,173,300,327,112,292,128,146,133,
+    369,263,180,256,405,88,163,33,106,221,222,69,140,298,300,403,89,70,14,33,
+    325,342,341,285,80,265,94,129,267,136,15,288,5,256,318,109,363,242,135,170,
+    16,358,17,194,205,369,341,346,111,294,179,409,125,97,173,386,109,224,365,94,
+    173,142,21,361,94,69,133,90,279,97,300,90,206,10,372,392,125,262,94,8,
+    342,135,300,371,93,363,145,145,247,128,390,10,361,360,391,87,90,342,238,58,
+    100,279,180,204,94,336,205,173,15,392,100,372,49,266,350,38,59,143,363,106,
+    73,371,362,277,360,328,371,326,367,77,345,84,107,2,392,361,122,350,134,178,
+    395,362,325,123,188,279,209,100,274,137,355,394,392,188,122,222,80,186,134,133,
+    332,128,336,21,345,144,371,20,146,359,14,346,146,143,332,140,325,80,80,161,
+    125,345,298,166,137,241,185,247,302,94,3,402,246,262,258,11,69,185,268,135,
+    143,332,360,372,256,173,364,400,155,79,163,299,256,140,365,365,140,403,166,73,
+    256,47,103,143,145,365,409,133,302,367,38,203,278,3,265,325,125,325,80,138,
+    196,94,47,332,361,119,372,248,163,198,125,367,44,345,143,73,25,87,119,367,
+    372,348,343,300,119,41,321,347,283,181,133,106,135,316,116,49,49,365,363,361,
+    133,173,326,156,325,325,365,332,192,137,99,326,36,133,333,389,5,363,365,238,
+    38,392,181,369,189,14,306,402,371,345,145,366,325,310,137,365,126,14,367,313,
+    129,86,137,177,361,99,142,348,348,332,193,409,371,367,185,333,348,358,365,247,
+    41,188,346,365,133,380,371,386,364,362,247,227,125,201,367,200,66,373,371,171,
+    10,185,119,183,306,373,367,109,266,173,186,224,142,133,372,206,300,238,360,25,
+    10,390,106,73,185,360,350,362,180,38,266,372,358,336,310,392,84,107,355,122,
+    394,100,186,123,345,20,173,144,106,80,371,348,325,345,268,203,3,163,11,246,
+    258,47,103,372,313,125,119,80,106,265,49,198,345,365,363,343,181,133,365,135,
+    119,365,367,389,181,177,137,142,371,185,128,386,367,125,200,109,306,185,139,180,
+    135,348,56,135,135,361,56,185,370,52,133,243,52,243,395,146,395,135,201,201,
+    371,181,36,146,179,221,256,185,142,146,140,173,349,348,134,201,173,297,387,179,
+    140,256,180,361,52,193,193,119,34,94,202,202,94,243,266,266,209,94,348,166,
+    266,202,38,90,94,266,202,192,197,208,130,208,405,233,89,129,129,139,110,326,
+    331,123,126,161,123,302,173,222,38,120,120,365,258,59,346,335,208,208,258,58,
+    41,370,68,365,347,361,266,198,361,263,373,173,60,75,24,361,361,361,59,3,
+    390,58,24,365,198,386,361,75,185,392,89,94,94,202,202,372,52,266,34,337,
+    395,392,200,5,17,336,14,372,34,336,71,201,213,71,403,106,106,73,89,322,
+    372,247,33,99,256,372,321,324,302,302,89,88,342,6,69,336,393,266,299,300,
+    370,300,329,345,222,140,131,143,361,332,310,346,348,361,171,14,363,265,116,345,
+    345,124,351,80,384,355,343,384,229,215,256,385,133,403,133,133,256,133,38,36,
+    36,119,360,366,351,10,380,351,31,38,361,143,327,180,180,41,267,351,153,225,
+    142,363,48,163,371,41,365,221,380,405,266,374,361,238,80,344,365,49,405,68,
+    41,139,360,38,36,119,366,143,180,10,327,403,153,371,48,266,344,183,242,104,
+    242,361,183,183,104,149,57,180,57,183,104,149,111,265,17,111,373,46,119,145,
+    351,371,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(name: &str, query: &str) -> Option<PinyinMatch> {
+        let index = PinyinIndex::build(std::iter::once(name));
+        match_units(name, index.units(0), query)
+    }
+
+    #[test]
+    fn full_pinyin_initials_and_mixed_queries_match() {
+        let name = "报告文件.docx";
+        assert_eq!(find(name, "bgwj"), Some(PinyinMatch { first: 0, last: 3 }));
+        assert_eq!(
+            find(name, "baogao"),
+            Some(PinyinMatch { first: 0, last: 1 })
+        );
+        assert_eq!(
+            find(name, "BaoGaoWJ"),
+            Some(PinyinMatch { first: 0, last: 3 })
+        );
+        assert_eq!(
+            find(name, "wenjian.doc"),
+            Some(PinyinMatch { first: 2, last: 7 })
+        );
+        assert_eq!(find(name, "bgx"), None);
+    }
+
+    #[test]
+    fn polyphonic_characters_accept_other_readings() {
+        assert!(find("银行流水.xlsx", "yinhang").is_some());
+        assert!(find("银行流水.xlsx", "yinxing").is_some());
+        assert!(find("重庆出差.pdf", "cqcc").is_some());
+        assert!(find("音乐", "yinyue").is_some());
+    }
+
+    #[test]
+    fn names_without_hanzi_take_no_space() {
+        let index = PinyinIndex::build(["readme.md", "说明.md"].into_iter());
+        assert!(index.units(0).is_empty());
+        assert_eq!(index.units(1).len(), 5);
+        assert_eq!(find("readme.md", "readme"), None);
+    }
+}
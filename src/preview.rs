@@ -0,0 +1,345 @@
+//! 预览面板的内容：在后台线程读取选中结果的元数据与内容（图片、文本、十六进制、文件夹列表），
+//! 每种内容都有读取上限，大文件只读开头部分，不会阻塞界面。
+
+use std::io::Read;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::SystemTime;
+
+use eframe::egui;
+
+use crate::app::FileSearchApp;
+
+/// 文本预览最多读取的字节数。
+const MAX_TEXT_BYTES: usize = 256 * 1024;
+/// 十六进制预览最多显示的字节数。
+const MAX_HEX_BYTES: usize = 4 * 1024;
+/// 超过此大小的图片不解码（缩略图同样适用）。
+const MAX_IMAGE_BYTES: u64 = 64 * 1024 * 1024;
+/// 图片缩小到不超过此边长再上传为纹理。
+const MAX_IMAGE_SIDE: u32 = 1024;
+/// 文件夹预览最多列出的项数。
+const MAX_FOLDER_ENTRIES: usize = 1000;
+const HEX_ROW: usize = 16;
+
+pub enum PreviewContent {
+    /// 已缩放的 RGBA 像素；`original` 为原始尺寸。
+    Image {
+        image: egui::ColorImage,
+        original: (u32, u32),
+    },
+    /// `language` 为扩展名，交给语法高亮识别。
+    Text {
+        text: String,
+        language: String,
+        truncated: bool,
+    },
+    Hex {
+        dump: String,
+        truncated: bool,
+    },
+    /// (名称, 是否目录)，目录在前。
+    Folder {
+        entries: Vec<(String, bool)>,
+        total: usize,
+    },
+    Message(String),
+}
+
+pub struct Preview {
+    pub path: String,
+    pub metadata: Vec<(&'static str, String)>,
+    pub content: PreviewContent,
+}
+
+impl Preview {
+    pub fn load(path: &str, is_dir: bool) -> Self {
+        Self {
+            path: path.to_string(),
+            metadata: metadata_rows(path, is_dir),
+            content: load_content(path, is_dir),
+        }
+    }
+}
+
+/// 名称、位置、类型、大小、时间与属性；属性窗口与预览面板共用。
+pub fn metadata_rows(path: &str, is_dir: bool) -> Vec<(&'static str, String)> {
+    let format_time = |time: std::io::Result<SystemTime>| {
+        time.map_or("未知".to_string(), |t| {
+            chrono::DateTime::<chrono::Local>::from(t).format("%Y-%m-%d %H:%M:%S").to_string()
+        })
+    };
+    let name = path.trim_end_matches('/').rsplit('/').next().unwrap_or(path);
+    let mut rows = vec![
+        ("名称", name.to_string()),
+        ("位置", path.to_string()),
+        ("类型", if is_dir { "文件夹" } else { "文件" }.to_string()),
+    ];
+    match std::fs::metadata(path) {
+        Ok(meta) => {
+            if !is_dir {
+                rows.push(("大小", format!("{} ({} 字节)", FileSearchApp::format_size(meta.len()), meta.len())));
+            }
+            rows.push(("修改时间", format_time(meta.modified())));
+            rows.push(("创建时间", format_time(meta.created())));
+            rows.push(("访问时间", format_time(meta.accessed())));
+            rows.push(("属性", attributes(name, &meta)));
+        }
+        Err(e) => rows.push(("错误", e.to_string())),
+    }
+    rows
+}
+
+#[cfg(windows)]
+fn attributes(_name: &str, meta: &std::fs::Metadata) -> String {
+    use std::os::windows::fs::MetadataExt;
+    use winapi::um::winnt::{
+        FILE_ATTRIBUTE_ARCHIVE, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_READONLY, FILE_ATTRIBUTE_SYSTEM,
+    };
+    let bits = meta.file_attributes();
+    let flags: Vec<&str> = [
+        (FILE_ATTRIBUTE_READONLY, "只读"),
+        (FILE_ATTRIBUTE_HIDDEN, "隐藏"),
+        (FILE_ATTRIBUTE_SYSTEM, "系统"),
+        (FILE_ATTRIBUTE_ARCHIVE, "存档"),
+    ]
+    .into_iter()
+    .filter(|(bit, _)| bits & bit != 0)
+    .map(|(_, label)| label)
+    .collect();
+    if flags.is_empty() { "无".to_string() } else { flags.join("、") }
+}
+
+#[cfg(not(windows))]
+fn attributes(name: &str, meta: &std::fs::Metadata) -> String {
+    let mut flags = Vec::new();
+    if meta.permissions().readonly() {
+        flags.push("只读".to_string());
+    }
+    if name.starts_with('.') {
+        flags.push("隐藏".to_string());
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = meta.permissions().mode();
+        let rwx: String = (0..9)
+            .map(|i| match (mode >> (8 - i)) & 1 {
+                0 => '-',
+                _ => ['r', 'w', 'x'][i % 3],
+            })
+            .collect();
+        flags.push(rwx);
+    }
+    if flags.is_empty() { "无".to_string() } else { flags.join("、") }
+}
+
+fn load_content(path: &str, is_dir: bool) -> PreviewContent {
+    if is_dir {
+        return load_folder(path);
+    }
+    let result = match image::ImageFormat::from_path(path) {
+        Ok(format) if format.reading_enabled() => load_image(path),
+        _ => load_file_head(path),
+    };
+    result.unwrap_or_else(|e| PreviewContent::Message(format!("无法预览: {e}")))
+}
+
+fn load_folder(path: &str) -> PreviewContent {
+    let read = match std::fs::read_dir(path) {
+        Ok(read) => read,
+        Err(e) => return PreviewContent::Message(format!("无法读取文件夹: {e}")),
+    };
+    let mut total = 0;
+    let mut entries = Vec::new();
+    for entry in read.flatten() {
+        total += 1;
+        if entries.len() < MAX_FOLDER_ENTRIES {
+            let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+            entries.push((entry.file_name().to_string_lossy().into_owned(), is_dir));
+        }
+    }
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.to_lowercase().cmp(&b.0.to_lowercase())));
+    PreviewContent::Folder { entries, total }
+}
+
+fn load_image(path: &str) -> Result<PreviewContent, String> {
+    let (image, original) = decode_image(path, MAX_IMAGE_SIDE)?;
+    Ok(PreviewContent::Image { image, original })
+}
+
+/// 解码图片并缩小到不超过 `max_side`，返回像素与原始尺寸；预览面板与结果列表的缩略图共用。
+pub fn decode_image(path: &str, max_side: u32) -> Result<(egui::ColorImage, (u32, u32)), String> {
+    let len = std::fs::metadata(path).map_err(|e| e.to_string())?.len();
+    if len > MAX_IMAGE_BYTES {
+        return Err(format!("图片过大（{}）", FileSearchApp::format_size(len)));
+    }
+    let decoded = image::ImageReader::open(path)
+        .map_err(|e| e.to_string())?
+        .with_guessed_format()
+        .map_err(|e| e.to_string())?
+        .decode()
+        .map_err(|e| e.to_string())?;
+    let original = (decoded.width(), decoded.height());
+    let scaled = if original.0 > max_side || original.1 > max_side {
+        decoded.thumbnail(max_side, max_side)
+    } else {
+        decoded
+    };
+    let rgba = scaled.to_rgba8();
+    let size = [rgba.width() as usize, rgba.height() as usize];
+    Ok((egui::ColorImage::from_rgba_unmultiplied(size, rgba.as_raw()), original))
+}
+
+/// 读取文件开头：是文本（UTF-8 且不含 NUL）就按文本显示，否则显示十六进制。
+fn load_file_head(path: &str) -> Result<PreviewContent, String> {
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut head = Vec::with_capacity(MAX_TEXT_BYTES.min(64 * 1024));
+    file.take(MAX_TEXT_BYTES as u64 + 1)
+        .read_to_end(&mut head)
+        .map_err(|e| e.to_string())?;
+    let truncated = head.len() > MAX_TEXT_BYTES;
+    head.truncate(MAX_TEXT_BYTES);
+    if head.is_empty() {
+        return Ok(PreviewContent::Message("空文件".to_string()));
+    }
+    if let Some(text) = as_text(&head, truncated) {
+        let language = path.rsplit_once('.').map_or("", |(_, ext)| ext).to_lowercase();
+        return Ok(PreviewContent::Text {
+            text,
+            language,
+            truncated,
+        });
+    }
+    let shown = head.len().min(MAX_HEX_BYTES);
+    Ok(PreviewContent::Hex {
+        dump: hex_dump(&head[..shown]),
+        truncated: truncated || head.len() > shown,
+    })
+}
+
+/// 截断处可能切开一个多字节字符，只在末尾容忍不完整的字符。
+fn as_text(bytes: &[u8], truncated: bool) -> Option<String> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    if bytes.contains(&0) {
+        return None;
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Some(text.to_string()),
+        Err(e) if truncated && e.error_len().is_none() => {
+            Some(String::from_utf8_lossy(&bytes[..e.valid_up_to()]).into_owned())
+        }
+        Err(_) => None,
+    }
+}
+
+/// 每行 16 字节：偏移、十六进制、可打印字符。
+fn hex_dump(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 4 + bytes.len() / HEX_ROW * 12);
+    for (row, chunk) in bytes.chunks(HEX_ROW).enumerate() {
+        out.push_str(&format!("{:08x}  ", row * HEX_ROW));
+        for i in 0..HEX_ROW {
+            match chunk.get(i) {
+                Some(b) => out.push_str(&format!("{b:02x} ")),
+                None => out.push_str("   "),
+            }
+        }
+        out.push(' ');
+        out.extend(chunk.iter().map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' }));
+        out.push('\n');
+    }
+    out
+}
+
+/// 后台加载预览；只保留最近一次请求的结果。
+#[derive(Default)]
+pub struct PreviewLoader {
+    requested: Option<String>,
+    seq: Arc<AtomicU64>,
+    ready: Arc<Mutex<Option<Preview>>>,
+}
+
+impl PreviewLoader {
+    /// 选中项变化时调用；同一路径不会重复加载。
+    pub fn request(&mut self, ctx: &egui::Context, path: &str, is_dir: bool) {
+        if self.requested.as_deref() == Some(path) {
+            return;
+        }
+        self.requested = Some(path.to_string());
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let latest = Arc::clone(&self.seq);
+        let ready = Arc::clone(&self.ready);
+        let path = path.to_string();
+        let ctx = ctx.clone();
+        thread::spawn(move || {
+            let preview = Preview::load(&path, is_dir);
+            if latest.load(Ordering::SeqCst) == seq {
+                *ready.lock().unwrap() = Some(preview);
+                ctx.request_repaint();
+            }
+        });
+    }
+
+    pub fn clear(&mut self) {
+        if self.requested.take().is_none() {
+            return;
+        }
+        self.seq.fetch_add(1, Ordering::SeqCst);
+        *self.ready.lock().unwrap() = None;
+    }
+
+    /// 取出已加载完成的预览。
+    pub fn take_ready(&self) -> Option<Preview> {
+        self.ready.lock().unwrap().take()
+    }
+
+    /// 最近一次请求的路径；与已显示的预览不同时表示正在加载。
+    pub fn requested(&self) -> Option<&str> {
+        self.requested.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_is_capped_and_binary_falls_back_to_hex() {
+        let dir = std::env::temp_dir().join(format!("rust_search_preview_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().replace('\\', "/");
+
+        // 截断点落在“文”字中间：去掉不完整的字符，仍按文本显示
+        let mut long = "a".repeat(MAX_TEXT_BYTES - 1).into_bytes();
+        long.extend_from_slice("文件".as_bytes());
+        std::fs::write(dir.join("long.rs"), &long).unwrap();
+        match load_content(&format!("{dir_str}/long.rs"), false) {
+            PreviewContent::Text { text, language, truncated } => {
+                assert!(truncated);
+                assert_eq!(language, "rs");
+                assert_eq!(text.len(), MAX_TEXT_BYTES - 1);
+            }
+            _ => panic!("应按文本预览"),
+        }
+
+        std::fs::write(dir.join("data.bin"), [0u8, 1, 2, b'A', 0xff]).unwrap();
+        match load_content(&format!("{dir_str}/data.bin"), false) {
+            PreviewContent::Hex { dump, truncated } => {
+                assert!(!truncated);
+                assert!(dump.starts_with("00000000  00 01 02 41 ff "));
+                assert!(dump.trim_end().ends_with("...A."));
+            }
+            _ => panic!("应按十六进制预览"),
+        }
+
+        match load_content(&dir_str, true) {
+            PreviewContent::Folder { entries, total } => {
+                assert_eq!(total, 2);
+                assert_eq!(entries[0].0, "data.bin");
+            }
+            _ => panic!("应列出文件夹内容"),
+        }
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,218 @@
+//! 排序权重：把打分中用到的加权、加分与扣分集中到 `RankingProfile`，可按预设切换、在设置中微调并持久化；
+//! `ScoreExplanation` 记录单个结果的得分构成（见 `Searcher::explain`），用于调参时核对。
+
+use serde::{Deserialize, Serialize};
+
+use crate::searcher::MatchType;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankingProfile {
+    /// 匹配类型加权。
+    pub name_weight: f32,
+    pub path_weight: f32,
+    pub extension_weight: f32,
+    /// 文件名长度惩罚：每个字节扣 `length_penalty`，最多扣 `length_penalty_max`。
+    pub length_penalty: f32,
+    pub length_penalty_max: f32,
+
+    /// 非宽松模式：开头命中 / 中间命中。
+    pub prefix_score: f32,
+    pub substring_score: f32,
+
+    /// 宽松模式单个词：基础分 + 紧凑度 × `fuzzy_compact` + `fuzzy_start / (1 + 起始位置)`
+    /// − 间隔数 × `fuzzy_gap_penalty`，无间隔再加 `fuzzy_contiguous`。
+    pub fuzzy_base: f32,
+    pub fuzzy_compact: f32,
+    pub fuzzy_start: f32,
+    pub fuzzy_gap_penalty: f32,
+    pub fuzzy_contiguous: f32,
+
+    /// 宽松模式多个词：每个命中词加分、每个缺失词扣分，以及整体紧凑度、跨度、顺序与词间间隔。
+    pub token_bonus: f32,
+    pub missing_penalty: f32,
+    pub compact_bonus: f32,
+    pub span_penalty: f32,
+    pub inversion_penalty: f32,
+    pub in_order_bonus: f32,
+    pub gap_penalty: f32,
+
+    /// 拼音命中比同位置的直接字符命中低的分数。
+    pub pinyin_penalty: f32,
+    /// 容错命中的基础分（低于任何无间隔的精确/子序列命中）、开头加分与每次编辑的扣分。
+    pub typo_base: f32,
+    pub typo_start: f32,
+    pub typo_edit_penalty: f32,
+
+    /// frecency 加分：`frecency_weight * ln(1 + frecency / 10)`，最多 `frecency_max`。
+    pub frecency_weight: f32,
+    pub frecency_max: f32,
+}
+
+impl Default for RankingProfile {
+    fn default() -> Self {
+        Self {
+            name_weight: 100.0,
+            path_weight: 50.0,
+            extension_weight: 30.0,
+            length_penalty: 0.01,
+            length_penalty_max: 10.0,
+            prefix_score: 80.0,
+            substring_score: 50.0,
+            fuzzy_base: 40.0,
+            fuzzy_compact: 60.0,
+            fuzzy_start: 30.0,
+            fuzzy_gap_penalty: 1.5,
+            fuzzy_contiguous: 20.0,
+            token_bonus: 18.0,
+            missing_penalty: 28.0,
+            compact_bonus: 90.0,
+            span_penalty: 0.6,
+            inversion_penalty: 16.0,
+            in_order_bonus: 10.0,
+            gap_penalty: 0.7,
+            pinyin_penalty: 12.0,
+            typo_base: 30.0,
+            typo_start: 10.0,
+            typo_edit_penalty: 10.0,
+            frecency_weight: 8.0,
+            frecency_max: 40.0,
+        }
+    }
+}
+
+impl RankingProfile {
+    /// 当前权重与某个预设完全一致时返回该预设，否则视为自定义。
+    pub fn preset(&self) -> Option<RankingPreset> {
+        RankingPreset::ALL.into_iter().find(|p| p.profile() == *self)
+    }
+
+    /// 供设置界面逐项编辑的 (名称, 权重) 列表。
+    pub fn fields_mut(&mut self) -> [(&'static str, &mut f32); 25] {
+        [
+            ("文件名命中", &mut self.name_weight),
+            ("路径命中", &mut self.path_weight),
+            ("扩展名命中", &mut self.extension_weight),
+            ("长度惩罚/字节", &mut self.length_penalty),
+            ("长度惩罚上限", &mut self.length_penalty_max),
+            ("开头命中", &mut self.prefix_score),
+            ("中间命中", &mut self.substring_score),
+            ("子序列基础分", &mut self.fuzzy_base),
+            ("子序列紧凑度", &mut self.fuzzy_compact),
+            ("子序列开头加分", &mut self.fuzzy_start),
+            ("子序列间隔扣分", &mut self.fuzzy_gap_penalty),
+            ("子序列连续加分", &mut self.fuzzy_contiguous),
+            ("每个命中词", &mut self.token_bonus),
+            ("每个缺失词", &mut self.missing_penalty),
+            ("多词紧凑度", &mut self.compact_bonus),
+            ("多词跨度扣分", &mut self.span_penalty),
+            ("词序颠倒扣分", &mut self.inversion_penalty),
+            ("词序一致加分", &mut self.in_order_bonus),
+            ("词间间隔扣分", &mut self.gap_penalty),
+            ("拼音扣分", &mut self.pinyin_penalty),
+            ("容错基础分", &mut self.typo_base),
+            ("容错开头加分", &mut self.typo_start),
+            ("容错每次编辑扣分", &mut self.typo_edit_penalty),
+            ("常用度权重", &mut self.frecency_weight),
+            ("常用度加分上限", &mut self.frecency_max),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankingPreset {
+    /// 默认：文件名命中优先。
+    Name,
+    /// 路径命中与文件名命中同等对待，适合按目录结构找文件。
+    Path,
+    /// 大幅提高常用度加分，最近常用的文件几乎总在最前。
+    Recent,
+}
+
+impl RankingPreset {
+    pub const ALL: [RankingPreset; 3] = [
+        RankingPreset::Name,
+        RankingPreset::Path,
+        RankingPreset::Recent,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RankingPreset::Name => "文件名优先",
+            RankingPreset::Path => "路径优先",
+            RankingPreset::Recent => "最近常用优先",
+        }
+    }
+
+    pub fn profile(self) -> RankingProfile {
+        let base = RankingProfile::default();
+        match self {
+            RankingPreset::Name => base,
+            RankingPreset::Path => RankingProfile {
+                name_weight: 90.0,
+                path_weight: 100.0,
+                length_penalty: 0.0,
+                ..base
+            },
+            RankingPreset::Recent => RankingProfile {
+                frecency_weight: 30.0,
+                frecency_max: 150.0,
+                ..base
+            },
+        }
+    }
+}
+
+/// 得分中的一项；`token` 为对应查询词的下标（整体项为 `None`）。
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ScoreTerm {
+    pub label: &'static str,
+    pub token: Option<usize>,
+    pub value: f32,
+}
+
+/// 单个结果的得分构成：`terms` 之和等于 `total`（即 `SearchResult::score`）。
+#[derive(Clone, Debug, Serialize)]
+pub struct ScoreExplanation {
+    pub match_type: MatchType,
+    pub total: f32,
+    pub terms: Vec<ScoreTerm>,
+}
+
+/// 打分时的记录器：只有 `Searcher::explain` 传入 `Some`，扫描阶段不产生任何分配。
+pub(crate) struct Trace<'a>(pub(crate) Option<&'a mut Vec<ScoreTerm>>);
+
+impl Trace<'_> {
+    pub(crate) fn off() -> Self {
+        Trace(None)
+    }
+
+    /// 记录一项并原样返回分值，便于写成 `score += trace.add(...)`。
+    pub(crate) fn add(&mut self, label: &'static str, token: Option<usize>, value: f32) -> f32 {
+        if let Some(terms) = self.0.as_deref_mut()
+            && value != 0.0
+        {
+            terms.push(ScoreTerm { label, token, value });
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_distinct_and_round_trip() {
+        for preset in RankingPreset::ALL {
+            assert_eq!(preset.profile().preset(), Some(preset));
+        }
+        let mut custom = RankingProfile::default();
+        *custom.fields_mut()[0].1 = 120.0;
+        assert_eq!(custom.preset(), None);
+
+        let bytes = bincode::serialize(&custom).unwrap();
+        let back: RankingProfile = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back, custom);
+    }
+}
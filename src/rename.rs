@@ -0,0 +1,509 @@
+//! 批量重命名：按规则（查找替换、命名模板、大小写）为一组结果生成新文件名，
+//! 先检查冲突再整体执行，失败时回滚；执行过的批次记入撤销日志。
+//!
+//! 命名模板：`{name}` 为主名、`{ext}` 为扩展名（不含点）、`{n}` 为序号，`{n:3}` 表示补零到 3 位，
+//! `{date}` 为修改日期（`%Y%m%d`），`{date:%Y-%m-%d}` 可指定 chrono 格式。其余文字原样保留；
+//! 无法识别的占位符也原样保留。
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::io;
+
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::entry_store::split_extension;
+
+/// 撤销日志最多保留的批次数。
+const MAX_UNDO_BATCHES: usize = 20;
+
+/// 按模板生成新文件名；`n` 为该项的序号，`modified_ms` 供 `{date}` 使用。
+/// 模板用到 `{date}` 而修改时间未知时返回错误，不拿 1970 年凑数。
+pub fn expand_template(template: &str, name: &str, n: usize, modified_ms: Option<u64>) -> Result<String, &'static str> {
+    let (stem, ext) = split_extension(name);
+    let date = |format: &str| modified_ms.map(|ms| format_date(ms, format)).ok_or("无法读取修改时间");
+    let mut out = String::with_capacity(template.len() + name.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let Some(close) = after.find('}') else {
+            out.push_str(&rest[open..]);
+            return Ok(out);
+        };
+        let field = &after[..close];
+        let expanded = match field.split_once(':') {
+            None => match field {
+                "name" => Some(stem.to_string()),
+                // 没有扩展名时连同前面的点一起省略，"{name}.{ext}" 不会留下结尾的点
+                "ext" if ext.is_empty() => {
+                    if out.ends_with('.') {
+                        out.pop();
+                    }
+                    Some(String::new())
+                }
+                "ext" => Some(ext.to_string()),
+                "n" => Some(n.to_string()),
+                "date" => date("%Y%m%d")?,
+                _ => None,
+            },
+            Some(("n", width)) => width.parse::<usize>().ok().map(|width| format!("{n:0width$}")),
+            Some(("date", format)) => date(format)?,
+            Some(_) => None,
+        };
+        out.push_str(expanded.as_deref().unwrap_or(&rest[open..open + close + 2]));
+        rest = &after[close + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// 格式串无效时返回 `None`（chrono 对无效格式的 `to_string` 会 panic）。
+fn format_date(modified_ms: u64, format: &str) -> Option<String> {
+    let time = chrono::DateTime::from_timestamp_millis(modified_ms as i64)?.with_timezone(&chrono::Local);
+    let mut out = String::new();
+    write!(out, "{}", time.format(format)).ok()?;
+    Some(out)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaseChange {
+    #[default]
+    Keep,
+    Lower,
+    Upper,
+    /// 每个单词首字母大写。
+    Title,
+}
+
+impl CaseChange {
+    pub const ALL: [CaseChange; 4] = [CaseChange::Keep, CaseChange::Lower, CaseChange::Upper, CaseChange::Title];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CaseChange::Keep => "保持不变",
+            CaseChange::Lower => "全部小写",
+            CaseChange::Upper => "全部大写",
+            CaseChange::Title => "单词首字母大写",
+        }
+    }
+
+    fn apply(self, name: &str) -> String {
+        match self {
+            CaseChange::Keep => name.to_string(),
+            CaseChange::Lower => name.to_lowercase(),
+            CaseChange::Upper => name.to_uppercase(),
+            CaseChange::Title => {
+                let mut out = String::with_capacity(name.len());
+                let mut word_start = true;
+                for c in name.chars() {
+                    if word_start {
+                        out.extend(c.to_uppercase());
+                    } else {
+                        out.extend(c.to_lowercase());
+                    }
+                    word_start = !c.is_alphanumeric();
+                }
+                out
+            }
+        }
+    }
+}
+
+/// 重命名规则，依次应用：查找替换（作用于完整文件名）→ 命名模板 → 大小写。
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RenameRules {
+    pub find: String,
+    /// 正则模式下可用 `$1`、`${name}` 引用捕获组。
+    pub replace: String,
+    pub regex: bool,
+    pub case_sensitive: bool,
+    pub template: String,
+    pub start: usize,
+    pub step: usize,
+    pub case: CaseChange,
+}
+
+impl Default for RenameRules {
+    fn default() -> Self {
+        Self {
+            find: String::new(),
+            replace: String::new(),
+            regex: false,
+            case_sensitive: false,
+            template: "{name}.{ext}".to_string(),
+            start: 1,
+            step: 1,
+            case: CaseChange::Keep,
+        }
+    }
+}
+
+impl RenameRules {
+    /// 编译查找模式；正则无效时返回错误信息。
+    fn matcher(&self) -> Result<Option<Regex>, String> {
+        if self.find.is_empty() {
+            return Ok(None);
+        }
+        let pattern = if self.regex { self.find.clone() } else { regex::escape(&self.find) };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .map(Some)
+            .map_err(|e| format!("正则表达式无效: {e}"))
+    }
+
+    fn new_name(&self, matcher: Option<&Regex>, source: &RenameSource, position: usize) -> Result<String, &'static str> {
+        let replaced = match matcher {
+            // 非正则模式下替换文本按字面使用，不解析 `$`
+            Some(re) if self.regex => re.replace_all(&source.name, self.replace.as_str()),
+            Some(re) => re.replace_all(&source.name, regex::NoExpand(&self.replace)),
+            None => source.name.as_str().into(),
+        };
+        let n = self.start + position * self.step;
+        let expanded = expand_template(&self.template, &replaced, n, source.modified_ms)?;
+        Ok(self.case.apply(expanded.trim()))
+    }
+}
+
+/// 待重命名的一项：路径、名称与其在索引中的下标。
+#[derive(Clone, Debug)]
+pub struct RenameSource {
+    pub index: usize,
+    pub path: String,
+    pub name: String,
+    /// 打开工具时从文件系统读取；读取失败为 `None`。
+    pub modified_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RenameStatus {
+    Ready,
+    Unchanged,
+    /// 冲突或名称无效，整批不能执行。
+    Problem(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct PlannedRename {
+    pub index: usize,
+    pub old_path: String,
+    pub old_name: String,
+    pub new_name: String,
+    pub new_path: String,
+    pub status: RenameStatus,
+}
+
+/// 预览结果：逐项的新名称与状态。
+#[derive(Clone, Debug, Default)]
+pub struct RenamePlan {
+    pub items: Vec<PlannedRename>,
+}
+
+impl RenamePlan {
+    /// 按规则生成预览并检查冲突：新名称无效、批内重名、目标已存在（且不是本批中会被改名的项）、
+    /// 与所选文件夹内的项同时改名（父路径会先变化）。
+    pub fn build(rules: &RenameRules, sources: &[RenameSource]) -> Result<Self, String> {
+        let matcher = rules.matcher()?;
+        let mut items: Vec<PlannedRename> = sources
+            .iter()
+            .enumerate()
+            .map(|(position, source)| {
+                let (new_name, status) = match rules.new_name(matcher.as_ref(), source, position) {
+                    Ok(new_name) => (new_name, None),
+                    // 无法生成新名称：预览中保留原名称并标出问题
+                    Err(problem) => (source.name.clone(), Some(RenameStatus::Problem(problem.to_string()))),
+                };
+                let new_path = sibling_path(&source.path, &new_name);
+                let status = if let Some(status) = status {
+                    status
+                } else if new_name == source.name {
+                    RenameStatus::Unchanged
+                } else if let Some(problem) = invalid_name(&new_name) {
+                    RenameStatus::Problem(problem.to_string())
+                } else {
+                    RenameStatus::Ready
+                };
+                PlannedRename {
+                    index: source.index,
+                    old_path: source.path.clone(),
+                    old_name: source.name.clone(),
+                    new_name,
+                    new_path,
+                    status,
+                }
+            })
+            .collect();
+
+        let renamed: Vec<&PlannedRename> = items.iter().filter(|i| i.status == RenameStatus::Ready).collect();
+        // 本批中会被改名的原路径：目标与其重合时不算“已存在”
+        let vacated: HashSet<String> = renamed.iter().map(|i| collision_key(&i.old_path)).collect();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for item in items.iter().filter(|i| i.status != RenameStatus::Unchanged) {
+            *counts.entry(collision_key(&item.new_path)).or_default() += 1;
+        }
+        // 未改名的项仍占用原名称
+        for item in items.iter().filter(|i| i.status == RenameStatus::Unchanged) {
+            *counts.entry(collision_key(&item.old_path)).or_default() += 1;
+        }
+        let folders: Vec<String> = renamed.iter().map(|i| format!("{}/", i.old_path.trim_end_matches('/'))).collect();
+
+        for item in items.iter_mut().filter(|i| i.status == RenameStatus::Ready) {
+            let key = collision_key(&item.new_path);
+            let problem = if counts.get(&key).copied().unwrap_or(0) > 1 {
+                Some("与本批中的其他项重名")
+            } else if std::fs::symlink_metadata(&item.new_path).is_ok() && !vacated.contains(&key) {
+                Some("目标名称已存在")
+            } else if folders.iter().any(|f| item.old_path.starts_with(f.as_str())) {
+                Some("所在文件夹也在本批中改名，请分两次进行")
+            } else {
+                None
+            };
+            if let Some(problem) = problem {
+                item.status = RenameStatus::Problem(problem.to_string());
+            }
+        }
+        Ok(Self { items })
+    }
+
+    pub fn ready(&self) -> impl Iterator<Item = &PlannedRename> {
+        self.items.iter().filter(|i| i.status == RenameStatus::Ready)
+    }
+
+    pub fn problems(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|i| matches!(i.status, RenameStatus::Problem(_)))
+            .count()
+    }
+
+    /// 没有冲突且至少有一项需要改名时才能执行。
+    pub fn can_apply(&self) -> bool {
+        self.problems() == 0 && self.ready().next().is_some()
+    }
+}
+
+fn invalid_name(name: &str) -> Option<&'static str> {
+    if name.is_empty() || name == "." || name == ".." {
+        Some("新名称为空")
+    } else if name.contains(['/', '\\']) {
+        Some("新名称包含路径分隔符")
+    } else if cfg!(windows) && name.contains([':', '*', '?', '"', '<', '>', '|']) {
+        Some("新名称包含 Windows 不允许的字符")
+    } else {
+        None
+    }
+}
+
+/// Windows 与 macOS 的文件系统默认不区分大小写，按小写比较是否冲突。
+fn collision_key(path: &str) -> String {
+    if cfg!(any(windows, target_os = "macos")) {
+        path.to_lowercase()
+    } else {
+        path.to_string()
+    }
+}
+
+fn sibling_path(path: &str, new_name: &str) -> String {
+    match path.trim_end_matches('/').rfind('/') {
+        Some(i) => format!("{}{new_name}", &path[..=i]),
+        None => new_name.to_string(),
+    }
+}
+
+/// 一项已执行的重命名；撤销时反向执行。
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RenameRecord {
+    pub index: usize,
+    pub old_path: String,
+    pub new_path: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RenameBatch {
+    pub time_ms: u64,
+    pub records: Vec<RenameRecord>,
+}
+
+/// 撤销日志（持久化），最新的批次在末尾。
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RenameLog {
+    pub batches: Vec<RenameBatch>,
+}
+
+impl RenameLog {
+    pub fn push(&mut self, batch: RenameBatch) {
+        self.batches.push(batch);
+        let excess = self.batches.len().saturating_sub(MAX_UNDO_BATCHES);
+        self.batches.drain(..excess);
+    }
+
+    pub fn last(&self) -> Option<&RenameBatch> {
+        self.batches.last()
+    }
+
+    pub fn pop(&mut self) -> Option<RenameBatch> {
+        self.batches.pop()
+    }
+}
+
+/// 执行预览中可执行的全部改名，返回撤销记录。
+pub fn apply_plan(plan: &RenamePlan, time_ms: u64) -> io::Result<RenameBatch> {
+    let records: Vec<RenameRecord> = plan
+        .ready()
+        .map(|i| RenameRecord {
+            index: i.index,
+            old_path: i.old_path.clone(),
+            new_path: i.new_path.clone(),
+        })
+        .collect();
+    let pairs: Vec<(&str, &str)> = records.iter().map(|r| (r.old_path.as_str(), r.new_path.as_str())).collect();
+    rename_all(&pairs)?;
+    Ok(RenameBatch { time_ms, records })
+}
+
+/// 撤销一批重命名（把新路径改回原路径）。
+pub fn undo_batch(batch: &RenameBatch) -> io::Result<()> {
+    let pairs: Vec<(&str, &str)> = batch
+        .records
+        .iter()
+        .map(|r| (r.new_path.as_str(), r.old_path.as_str()))
+        .collect();
+    rename_all(&pairs)
+}
+
+/// 整体执行一组同目录内的改名：先全部改为临时名，再改为目标名，因此互换名称（a→b、b→a）也能完成。
+/// 任一步失败时把已完成的步骤按相反顺序撤回，尽量保持全部或全不。目标已存在时不覆盖。
+pub fn rename_all(pairs: &[(&str, &str)]) -> io::Result<()> {
+    let temps: Vec<String> = pairs
+        .iter()
+        .enumerate()
+        .map(|(i, (from, _))| sibling_path(from, &format!(".rename-{}-{i}.tmp", std::process::id())))
+        .collect();
+    let mut done: Vec<(&str, &str)> = Vec::with_capacity(pairs.len() * 2);
+    let result = (|| {
+        for ((from, _), temp) in pairs.iter().zip(&temps) {
+            std::fs::rename(from, temp).map_err(|e| with_path(e, from))?;
+            done.push((from, temp));
+        }
+        for ((_, to), temp) in pairs.iter().zip(&temps) {
+            if std::fs::symlink_metadata(to).is_ok() {
+                return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{to}: 目标名称已存在")));
+            }
+            std::fs::rename(temp, to).map_err(|e| with_path(e, to))?;
+            done.push((temp, to));
+        }
+        Ok(())
+    })();
+    if result.is_err() {
+        for (from, to) in done.into_iter().rev() {
+            let _ = std::fs::rename(to, from);
+        }
+    }
+    result
+}
+
+fn with_path(e: io::Error, path: &str) -> io::Error {
+    io::Error::new(e.kind(), format!("{path}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates_expand_name_extension_and_counter() {
+        let expand = |template: &str, name: &str, n: usize, ms: Option<u64>| expand_template(template, name, n, ms).unwrap();
+        assert_eq!(expand("{name}_{n:3}.{ext}", "photo.JPG", 7, None), "photo_007.JPG");
+        assert_eq!(expand("旅行 {n}", "IMG_1.png", 12, None), "旅行 12");
+        assert_eq!(expand("{name}{x}{", "a.b.c", 1, None), "a.b{x}{");
+        assert_eq!(expand("{name}.{ext}", "README", 1, None), "README");
+        let ms = chrono::NaiveDate::from_ymd_opt(2024, 3, 9)
+            .and_then(|d| d.and_hms_opt(12, 0, 0))
+            .and_then(|t| t.and_local_timezone(chrono::Local).single())
+            .map(|t| t.timestamp_millis() as u64)
+            .unwrap();
+        assert_eq!(expand("{date}_{name}", "a.txt", 1, Some(ms)), "20240309_a");
+        assert_eq!(expand("{date:%Y-%m}", "a.txt", 1, Some(ms)), "2024-03");
+        assert_eq!(expand_template("{date}_{name}", "a.txt", 1, None), Err("无法读取修改时间"));
+    }
+
+    #[test]
+    fn plan_detects_collisions_and_swaps_apply_atomically() {
+        let dir = std::env::temp_dir().join(format!("rust_search_rename_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().replace('\\', "/");
+        for name in ["a.txt", "b.txt", "keep.log"] {
+            std::fs::write(dir.join(name), name).unwrap();
+        }
+        let source = |index: usize, name: &str| RenameSource {
+            index,
+            path: format!("{dir_str}/{name}"),
+            name: name.to_string(),
+            modified_ms: None,
+        };
+
+        // 全部改成同一个名字：批内重名
+        let rules = RenameRules {
+            template: "same.{ext}".to_string(),
+            ..RenameRules::default()
+        };
+        let plan = RenamePlan::build(&rules, &[source(0, "a.txt"), source(1, "b.txt")]).unwrap();
+        assert_eq!(plan.problems(), 2);
+        assert!(!plan.can_apply());
+
+        // 改成未参与本批的已有文件名：目标已存在
+        let rules = RenameRules {
+            find: "A.TXT".to_string(),
+            replace: "keep.log".to_string(),
+            ..RenameRules::default()
+        };
+        let plan = RenamePlan::build(&rules, &[source(0, "a.txt")]).unwrap();
+        assert_eq!(plan.items[0].status, RenameStatus::Problem("目标名称已存在".to_string()));
+
+        let bad = RenameRules {
+            find: "(".to_string(),
+            regex: true,
+            ..RenameRules::default()
+        };
+        assert!(RenamePlan::build(&bad, &[source(0, "a.txt")]).is_err());
+
+        // 正则互换 a/b：两个目标都是本批中会腾出的名字，可以执行，撤销后恢复原状
+        let rules = RenameRules {
+            find: r"^(a|b)\.".to_string(),
+            replace: "x$1.".to_string(),
+            regex: true,
+            case: CaseChange::Upper,
+            ..RenameRules::default()
+        };
+        let plan = RenamePlan::build(&rules, &[source(0, "a.txt"), source(1, "b.txt"), source(2, "keep.log")]).unwrap();
+        assert_eq!(plan.items[0].new_name, "XA.TXT");
+        assert_eq!(plan.items[2].status, RenameStatus::Ready);
+        let batch = apply_plan(&plan, 0).unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("XA.TXT")).unwrap(), "a.txt");
+        assert_eq!(std::fs::read_to_string(dir.join("KEEP.LOG")).unwrap(), "keep.log");
+        undo_batch(&batch).unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("b.txt")).unwrap(), "b.txt");
+
+        let swap = [
+            (format!("{dir_str}/a.txt"), format!("{dir_str}/b.txt")),
+            (format!("{dir_str}/b.txt"), format!("{dir_str}/a.txt")),
+        ];
+        let pairs: Vec<(&str, &str)> = swap.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect();
+        rename_all(&pairs).unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("a.txt")).unwrap(), "b.txt");
+
+        // 第二项的源文件不存在：第一项被撤回
+        let missing = [
+            (format!("{dir_str}/a.txt"), format!("{dir_str}/c.txt")),
+            (format!("{dir_str}/missing.txt"), format!("{dir_str}/d.txt")),
+        ];
+        let pairs: Vec<(&str, &str)> = missing.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect();
+        assert!(rename_all(&pairs).is_err());
+        assert!(dir.join("a.txt").exists());
+        assert!(!dir.join("c.txt").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
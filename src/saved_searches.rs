@@ -0,0 +1,115 @@
+//! 保存的搜索：记录查询文本与 `SearchOptions`（含筛选、排序与限定目录），可一键或用快捷键重新执行。
+//!
+//! 开启“实时”的保存搜索会在每次索引更新后在后台重跑，新出现的结果数显示为角标，直到再次打开该搜索。
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::searcher::SearchOptions;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+    /// 执行时沿用当前的排序权重与分类表（见 `SearchOptions` 中不保存的字段）。
+    pub options: SearchOptions,
+    pub live: bool,
+    /// 上次打开后新出现的结果数。
+    pub new_matches: usize,
+    /// 已见过的结果路径；不持久化，启动后的第一次刷新只建立基线。
+    #[serde(skip)]
+    seen: Option<HashSet<String>>,
+}
+
+impl SavedSearch {
+    pub fn new(name: String, query: String, options: SearchOptions) -> Self {
+        Self {
+            name,
+            query,
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// 用最新一次结果更新已见集合，累加并返回本次新出现的结果数。
+    pub fn observe<I: IntoIterator<Item = String>>(&mut self, paths: I) -> usize {
+        let Some(seen) = self.seen.as_mut() else {
+            self.seen = Some(paths.into_iter().collect());
+            return 0;
+        };
+        let added = paths.into_iter().filter(|p| seen.insert(p.clone())).count();
+        self.new_matches += added;
+        added
+    }
+
+    /// 打开该搜索时清除角标。
+    pub fn mark_opened(&mut self) {
+        self.new_matches = 0;
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedSearches {
+    pub searches: Vec<SavedSearch>,
+}
+
+impl SavedSearches {
+    /// 同名时覆盖原有条目（保留其实时设置），否则追加到末尾。
+    pub fn save(&mut self, search: SavedSearch) {
+        match self.searches.iter_mut().find(|s| s.name == search.name) {
+            Some(existing) => {
+                existing.query = search.query;
+                existing.options = search.options;
+                existing.seen = None;
+            }
+            None => self.searches.push(search),
+        }
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut SavedSearch> {
+        self.searches.iter_mut().find(|s| s.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn live_search_counts_new_matches_after_baseline() {
+        let mut search = SavedSearch::new("报告".into(), "report".into(), SearchOptions::default());
+        assert_eq!(search.observe(paths(&["C:/a", "C:/b"])), 0);
+        assert_eq!(search.observe(paths(&["C:/a", "C:/b", "C:/c"])), 1);
+        assert_eq!(search.observe(paths(&["C:/b", "C:/c", "C:/d"])), 1);
+        assert_eq!(search.new_matches, 2);
+        search.mark_opened();
+        assert_eq!(search.new_matches, 0);
+        assert_eq!(search.observe(paths(&["C:/a"])), 0);
+    }
+
+    #[test]
+    fn saving_under_an_existing_name_replaces_the_query() {
+        let mut saved = SavedSearches::default();
+        saved.save(SavedSearch::new("a".into(), "x".into(), SearchOptions::default()));
+        saved.get_mut("a").unwrap().live = true;
+        let mut options = SearchOptions::default();
+        options.filters.roots = vec!["D:/work".to_string()];
+        saved.save(SavedSearch::new("a".into(), "y".into(), options));
+        assert_eq!(saved.searches.len(), 1);
+        let a = &saved.searches[0];
+        assert_eq!(a.query, "y");
+        assert!(a.live);
+        assert_eq!(a.options.filters.roots, ["D:/work"]);
+
+        let bytes = bincode::serialize(&saved).unwrap();
+        let back: SavedSearches = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back.searches[0].options.filters.roots, ["D:/work"]);
+    }
+}
@@ -1,15 +1,91 @@
-use crate::indexer::{FileEntry, FileIndexer};
+use crate::entry_store::{split_extension, EntryRef, EntryStore};
+use crate::categories::CategoryRegistry;
+use crate::facets::{FacetCounter, Facets, FileTypeFilter, SearchFilters, TypeMatcher};
+use crate::fold::{fold_for_search, fold_with_origins};
+use crate::indexer::FileIndexer;
+use crate::pinyin::{self, PinyinIndex};
+use crate::ranking::{RankingProfile, ScoreExplanation, Trace};
+use crate::usage::FrecencyTable;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::cmp::{Ordering, Reverse};
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Range;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-#[derive(Clone)]
+/// 结果排列方式。`search` 先按相关度选出前 `max_results` 个再按此重新排列；`search_hits` 对全部命中排列。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    #[default]
+    Relevance,
+    Name,
+    Path,
+    ModifiedDesc,
+    SizeDesc,
+}
+
+impl SortOrder {
+    pub const ALL: [SortOrder; 5] = [
+        SortOrder::Relevance,
+        SortOrder::Name,
+        SortOrder::Path,
+        SortOrder::ModifiedDesc,
+        SortOrder::SizeDesc,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortOrder::Relevance => "相关度",
+            SortOrder::Name => "名称",
+            SortOrder::Path => "路径",
+            SortOrder::ModifiedDesc => "最近修改",
+            SortOrder::SizeDesc => "大小",
+        }
+    }
+
+    /// 稳定排序：取值相同的命中保持相关度顺序。
+    fn apply(self, indexer: &FileIndexer, entries: &EntryStore, hits: &mut [HeapItem]) {
+        match self {
+            SortOrder::Relevance => {}
+            SortOrder::Name => hits.sort_by_cached_key(|h| entries.entry(h.tie).name_lower().to_string()),
+            SortOrder::Path => {
+                hits.sort_by_cached_key(|h| fold_for_search(&indexer.display_path_for(h.tie)).into_owned())
+            }
+            SortOrder::ModifiedDesc => hits.sort_by_key(|h| Reverse(entries.entry(h.tie).modified_ms())),
+            // 大小未知（`u64::MAX`，NTFS 枚举的条目）排在最后
+            SortOrder::SizeDesc => hits.sort_by_key(|h| {
+                let size = entries.entry(h.tie).size();
+                (size == u64::MAX, Reverse(size))
+            }),
+        }
+    }
+}
+
+/// 可随保存的搜索一起序列化；排序权重与分类表是全局设置，不随之保存。
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SearchOptions {
     pub case_sensitive: bool,
     pub regex: bool,
     pub path_search: bool,
     pub fuzzy: bool,
+    /// 允许用全拼/首字母匹配含汉字的文件名（如 "bgwj" 命中 "报告文件.docx"）。
+    pub pinyin: bool,
+    /// 拼写容错允许的最大编辑次数（0/1/2，含相邻字母互换）；短词按 `typo_budget` 再收紧。
+    pub typo_tolerance: u8,
+    /// `search` 返回的结果数上限；结果列表使用不设上限的 `search_hits` 分页显示。
     pub max_results: usize,
+    pub sort: SortOrder,
+    /// 打分权重（见 `RankingProfile`）。
+    #[serde(skip)]
+    pub ranking: RankingProfile,
+    /// 类型/扩展名/文件夹/大小/时间/隐藏文件筛选，在截取 `max_results` 之前应用。
+    pub filters: SearchFilters,
+    /// 文件类型分类表，供类型筛选与 `type:` 前缀使用。
+    #[serde(skip)]
+    pub categories: Arc<CategoryRegistry>,
 }
 
 impl Default for SearchOptions {
@@ -19,27 +95,93 @@ impl Default for SearchOptions {
             regex: false,
             path_search: false,
             fuzzy: true,
+            pinyin: true,
+            typo_tolerance: 1,
             max_results: 500,
+            sort: SortOrder::default(),
+            ranking: RankingProfile::default(),
+            filters: SearchFilters::default(),
+            categories: Arc::new(CategoryRegistry::default()),
         }
     }
 }
 
+/// 搜索结果不持有整个索引：同一页的结果共享一份只含这些条目的小副本，索引因此可以原地改写
+/// （见 `FileIndexer::edit`）。
 pub struct SearchResult {
-    pub entry: Arc<FileEntry>,
+    /// 本页条目的副本，本条在其中的下标为 `slot`。
+    page: Arc<EntryStore>,
+    slot: usize,
+    /// 条目在完整索引中的下标，仅在 `generation` 版本的索引中有效。
+    pub index: usize,
+    pub generation: u64,
     pub display_path: String,
     pub score: f32,
     pub match_type: MatchType,
+    /// 每个查询词在文件名与展示路径中的命中位置（见 `Highlight`）。
+    pub highlights: Vec<Highlight>,
+}
+
+impl SearchResult {
+    pub fn entry(&self) -> EntryRef<'_> {
+        self.page.entry(self.slot)
+    }
+
+    /// 某字段中所有查询词命中区间的并集（已排序、合并），供界面整体高亮。
+    pub fn merged_ranges(&self, field: MatchField) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = self
+            .highlights
+            .iter()
+            .filter(|h| h.field == field)
+            .flat_map(|h| h.ranges.iter().cloned())
+            .collect();
+        ranges.sort_by_key(|r| r.start);
+        merge_ranges(ranges)
+    }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+/// 高亮所在的字段。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum MatchField {
+    Name,
+    Path,
+}
+
+/// 第 `token` 个查询词在 `field`（文件名或 `display_path`）中命中的字符，按原文字符下标的半开区间给出；
+/// 宽松匹配跳过的字符不在区间内，因此区间之间的空隙就是匹配中的间隔。
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Highlight {
+    pub token: usize,
+    pub field: MatchField,
+    pub ranges: Vec<Range<usize>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
 pub enum MatchType {
     Name,
     Path,
     Extension,
 }
 
+/// 一次不设上限的搜索的全部命中，只记录下标与得分并已按排序方式排好；
+/// 界面滚动时用 `Searcher::page` 取出其中一段生成 `SearchResult`。
+pub struct SearchHits {
+    /// 搜索时的索引版本（见 `FileIndexer::generation`）。
+    pub generation: u64,
+    pattern: String,
+    hits: Vec<HeapItem>,
+}
+
+impl SearchHits {
+    pub fn len(&self) -> usize {
+        self.hits.len()
+    }
+}
+
 pub struct Searcher {
     pub options: SearchOptions,
+    /// 使用记录快照；为 `None` 时不做 frecency 加分。
+    frecency: Option<Arc<FrecencyTable>>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -68,7 +210,7 @@ impl PartialOrd for Score {
 struct HeapItem {
     score: Score,
     tie: usize,
-    result: SearchResult,
+    match_type: MatchType,
 }
 
 impl PartialEq for HeapItem {
@@ -93,6 +235,13 @@ impl Ord for HeapItem {
     }
 }
 
+/// 文件名的拼音匹配上下文：小写文件名及其拼音单元（见 `PinyinIndex`）。
+#[derive(Clone, Copy)]
+struct PinyinName<'a> {
+    name: &'a str,
+    units: &'a [u16],
+}
+
 #[derive(Clone, Debug)]
 struct TokenMatch {
     query_index: usize,
@@ -100,12 +249,62 @@ struct TokenMatch {
     last: usize,
     score: f32,
     needle_len: usize,
+    /// 命中方式，供 `explain` 展示。
+    label: &'static str,
+}
+
+/// 一次搜索共用的查询词与拼音索引。
+struct Query<'a> {
+    tokens: Vec<&'a str>,
+    /// 以点开头的词（如 ".rs"）去掉点后的部分：条目的扩展名必须以它开头。
+    extensions: Vec<&'a str>,
+    /// `type:xxx` 前缀（不参与打分，与设置中的类型筛选同时生效）。
+    file_type: Option<TypeMatcher<'a>>,
+    pinyin_index: Option<&'a PinyinIndex>,
+    /// 父目录的展示路径（已按大小写设置折叠），在本次搜索内按需计算并复用。
+    folder_paths: RefCell<HashMap<usize, String>>,
+    /// 拼接完整路径用的缓冲区，避免逐条目分配。
+    path_buf: RefCell<String>,
+}
+
+impl<'a> Query<'a> {
+    fn new(pattern: &'a str, categories: &'a CategoryRegistry, pinyin_index: Option<&'a PinyinIndex>) -> Self {
+        let mut tokens: Vec<&str> = Vec::new();
+        let mut file_type = None;
+        for token in pattern.split_whitespace() {
+            match token.get(..5).filter(|p| p.eq_ignore_ascii_case("type:")) {
+                Some(_) if token.len() > 5 => {
+                    file_type = Some(FileTypeFilter::parse(&token[5..], categories).resolve(categories));
+                }
+                _ => tokens.push(token),
+            }
+        }
+        let extensions = tokens
+            .iter()
+            .filter_map(|t| t.strip_prefix('.'))
+            .filter(|e| !e.is_empty() && !e.contains('.'))
+            .collect();
+        Self {
+            tokens,
+            extensions,
+            file_type,
+            pinyin_index,
+            folder_paths: RefCell::default(),
+            path_buf: RefCell::default(),
+        }
+    }
+
+    /// 只有 `type:` 前缀时列出该类型的全部条目。
+    fn is_empty(&self) -> bool {
+        self.tokens.is_empty() && self.file_type.is_none()
+    }
 }
 
 impl Searcher {
     pub fn new() -> Self {
         Self {
             options: SearchOptions::default(),
+            frecency: None,
         }
     }
 
@@ -113,120 +312,354 @@ impl Searcher {
         self.options = options;
     }
 
+    pub fn set_frecency(&mut self, table: Option<Arc<FrecencyTable>>) {
+        self.frecency = table.filter(|t| !t.is_empty());
+    }
+
+    fn fold_pattern<'p>(&self, pattern: &'p str) -> Cow<'p, str> {
+        if self.options.case_sensitive {
+            Cow::Borrowed(pattern)
+        } else {
+            fold_for_search(pattern)
+        }
+    }
+
+    /// 按相关度取前 `max_results` 个结果，再按 `sort` 排列。
     pub fn search(&self, indexer: &FileIndexer, pattern: &str) -> Vec<SearchResult> {
+        let entries = indexer.get_entries();
+        let mut hits = self.collect_hits(indexer, entries, pattern, None, self.options.max_results.max(1));
+        self.options.sort.apply(indexer, entries, &mut hits);
+        self.materialize(indexer, pattern, &hits)
+    }
+
+    /// 不设上限地收集全部命中并排好序，同时统计分面计数；结果由 `page` 按需生成。
+    pub fn search_hits(&self, indexer: &FileIndexer, pattern: &str) -> (SearchHits, Facets) {
+        let entries = indexer.get_entries();
+        let mut counter = FacetCounter::new(now_ms(), &self.options.categories);
+        let mut hits = self.collect_hits(indexer, entries, pattern, Some(&mut counter), usize::MAX);
+        self.options.sort.apply(indexer, entries, &mut hits);
+        let facets = counter.finish(indexer);
+        let hits = SearchHits {
+            generation: indexer.generation(),
+            pattern: pattern.to_string(),
+            hits,
+        };
+        (hits, facets)
+    }
+
+    /// 生成 `hits` 中 `range` 一段的结果（含展示路径与高亮）。索引在搜索之后已重建时返回 `None`，
+    /// 此时下标不再可信，应重新搜索。
+    pub fn page(&self, indexer: &FileIndexer, hits: &SearchHits, range: Range<usize>) -> Option<Vec<SearchResult>> {
+        if indexer.generation() != hits.generation {
+            return None;
+        }
+        let range = range.start.min(hits.len())..range.end.min(hits.len());
+        Some(self.materialize(indexer, &hits.pattern, &hits.hits[range]))
+    }
+
+    /// 全部匹配且满足筛选条件的条目中得分最高的 `keep` 个，按得分从高到低（同分按下标）排列。
+    fn collect_hits<'e>(
+        &self,
+        indexer: &FileIndexer,
+        entries: &'e EntryStore,
+        pattern: &str,
+        mut facets: Option<&mut FacetCounter<'e>>,
+        keep: usize,
+    ) -> Vec<HeapItem> {
         if pattern.is_empty() {
             return Vec::new();
         }
 
-        let entries = indexer.get_entries();
-        let keep = self.options.max_results.max(1);
         let mut heap: BinaryHeap<Reverse<HeapItem>> = BinaryHeap::new();
 
-        let search_pattern = if self.options.case_sensitive {
-            pattern.to_string()
-        } else {
-            pattern.to_lowercase()
-        };
-        let tokens: Vec<&str> = search_pattern.split_whitespace().filter(|t| !t.is_empty()).collect();
-        if tokens.is_empty() {
+        let search_pattern = self.fold_pattern(pattern);
+        let query = Query::new(
+            &search_pattern,
+            &self.options.categories,
+            self.options.pinyin.then(|| entries.pinyin_index()),
+        );
+        if query.is_empty() {
             return Vec::new();
         }
 
-        for (entry_idx, entry) in entries.iter().enumerate() {
-            if self.options.path_search {
-                let path = indexer.display_path_for(entry);
-                let haystack = if self.options.case_sensitive {
-                    path
-                } else {
-                    path.to_lowercase()
-                };
-                if let Some(score) = self.tokens_score(haystack.as_str(), &tokens) {
-                    self.push_top_k(
-                        &mut heap,
-                        keep,
-                        entry_idx,
-                        entry,
-                        score,
-                        MatchType::Path,
-                    );
-                }
+        let filter = self.options.filters.prepare(&self.options.categories, now_ms());
+        for entry in entries.iter() {
+            // 只看条目字段的筛选先做，排除的条目不必打分；读文件头、拼路径的条件留到匹配之后
+            if !filter.prefilter(entry) || query.file_type.as_ref().is_some_and(|t| t.quick(entry) == Some(false)) {
                 continue;
             }
+            let Some((score, match_type)) = self.match_entry(indexer, entry, &query, &mut Trace::off()) else {
+                continue;
+            };
+            if !filter.matches(indexer, entry)
+                || !query.file_type.as_ref().is_none_or(|t| t.matches(indexer, entry))
+            {
+                continue;
+            }
+            if let Some(counter) = facets.as_deref_mut() {
+                counter.add(entry);
+            }
+            self.push_top_k(indexer, &mut heap, keep, entry, score, match_type);
+        }
 
-            let name_haystack = if self.options.case_sensitive {
-                entry.name.as_str()
-            } else {
-                if entry.name_lower.is_empty() {
-                    entry.name.as_str()
-                } else {
-                    entry.name_lower.as_str()
+        let mut items: Vec<HeapItem> = heap.into_iter().map(|r| r.0).collect();
+        items.sort_by(|a, b| b.score.cmp(&a.score).then(a.tie.cmp(&b.tie)));
+        items
+    }
+
+    fn materialize(&self, indexer: &FileIndexer, pattern: &str, hits: &[HeapItem]) -> Vec<SearchResult> {
+        let entries = indexer.get_entries();
+        let mut page = EntryStore::with_capacity(hits.len());
+        for item in hits {
+            let entry = entries.entry(item.tie);
+            page.push(entry.name(), entry.path(), *entry.meta());
+        }
+        let page = Arc::new(page);
+        let search_pattern = self.fold_pattern(pattern);
+        let query = Query::new(
+            &search_pattern,
+            &self.options.categories,
+            self.options.pinyin.then(|| entries.pinyin_index()),
+        );
+        hits.iter()
+            .enumerate()
+            .map(|(slot, item)| {
+                let display_path = indexer.display_path_for(item.tie);
+                let highlights =
+                    self.highlights(entries, query.pinyin_index, item.tie, &display_path, &query.tokens);
+                SearchResult {
+                    page: Arc::clone(&page),
+                    slot,
+                    index: item.tie,
+                    generation: indexer.generation(),
+                    display_path,
+                    score: item.score.0,
+                    match_type: item.match_type,
+                    highlights,
                 }
+            })
+            .collect()
+    }
+
+    /// 按与 `search` 完全相同的规则重新给第 `index` 个条目打分，并列出得分的每一项；
+    /// 条目不匹配 `pattern` 时返回 `None`。
+    pub fn explain(&self, indexer: &FileIndexer, pattern: &str, index: usize) -> Option<ScoreExplanation> {
+        let entries = indexer.get_entries();
+        if index >= entries.len() {
+            return None;
+        }
+        let search_pattern = self.fold_pattern(pattern);
+        let query = Query::new(
+            &search_pattern,
+            &self.options.categories,
+            self.options.pinyin.then(|| entries.pinyin_index()),
+        );
+        if query.is_empty() {
+            return None;
+        }
+
+        let entry = entries.entry(index);
+        let mut terms = Vec::new();
+        let mut trace = Trace(Some(&mut terms));
+        let (match_score, match_type) = self.match_entry(indexer, entry, &query, &mut trace)?;
+        let total = self.final_score(entry, match_score, match_type, &mut trace)
+            + self.frecency_boost(indexer, entry, &mut trace);
+        Some(ScoreExplanation {
+            match_type,
+            total,
+            terms,
+        })
+    }
+
+    /// 单个条目的匹配分与匹配类型（未加类型权重与 frecency）。
+    fn match_entry(
+        &self,
+        indexer: &FileIndexer,
+        entry: EntryRef<'_>,
+        query: &Query<'_>,
+        trace: &mut Trace<'_>,
+    ) -> Option<(f32, MatchType)> {
+        let entry_idx = entry.index();
+        let name_haystack = if self.options.case_sensitive {
+            entry.name()
+        } else {
+            entry.name_lower()
+        };
+        let extension = if entry.is_dir() {
+            ""
+        } else {
+            split_extension(name_haystack).1
+        };
+        if !query.extensions.iter().all(|e| extension.starts_with(e)) {
+            return None;
+        }
+        if query.tokens.is_empty() {
+            return Some((0.0, MatchType::Name));
+        }
+
+        if self.options.path_search {
+            let score = self.path_score(indexer, entry, query, trace)?;
+            return Some((score, MatchType::Path));
+        }
+
+        let pinyin_name = query
+            .pinyin_index
+            .map(|index| index.units(entry_idx))
+            .filter(|units| !units.is_empty())
+            .map(|units| PinyinName {
+                name: entry.name_lower(),
+                units,
+            });
+        if let Some(score) = self.tokens_score(name_haystack, &query.tokens, pinyin_name, &mut Trace::off()) {
+            // 扫描阶段不记录；explain 时命中后再记一遍，避免把未命中的尝试混进明细。
+            if trace.0.is_some() {
+                self.tokens_score(name_haystack, &query.tokens, pinyin_name, trace);
+            }
+            let match_type = if self.matches_only_extension(name_haystack, extension, &query.tokens, pinyin_name) {
+                MatchType::Extension
+            } else {
+                MatchType::Name
             };
-            if let Some(score) = self.tokens_score(name_haystack, &tokens) {
-                self.push_top_k(
-                    &mut heap,
-                    keep,
-                    entry_idx,
-                    entry,
-                    score,
-                    MatchType::Name,
-                );
-                continue;
+            return Some((score, match_type));
+        }
+
+        // NTFS 枚举的条目不保存路径，沿父链拼接代价高：只在路径搜索模式下参与路径匹配（WalkDir 条目照常）
+        if entry.path().is_empty() && entry.drive() != 0 {
+            return None;
+        }
+        let score = self.path_score(indexer, entry, query, trace)?;
+        Some((score, MatchType::Path))
+    }
+
+    /// 按完整路径打分。NTFS 枚举与 WalkDir 扫描都不预先保存全路径：不自带路径的条目由父目录的
+    /// 展示路径加文件名拼出，父目录路径缓存在 `query` 中，同一目录下的条目只沿父链拼接一次。
+    /// NTFS 条目只在路径搜索模式下走到这里（见 `match_entry`）。
+    fn path_score(&self, indexer: &FileIndexer, entry: EntryRef<'_>, query: &Query<'_>, trace: &mut Trace<'_>) -> Option<f32> {
+        let case_sensitive = self.options.case_sensitive;
+        let fold = |path: String| if case_sensitive { path } else { fold_for_search(&path).into_owned() };
+        if !entry.path().is_empty() {
+            let haystack = if case_sensitive { entry.path() } else { entry.path_lower() };
+            return self.tokens_score(haystack, &query.tokens, None, trace);
+        }
+        let Some(parent) = entry.parent() else {
+            // 卷根下的条目：路径就是盘符加文件名
+            let path = fold(indexer.display_path_for(entry.index()));
+            return self.tokens_score(&path, &query.tokens, None, trace);
+        };
+        let mut folder_paths = query.folder_paths.borrow_mut();
+        let folder = folder_paths
+            .entry(parent.index())
+            .or_insert_with(|| fold(indexer.display_path_for(parent.index())));
+        let name = if case_sensitive { entry.name() } else { entry.name_lower() };
+        let mut path = query.path_buf.borrow_mut();
+        path.clear();
+        path.push_str(folder);
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+        path.push_str(name);
+        self.tokens_score(&path, &query.tokens, None, trace)
+    }
+
+    /// 每个词（去掉开头的点）都落在扩展名内、且主名本身并不匹配时，视为扩展名命中（如 "pdf"、".rs"）。
+    fn matches_only_extension(
+        &self,
+        name: &str,
+        extension: &str,
+        tokens: &[&str],
+        pinyin: Option<PinyinName<'_>>,
+    ) -> bool {
+        let within_extension = |t: &&str| {
+            let t = t.strip_prefix('.').unwrap_or(t);
+            !t.is_empty() && extension.contains(t)
+        };
+        if extension.is_empty() || !tokens.iter().all(within_extension) {
+            return false;
+        }
+        let stem = &name[..name.len() - extension.len() - 1];
+        let pinyin = pinyin.map(|p| {
+            let stem_lower = split_extension(p.name).0;
+            PinyinName {
+                name: stem_lower,
+                units: &p.units[..stem_lower.chars().count().min(p.units.len())],
             }
+        });
+        self.tokens_score(stem, tokens, pinyin, &mut Trace::off()).is_none()
+    }
 
-            // Windows 的 NTFS 枚举默认不预先构建全路径（否则会非常慢）；
-            // 因此在“非路径搜索模式”下，只有当 entry 自带路径时才参与路径匹配。
-            if !entry.path.is_empty() {
-                let path_haystack = if self.options.case_sensitive {
-                    entry.path.as_str()
-                } else {
-                    if entry.path_lower.is_empty() {
-                        entry.path.as_str()
-                    } else {
-                        entry.path_lower.as_str()
-                    }
+    /// 重新对最终结果的文件名与展示路径逐词定位命中字符（只对 top-k 做，扫描阶段不记录位置）。
+    fn highlights(
+        &self,
+        entries: &EntryStore,
+        pinyin_index: Option<&PinyinIndex>,
+        index: usize,
+        display_path: &str,
+        tokens: &[&str],
+    ) -> Vec<Highlight> {
+        let name = entries.name(index);
+        let units = pinyin_index.map_or(&[][..], |p| p.units(index));
+        let mut out = Vec::new();
+        for (field, text) in [(MatchField::Name, name), (MatchField::Path, display_path)] {
+            let (haystack, origins) = if self.options.case_sensitive {
+                (text.to_string(), (0..text.chars().count()).map(|i| i..i + 1).collect())
+            } else {
+                fold_with_origins(text)
+            };
+            let pinyin = (field == MatchField::Name && units.len() == origins.len()).then_some(PinyinName {
+                name: &haystack,
+                units,
+            });
+            for (token_index, token) in tokens.iter().enumerate() {
+                let Some(positions) = self.token_positions(&haystack, token, pinyin) else {
+                    continue;
                 };
-                if let Some(score) = self.tokens_score(path_haystack, &tokens) {
-                    self.push_top_k(
-                        &mut heap,
-                        keep,
-                        entry_idx,
-                        entry,
-                        score,
-                        MatchType::Path,
-                    );
-                }
+                let ranges = merge_ranges(positions.into_iter().map(|p| origins[p].clone()).collect());
+                out.push(Highlight {
+                    token: token_index,
+                    field,
+                    ranges,
+                });
             }
         }
+        out
+    }
 
-        let mut results: Vec<SearchResult> = heap.into_iter().map(|r| r.0.result).collect();
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
-        for r in results.iter_mut() {
-            r.display_path = indexer.display_path_for(r.entry.as_ref());
+    /// 按与打分相同的优先级（精确/子序列 → 拼音 → 容错）找出单个词命中的字符下标。
+    fn token_positions(&self, haystack: &str, token: &str, pinyin: Option<PinyinName<'_>>) -> Option<Vec<usize>> {
+        if self.options.fuzzy {
+            if self.fuzzy_token_match(haystack, token, 0).is_some() {
+                let mut positions = Vec::with_capacity(token.len());
+                fuzzy_match(haystack, token, Some(&mut positions))?;
+                return Some(positions);
+            }
+        } else if let Some(byte) = haystack.find(token) {
+            let first = haystack[..byte].chars().count();
+            return Some((first..first + token.chars().count()).collect());
         }
 
-        results
+        if let Some(m) = pinyin.and_then(|p| pinyin::match_units(p.name, p.units, token)) {
+            return Some((m.first..=m.last).collect());
+        }
+        let m = typo_match(haystack, token, self.typo_budget(token))?;
+        Some((m.first..=m.last).collect())
     }
 
     fn push_top_k(
         &self,
+        indexer: &FileIndexer,
         heap: &mut BinaryHeap<Reverse<HeapItem>>,
         keep: usize,
-        tie: usize,
-        entry: &FileEntry,
+        entry: EntryRef<'_>,
         match_score: f32,
         match_type: MatchType,
     ) {
-        let final_score = self.final_score(entry, match_score, match_type);
+        let tie = entry.index();
+        let final_score = self.final_score(entry, match_score, match_type, &mut Trace::off())
+            + self.frecency_boost(indexer, entry, &mut Trace::off());
         let item = Reverse(HeapItem {
             score: Score(final_score),
             tie,
-            result: SearchResult {
-                entry: Arc::new(entry.clone()),
-                display_path: String::new(),
-                score: final_score,
-                match_type,
-            },
+            match_type,
         });
 
         if heap.len() < keep {
@@ -243,55 +676,108 @@ impl Searcher {
         }
     }
 
-    fn final_score(&self, entry: &FileEntry, match_score: f32, match_type: MatchType) -> f32 {
+    fn final_score(&self, entry: EntryRef<'_>, match_score: f32, match_type: MatchType, trace: &mut Trace<'_>) -> f32 {
+        let weights = &self.options.ranking;
         let mut score = 0.0;
 
         // 匹配类型加权
-        match match_type {
-            MatchType::Name => score += 100.0,
-            MatchType::Path => score += 50.0,
-            MatchType::Extension => score += 30.0,
-        }
+        score += match match_type {
+            MatchType::Name => trace.add("文件名命中", None, weights.name_weight),
+            MatchType::Path => trace.add("路径命中", None, weights.path_weight),
+            MatchType::Extension => trace.add("扩展名命中", None, weights.extension_weight),
+        };
 
         score += match_score;
 
         // 长度惩罚（避免长文件名排名过高）
-        let len_penalty = (entry.name.len() as f32 / 100.0).min(10.0);
-        score -= len_penalty;
+        let len_penalty = (entry.name().len() as f32 * weights.length_penalty).min(weights.length_penalty_max);
+        score += trace.add("长度惩罚", None, -len_penalty);
 
         score
     }
 
-    fn tokens_score(&self, haystack: &str, tokens: &[&str]) -> Option<f32> {
+    /// 只有文件名出现在使用记录中的条目才拼接路径查表，其余条目不产生额外开销。
+    fn frecency_boost(&self, indexer: &FileIndexer, entry: EntryRef<'_>, trace: &mut Trace<'_>) -> f32 {
+        let Some(table) = self.frecency.as_deref() else {
+            return 0.0;
+        };
+        if !table.may_contain_name(entry.name()) {
+            return 0.0;
+        }
+        let weights = &self.options.ranking;
+        let frecency = table.frecency(&indexer.display_path_for(entry.index()));
+        let boost = (weights.frecency_weight * (1.0 + frecency / 10.0).ln()).min(weights.frecency_max);
+        trace.add("常用度", None, boost)
+    }
+
+    fn tokens_score(
+        &self,
+        haystack: &str,
+        tokens: &[&str],
+        pinyin: Option<PinyinName<'_>>,
+        trace: &mut Trace<'_>,
+    ) -> Option<f32> {
         if tokens.is_empty() {
             return None;
         }
 
         if self.options.fuzzy {
-            return self.fuzzy_tokens_score(haystack, tokens);
+            return self.fuzzy_tokens_score(haystack, tokens, pinyin, trace);
         }
 
+        let weights = &self.options.ranking;
         let mut total = 0.0;
-        for token in tokens {
-            total += self.substring_match_score(haystack, token)?;
+        for (query_index, token) in tokens.iter().enumerate() {
+            let (label, score) = self
+                .substring_match_score(haystack, token)
+                .or_else(|| pinyin.and_then(|p| self.pinyin_substring_score(p, token)))
+                .or_else(|| {
+                    let m = typo_match(haystack, token, self.typo_budget(token))?;
+                    Some(("容错命中", weights.typo_base - m.edits as f32 * weights.typo_edit_penalty))
+                })?;
+            total += trace.add(label, Some(query_index), score);
         }
         Some(total)
     }
 
-    fn substring_match_score(&self, haystack: &str, token: &str) -> Option<f32> {
+    /// 词长不足 4 个字符时不容错，8 个字符以上才允许 2 次编辑。
+    fn typo_budget(&self, token: &str) -> usize {
+        let by_len = match token.chars().count() {
+            0..4 => 0,
+            4..8 => 1,
+            _ => 2,
+        };
+        by_len.min(usize::from(self.options.typo_tolerance))
+    }
+
+    fn substring_match_score(&self, haystack: &str, token: &str) -> Option<(&'static str, f32)> {
         if token.is_empty() {
             return None;
         }
+        let weights = &self.options.ranking;
         if haystack.starts_with(token) {
-            return Some(80.0);
+            return Some(("开头命中", weights.prefix_score));
         }
         if haystack.contains(token) {
-            return Some(50.0);
+            return Some(("中间命中", weights.substring_score));
         }
         None
     }
 
-    fn fuzzy_tokens_score(&self, haystack: &str, tokens: &[&str]) -> Option<f32> {
+    fn pinyin_substring_score(&self, name: PinyinName<'_>, token: &str) -> Option<(&'static str, f32)> {
+        let weights = &self.options.ranking;
+        let m = pinyin::match_units(name.name, name.units, token)?;
+        let score = if m.first == 0 { weights.prefix_score } else { weights.substring_score };
+        Some(("拼音命中", score - weights.pinyin_penalty))
+    }
+
+    fn fuzzy_tokens_score(
+        &self,
+        haystack: &str,
+        tokens: &[&str],
+        pinyin: Option<PinyinName<'_>>,
+        trace: &mut Trace<'_>,
+    ) -> Option<f32> {
         let required = match tokens.len() {
             0 => return None,
             1 | 2 => tokens.len(),
@@ -299,15 +785,15 @@ impl Searcher {
         };
 
         let mut matches: Vec<TokenMatch> = Vec::with_capacity(tokens.len());
-        let mut base = 0.0f32;
         let mut missing = 0usize;
 
         for (query_index, token) in tokens.iter().enumerate() {
-            match self.fuzzy_token_match(haystack, token, query_index) {
-                Some(m) => {
-                    base += m.score;
-                    matches.push(m);
-                }
+            let m = self
+                .fuzzy_token_match(haystack, token, query_index)
+                .or_else(|| pinyin.and_then(|p| self.pinyin_token_match(p, token, query_index)))
+                .or_else(|| self.typo_token_match(haystack, token, query_index));
+            match m {
+                Some(m) => matches.push(m),
                 None => missing += 1,
             }
         }
@@ -316,9 +802,13 @@ impl Searcher {
             return None;
         }
 
-        let mut score = base;
-        score += matches.len() as f32 * 18.0;
-        score -= missing as f32 * 28.0;
+        let weights = &self.options.ranking;
+        let mut score = 0.0f32;
+        for m in &matches {
+            score += trace.add(m.label, Some(m.query_index), m.score);
+        }
+        score += trace.add("命中词数", None, matches.len() as f32 * weights.token_bonus);
+        score += trace.add("缺失词", None, -(missing as f32) * weights.missing_penalty);
 
         if matches.len() < 2 {
             return Some(score);
@@ -337,8 +827,12 @@ impl Searcher {
         let span = (max_last.saturating_sub(min_first) + 1).max(1) as f32;
         let compact = (total_needle_len.max(1) as f32 / span).min(1.0);
 
-        score += compact * 90.0;
-        score -= (span - total_needle_len.max(1) as f32).max(0.0) * 0.6;
+        score += trace.add("多词紧凑度", None, compact * weights.compact_bonus);
+        score += trace.add(
+            "多词跨度",
+            None,
+            -(span - total_needle_len.max(1) as f32).max(0.0) * weights.span_penalty,
+        );
 
         let mut by_pos: Vec<(usize, usize, usize)> = matches
             .iter()
@@ -354,9 +848,9 @@ impl Searcher {
                 }
             }
         }
-        score -= inversions as f32 * 16.0;
+        score += trace.add("词序颠倒", None, -(inversions as f32) * weights.inversion_penalty);
         if inversions == 0 {
-            score += 10.0;
+            score += trace.add("词序一致", None, weights.in_order_bonus);
         }
 
         let mut gap_sum = 0usize;
@@ -365,7 +859,7 @@ impl Searcher {
             let cur_first = w[1].1;
             gap_sum += cur_first.saturating_sub(prev_last + 1);
         }
-        score -= gap_sum as f32 * 0.7;
+        score += trace.add("词间间隔", None, -(gap_sum as f32) * weights.gap_penalty);
 
         Some(score)
     }
@@ -375,7 +869,7 @@ impl Searcher {
             return None;
         }
 
-        let m = fuzzy_match(haystack, token)?;
+        let m = fuzzy_match(haystack, token, None)?;
         let needle_len = token.chars().count().max(1);
         let span_usize = (m.last.saturating_sub(m.first) + 1).max(1);
         if needle_len <= 2 && m.gaps != 0 {
@@ -385,14 +879,15 @@ impl Searcher {
             return None;
         }
 
+        let weights = &self.options.ranking;
         let span = span_usize as f32;
         let compact = (needle_len as f32 / span).min(1.0);
-        let start_bonus = 30.0 / (1.0 + m.first as f32);
-        let gap_penalty = m.gaps as f32 * 1.5;
+        let start_bonus = weights.fuzzy_start / (1.0 + m.first as f32);
+        let gap_penalty = m.gaps as f32 * weights.fuzzy_gap_penalty;
 
-        let mut score = 40.0 + compact * 60.0 + start_bonus - gap_penalty;
+        let mut score = weights.fuzzy_base + compact * weights.fuzzy_compact + start_bonus - gap_penalty;
         if m.gaps == 0 {
-            score += 20.0;
+            score += weights.fuzzy_contiguous;
         }
 
         Some(TokenMatch {
@@ -401,6 +896,42 @@ impl Searcher {
             last: m.last,
             score,
             needle_len,
+            label: if m.gaps == 0 { "连续命中" } else { "子序列命中" },
+        })
+    }
+
+    fn typo_token_match(&self, haystack: &str, token: &str, query_index: usize) -> Option<TokenMatch> {
+        let weights = &self.options.ranking;
+        let m = typo_match(haystack, token, self.typo_budget(token))?;
+        let start_bonus = weights.typo_start / (1.0 + m.first as f32);
+        Some(TokenMatch {
+            query_index,
+            first: m.first,
+            last: m.last,
+            score: weights.typo_base + start_bonus - m.edits as f32 * weights.typo_edit_penalty,
+            needle_len: token.chars().count(),
+            label: "容错命中",
+        })
+    }
+
+    /// 拼音命中总是连续的一段汉字，按无间隔的直接命中计分后再扣 `pinyin_penalty`。
+    fn pinyin_token_match(
+        &self,
+        name: PinyinName<'_>,
+        token: &str,
+        query_index: usize,
+    ) -> Option<TokenMatch> {
+        let weights = &self.options.ranking;
+        let m = pinyin::match_units(name.name, name.units, token)?;
+        let start_bonus = weights.fuzzy_start / (1.0 + m.first as f32);
+        Some(TokenMatch {
+            query_index,
+            first: m.first,
+            last: m.last,
+            score: weights.fuzzy_base + weights.fuzzy_compact + start_bonus + weights.fuzzy_contiguous
+                - weights.pinyin_penalty,
+            needle_len: m.last - m.first + 1,
+            label: "拼音命中",
         })
     }
 }
@@ -417,7 +948,14 @@ struct FuzzyMatch {
     gaps: usize,
 }
 
-fn fuzzy_match(haystack: &str, needle: &str) -> Option<FuzzyMatch> {
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// 贪心子序列匹配；`positions` 非空时记录每个命中字符的下标。
+fn fuzzy_match(haystack: &str, needle: &str, mut positions: Option<&mut Vec<usize>>) -> Option<FuzzyMatch> {
     let mut needle_iter = needle.chars();
     let mut current = needle_iter.next()?;
 
@@ -433,6 +971,9 @@ fn fuzzy_match(haystack: &str, needle: &str) -> Option<FuzzyMatch> {
         if first.is_none() {
             first = Some(i);
         }
+        if let Some(positions) = positions.as_deref_mut() {
+            positions.push(i);
+        }
         if let Some(prev_i) = prev {
             gaps += i.saturating_sub(prev_i + 1);
         }
@@ -452,16 +993,139 @@ fn fuzzy_match(haystack: &str, needle: &str) -> Option<FuzzyMatch> {
     None
 }
 
+/// 合并已按起点排序的区间中重叠或相邻的部分。
+fn merge_ranges(ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+struct TypoMatch {
+    first: usize,
+    last: usize,
+    edits: usize,
+}
+
+/// needle 中比 haystack 多出的字符个数（按码点取模计数）。每个多出的字符至少需要一次编辑，
+/// 因此它是编辑距离的下界，可以在动态规划前排除差异过多的候选；取模冲突只会让下界偏小，不会误排除。
+fn missing_chars(haystack: &str, needle: &str) -> usize {
+    let mut counts = [0i32; 128];
+    for c in needle.chars() {
+        counts[c as usize % 128] += 1;
+    }
+    for c in haystack.chars() {
+        counts[c as usize % 128] -= 1;
+    }
+    counts.iter().map(|&n| n.max(0) as usize).sum()
+}
+
+/// `typo_match` 的工作区：字符数组与动态规划的六行，按线程复用，扫描时不再逐条目分配。
+#[derive(Default)]
+struct TypoScratch {
+    hay: Vec<char>,
+    pat: Vec<char>,
+    rows: [Vec<usize>; 6],
+}
+
+thread_local! {
+    static TYPO_SCRATCH: RefCell<TypoScratch> = RefCell::default();
+}
+
+/// 在 `haystack` 中找与 `needle` 的受限 Damerau-Levenshtein 距离（OSA：插入、删除、替换、
+/// 相邻互换）不超过 `max_edits` 的子串，返回编辑次数最少、其次结束最早的一段（按字符下标）。
+fn typo_match(haystack: &str, needle: &str, max_edits: usize) -> Option<TypoMatch> {
+    if max_edits == 0 || needle.is_empty() {
+        return None;
+    }
+    // 字节数不少于字符数：haystack 连长度都差太多时不必逐字符统计
+    if haystack.len() + max_edits < needle.chars().count() || missing_chars(haystack, needle) > max_edits {
+        return None;
+    }
+    TYPO_SCRATCH.with_borrow_mut(|scratch| typo_match_with(scratch, haystack, needle, max_edits))
+}
+
+fn typo_match_with(scratch: &mut TypoScratch, haystack: &str, needle: &str, max_edits: usize) -> Option<TypoMatch> {
+    let TypoScratch { hay, pat, rows } = scratch;
+    hay.clear();
+    hay.extend(haystack.chars());
+    pat.clear();
+    pat.extend(needle.chars());
+    let m = pat.len();
+
+    // 逐列滚动：dist[i] 为 needle 前 i 个字符匹配到当前位置的最少编辑，start[i] 为该匹配的起点。
+    // 第 0 行恒为 0，即匹配可以从 haystack 任意位置开始。
+    let [prev2, prev2_start, prev, prev_start, cur, cur_start] = rows;
+    for row in [&mut *prev2, &mut *prev2_start, &mut *prev, &mut *prev_start, &mut *cur, &mut *cur_start] {
+        row.clear();
+        row.resize(m + 1, 0);
+    }
+    prev2.fill(usize::MAX);
+    for (i, d) in prev.iter_mut().enumerate() {
+        *d = i;
+    }
+    let mut best: Option<TypoMatch> = None;
+
+    for j in 1..=hay.len() {
+        cur[0] = 0;
+        cur_start[0] = j;
+        for i in 1..=m {
+            let cost = usize::from(pat[i - 1] != hay[j - 1]);
+            let mut d = prev[i - 1] + cost;
+            let mut s = prev_start[i - 1];
+            if prev[i] + 1 < d {
+                d = prev[i] + 1;
+                s = prev_start[i];
+            }
+            if cur[i - 1] + 1 < d {
+                d = cur[i - 1] + 1;
+                s = cur_start[i - 1];
+            }
+            if i > 1
+                && j > 1
+                && pat[i - 1] == hay[j - 2]
+                && pat[i - 2] == hay[j - 1]
+                && prev2[i - 2] != usize::MAX
+                && prev2[i - 2] + 1 < d
+            {
+                d = prev2[i - 2] + 1;
+                s = prev2_start[i - 2];
+            }
+            cur[i] = d;
+            cur_start[i] = s;
+        }
+
+        let edits = cur[m];
+        if edits <= max_edits && cur_start[m] < j && best.as_ref().is_none_or(|b| edits < b.edits) {
+            best = Some(TypoMatch {
+                first: cur_start[m],
+                last: j - 1,
+                edits,
+            });
+        }
+
+        std::mem::swap(prev2, prev);
+        std::mem::swap(prev2_start, prev_start);
+        std::mem::swap(prev, cur);
+        std::mem::swap(prev_start, cur_start);
+    }
+
+    best
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indexer::FileEntry;
 
     fn entry(name: &str, path: &str) -> FileEntry {
         FileEntry {
             name: name.to_string(),
-            name_lower: name.to_lowercase(),
             path: path.to_string(),
-            path_lower: path.to_lowercase(),
             drive: 0,
             frn: 0,
             parent_frn: 0,
@@ -485,7 +1149,98 @@ mod tests {
 
         let results = searcher.search(&indexer, "world hello");
         assert!(!results.is_empty());
-        assert_eq!(results[0].entry.name, "hello_world.txt");
+        assert_eq!(results[0].entry().name(), "hello_world.txt");
+    }
+
+    #[test]
+    fn walkdir_entries_match_by_folder_component() {
+        let root = std::env::temp_dir().join(format!("rust_search_folder_match_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("Projects/alpha")).unwrap();
+        std::fs::write(root.join("Projects/alpha/notes.txt"), b"x").unwrap();
+        let (entries, usn_states, _) = FileIndexer::build_index_snapshot_with_stats(vec![root.clone()], None);
+        let mut indexer = FileIndexer::new();
+        indexer.replace_index(entries, usn_states);
+
+        // 文件本身不存路径，文件夹部分只能沿父链得到
+        let results = Searcher::new().search(&indexer, "projects notes");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].entry().name(), "notes.txt");
+        assert!(results[0].entry().path().is_empty());
+        assert_eq!(results[0].match_type, MatchType::Path);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn ntfs_entries_match_folders_only_in_path_mode() {
+        let usn = |name: &str, frn: u64, parent_frn: u64, is_dir: bool| FileEntry {
+            drive: b'C',
+            frn,
+            parent_frn,
+            is_dir,
+            ..entry(name, "")
+        };
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            usn("", 5, 5, true),
+            usn("Projects", 10, 5, true),
+            usn("notes.txt", 11, 10, false),
+        ]);
+
+        let mut searcher = Searcher::new();
+        assert!(searcher.search(&indexer, "projects notes").is_empty());
+        searcher.options.path_search = true;
+        let results = searcher.search(&indexer, "projects notes");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].display_path, "C:/Projects/notes.txt");
+    }
+
+    #[test]
+    fn transposed_or_mistyped_letters_are_tolerated() {
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![entry("quarterly_report.pdf", "C:/tmp/quarterly_report.pdf")]);
+
+        let mut searcher = Searcher::new();
+        for fuzzy in [true, false] {
+            searcher.options.fuzzy = fuzzy;
+            for query in ["reprot", "repirt", "quaterly", "quartrely report"] {
+                let results = searcher.search(&indexer, query);
+                assert_eq!(results.len(), 1, "{query}");
+            }
+        }
+    }
+
+    #[test]
+    fn typo_tolerance_is_bounded_by_setting_and_token_length() {
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![entry("quarterly_report.pdf", "C:/tmp/quarterly_report.pdf")]);
+
+        let mut searcher = Searcher::new();
+        assert!(searcher.search(&indexer, "rpeotr").is_empty());
+        assert!(searcher.search(&indexer, "pfd").is_empty());
+
+        searcher.options.typo_tolerance = 2;
+        assert_eq!(searcher.search(&indexer, "qaurtelry").len(), 1);
+        assert!(searcher.search(&indexer, "rpeotr").is_empty());
+
+        searcher.options.typo_tolerance = 0;
+        assert!(searcher.search(&indexer, "reprot").is_empty());
+    }
+
+    #[test]
+    fn typo_matches_rank_below_exact_and_subsequence_matches() {
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            entry("reprot.txt", "C:/tmp/reprot.txt"),
+            entry("r_e_p_o_r_t.txt", "C:/tmp/r_e_p_o_r_t.txt"),
+            entry("report.txt", "C:/tmp/report.txt"),
+        ]);
+
+        let searcher = Searcher::new();
+        let results = searcher.search(&indexer, "report");
+        let names: Vec<&str> = results.iter().map(|r| r.entry().name()).collect();
+        assert_eq!(names, ["report.txt", "r_e_p_o_r_t.txt", "reprot.txt"]);
     }
 
     #[test]
@@ -543,6 +1298,357 @@ mod tests {
 
         let results = searcher.search(&indexer, "hello");
         assert!(!results.is_empty());
-        assert_eq!(results[0].entry.name, "hello_target.txt");
+        assert_eq!(results[0].entry().name(), "hello_target.txt");
+    }
+
+    #[test]
+    fn case_insensitive_search_folds_unicode_names_and_queries() {
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            entry("ÄNDERUNG.txt", "C:/tmp/ÄNDERUNG.txt"),
+            entry("Ｒｅｐｏｒｔ.pdf", "C:/tmp/Ｒｅｐｏｒｔ.pdf"),
+        ]);
+
+        let searcher = Searcher::new();
+        for query in ["änderung", "A\u{308}NDERUNG"] {
+            let results = searcher.search(&indexer, query);
+            assert_eq!(results.len(), 1, "{query}");
+            assert_eq!(results[0].entry().name(), "ÄNDERUNG.txt");
+        }
+        let results = searcher.search(&indexer, "report");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].entry().name(), "Ｒｅｐｏｒｔ.pdf");
+    }
+
+    #[test]
+    fn results_carry_per_token_highlight_ranges() {
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            entry("Hello_World.txt", "C:/tmp/Hello_World.txt"),
+            entry("报告文件.docx", "C:/tmp/报告文件.docx"),
+        ]);
+
+        let searcher = Searcher::new();
+        let results = searcher.search(&indexer, "world hlo");
+        assert_eq!(results.len(), 1);
+        let name: Vec<&Highlight> = results[0]
+            .highlights
+            .iter()
+            .filter(|h| h.field == MatchField::Name)
+            .collect();
+        assert_eq!(name.len(), 2);
+        assert_eq!(name[0].token, 0);
+        assert_eq!(name[0].ranges.len(), 1);
+        assert_eq!(name[0].ranges[0], 6..11);
+        // 宽松匹配的间隔不高亮：h(0) l(2) o(4)
+        assert_eq!((name[1].token, name[1].ranges.clone()), (1, vec![0..1, 2..3, 4..5]));
+        assert_eq!(results[0].merged_ranges(MatchField::Name), [0..1, 2..3, 4..5, 6..11]);
+        let path = results[0].merged_ranges(MatchField::Path);
+        assert_eq!(path.last(), Some(&(13..18)));
+
+        let results = searcher.search(&indexer, "bgwj");
+        let ranges = results[0].merged_ranges(MatchField::Name);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0], 0..4);
+    }
+
+    #[test]
+    fn frecently_opened_files_rank_above_stale_ones() {
+        use crate::usage::{UsageKind, UsageStore};
+
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            entry("notes.txt", "C:/a/notes.txt"),
+            entry("notes.txt", "C:/b/notes.txt"),
+        ]);
+
+        let mut searcher = Searcher::new();
+        let first = searcher.search(&indexer, "notes");
+        assert_eq!(first[0].display_path, "C:/a/notes.txt");
+
+        let mut usage = UsageStore::load(None);
+        usage.record("C:/b/notes.txt", UsageKind::Open, 1_000).unwrap();
+        searcher.set_frecency(Some(Arc::new(usage.frecency_table(2_000))));
+        let boosted = searcher.search(&indexer, "notes");
+        assert_eq!(boosted[0].display_path, "C:/b/notes.txt");
+        assert!(boosted[0].score > first[0].score);
+
+        searcher.set_frecency(None);
+        assert_eq!(searcher.search(&indexer, "notes")[0].display_path, "C:/a/notes.txt");
+    }
+
+    #[test]
+    fn pinyin_matches_rank_just_below_direct_matches() {
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            entry("报告文件.docx", "C:/tmp/报告文件.docx"),
+            entry("bgwj.docx", "C:/tmp/bgwj.docx"),
+        ]);
+
+        let mut searcher = Searcher::new();
+        for fuzzy in [true, false] {
+            searcher.options.fuzzy = fuzzy;
+            let results = searcher.search(&indexer, "bgwj");
+            assert_eq!(results.len(), 2);
+            assert_eq!(results[0].entry().name(), "bgwj.docx");
+            assert_eq!(results[1].entry().name(), "报告文件.docx");
+        }
+
+        let results = searcher.search(&indexer, "baogao docx");
+        assert_eq!(results.len(), 1);
+
+        searcher.options.pinyin = false;
+        assert!(searcher.search(&indexer, "baogao").is_empty());
+    }
+
+    #[test]
+    fn explanation_terms_add_up_to_result_score() {
+        use crate::ranking::RankingPreset;
+        use crate::usage::{UsageKind, UsageStore};
+
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            entry("project_report_final.docx", "C:/work/project_report_final.docx"),
+            entry("report.txt", "C:/tmp/report.txt"),
+            entry("报告文件.docx", "C:/tmp/报告文件.docx"),
+        ]);
+        let mut usage = UsageStore::load(None);
+        usage.record("C:/tmp/report.txt", UsageKind::Open, 1_000).unwrap();
+
+        let mut searcher = Searcher::new();
+        searcher.set_frecency(Some(Arc::new(usage.frecency_table(2_000))));
+        for fuzzy in [true, false] {
+            searcher.options.fuzzy = fuzzy;
+            for pattern in ["report", "final report", "bgwj", "reprot"] {
+                for result in searcher.search(&indexer, pattern) {
+                    let explanation = searcher.explain(&indexer, pattern, result.index).unwrap();
+                    let sum: f32 = explanation.terms.iter().map(|t| t.value).sum();
+                    assert!((explanation.total - result.score).abs() < 1e-3, "{pattern}");
+                    assert!((sum - result.score).abs() < 1e-3, "{pattern}: {:?}", explanation.terms);
+                }
+            }
+        }
+        assert!(searcher.explain(&indexer, "report", 2).is_none());
+
+        let boosted = searcher.explain(&indexer, "report", 1).unwrap();
+        assert!(boosted.terms.iter().any(|t| t.label == "常用度"));
+        searcher.options.ranking = RankingPreset::Recent.profile();
+        let recent = searcher.explain(&indexer, "report", 1).unwrap();
+        assert!(recent.total > boosted.total);
+
+        searcher.options.ranking.prefix_score = 0.0;
+        searcher.options.ranking.substring_score = 500.0;
+        let results = searcher.search(&indexer, "report");
+        assert_eq!(results[0].entry().name(), "project_report_final.docx");
+    }
+
+    #[test]
+    fn extension_queries_produce_extension_matches_and_facets() {
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(vec![
+            entry("main.rs", "C:/src/main.rs"),
+            entry("rsync.txt", "C:/doc/rsync.txt"),
+            entry("lib.rs.bak", "C:/src/lib.rs.bak"),
+            entry("report.PDF", "C:/doc/report.PDF"),
+            entry("notes.txt", "C:/doc/notes.txt"),
+        ]);
+
+        let searcher = Searcher::new();
+        let results = searcher.search(&indexer, ".rs");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].entry().name(), "main.rs");
+        assert!(results[0].match_type == MatchType::Extension);
+
+        let results = searcher.search(&indexer, "rs");
+        let names: Vec<&str> = results.iter().map(|r| r.entry().name()).collect();
+        assert_eq!(names[0], "rsync.txt");
+        let main = results.iter().find(|r| r.entry().name() == "main.rs").unwrap();
+        assert!(main.match_type == MatchType::Extension);
+
+        let results = searcher.search(&indexer, "pdf");
+        assert!(results[0].match_type == MatchType::Extension);
+        assert!(searcher.search(&indexer, "main .txt").is_empty());
+
+        let (hits, facets) = searcher.search_hits(&indexer, "t");
+        assert_eq!(hits.len(), 3);
+        assert_eq!(facets.extensions, vec![("txt".to_string(), 2), ("pdf".to_string(), 1)]);
+    }
+
+    #[test]
+    fn facets_count_every_match_before_the_top_k_cut() {
+        use crate::facets::{DateBucket, FileTypeFilter, SizeBucket};
+
+        let mut entries = Vec::new();
+        for i in 0..30 {
+            let mut e = entry(&format!("photo{i}.jpg"), &format!("C:/pics/photo{i}.jpg"));
+            e.size = 2 << 20;
+            e.modified_ms = now_ms() - 1000;
+            entries.push(e);
+        }
+        // 大小与修改时间都未知（USN 枚举的条目）：不计入大小与时间档位
+        let mut notes = entry("photo notes.txt", "C:/doc/photo notes.txt");
+        notes.size = u64::MAX;
+        entries.push(notes);
+        let mut dir = entry("photos", "C:/photos");
+        dir.is_dir = true;
+        entries.push(dir);
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(entries);
+
+        let mut searcher = Searcher::new();
+        searcher.options.max_results = 5;
+        assert_eq!(searcher.search(&indexer, "photo").len(), 5);
+        let (hits, facets) = searcher.search_hits(&indexer, "photo");
+        assert_eq!(hits.len(), 32);
+        assert_eq!(
+            facets.types,
+            vec![
+                (FileTypeFilter::Files, 31),
+                (FileTypeFilter::Folders, 1),
+                (FileTypeFilter::Category("document".to_string()), 1),
+                (FileTypeFilter::Category("image".to_string()), 30),
+            ]
+        );
+        assert_eq!(facets.extensions[0], ("jpg".to_string(), 30));
+        assert_eq!(facets.folders[0], ("C:/pics".to_string(), 30));
+        assert_eq!(facets.folders.len(), 3);
+        assert_eq!(facets.sizes, vec![(SizeBucket::Large, 30)]);
+        assert_eq!(facets.dates, vec![(DateBucket::Today, 30)]);
+    }
+
+    #[test]
+    fn filters_apply_before_max_results() {
+        use crate::facets::{FileTypeFilter, SizeBucket};
+
+        let mut entries = Vec::new();
+        for i in 0..50 {
+            entries.push(entry(&format!("trip{i}.txt"), &format!("C:/notes/trip{i}.txt")));
+        }
+        entries.push(entry("trip_photo_from_the_beach.PNG", "C:/pics/trip_photo_from_the_beach.PNG"));
+        let mut hidden = entry("trip.jpg", "C:/pics/trip.jpg");
+        hidden.is_hidden = true;
+        hidden.size = u64::MAX;
+        entries.push(hidden);
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(entries);
+
+        let mut searcher = Searcher::new();
+        searcher.options.max_results = 10;
+        searcher.options.filters.file_type = FileTypeFilter::Category("image".to_string());
+        let results = searcher.search(&indexer, "trip");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].entry().name(), "trip_photo_from_the_beach.PNG");
+
+        searcher.options.filters.show_hidden = true;
+        let (hits, facets) = searcher.search_hits(&indexer, "trip");
+        assert_eq!(hits.len(), 2);
+        assert_eq!(facets.extensions, vec![("jpg".to_string(), 1), ("png".to_string(), 1)]);
+
+        searcher.options.filters.file_type = FileTypeFilter::All;
+        searcher.options.filters.extension = ".PNG".to_string();
+        assert_eq!(searcher.search(&indexer, "trip").len(), 1);
+        searcher.options.filters.extension.clear();
+        searcher.options.filters.folder = Some("C:/pics/".to_string());
+        assert_eq!(searcher.search(&indexer, "trip").len(), 2);
+        searcher.options.filters.folder = Some("C:/pic".to_string());
+        assert!(searcher.search(&indexer, "trip").is_empty());
+
+        // 大小未知的条目不满足任何大小档位
+        searcher.options.filters.folder = None;
+        searcher.options.filters.size = Some(SizeBucket::Empty);
+        assert_eq!(searcher.search_hits(&indexer, "trip").0.len(), 51);
+    }
+
+    #[test]
+    fn roots_limit_results_and_sort_order_reorders_them() {
+        let mut entries = vec![
+            entry("report_b.txt", "C:/work/report_b.txt"),
+            entry("report_a.txt", "C:/work/sub/report_a.txt"),
+            entry("report.txt", "D:/home/report.txt"),
+        ];
+        entries[0].size = 10;
+        entries[1].size = 30;
+        entries[2].size = u64::MAX;
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(entries);
+
+        let mut searcher = Searcher::new();
+        searcher.options.filters.roots = vec!["C:/work".to_string()];
+        searcher.options.sort = SortOrder::Name;
+        let names = |results: Vec<SearchResult>| -> Vec<String> {
+            results.iter().map(|r| r.entry().name().to_string()).collect()
+        };
+        assert_eq!(names(searcher.search(&indexer, "report")), ["report_a.txt", "report_b.txt"]);
+        searcher.options.sort = SortOrder::SizeDesc;
+        searcher.options.filters.roots.push("D:/home".to_string());
+        assert_eq!(
+            names(searcher.search(&indexer, "report")),
+            ["report_a.txt", "report_b.txt", "report.txt"]
+        );
+    }
+
+    #[test]
+    fn search_hits_are_uncapped_and_paged_in_sort_order() {
+        let entries = (0..30)
+            .rev()
+            .map(|i| entry(&format!("app_{i:02}.log"), &format!("C:/logs/app_{i:02}.log")))
+            .collect();
+        let mut indexer = FileIndexer::new();
+        indexer.set_entries_from_cache(entries);
+
+        let mut searcher = Searcher::new();
+        searcher.options.max_results = 5;
+        searcher.options.sort = SortOrder::Name;
+        let (hits, facets) = searcher.search_hits(&indexer, ".log");
+        assert_eq!(hits.len(), 30);
+        assert_eq!(facets.extensions.iter().map(|(_, n)| *n).sum::<usize>(), 30);
+
+        let names = |page: Vec<SearchResult>| -> Vec<String> { page.iter().map(|r| r.entry().name().to_string()).collect() };
+        let first = names(searcher.page(&indexer, &hits, 0..3).unwrap());
+        assert_eq!(first, ["app_00.log", "app_01.log", "app_02.log"]);
+        let last = searcher.page(&indexer, &hits, 28..40).unwrap();
+        assert_eq!(names(last), ["app_28.log", "app_29.log"]);
+
+        // 就地改写不移动已有下标，分页照常；重建后下标不再可信
+        assert!(indexer.edit(indexer.generation(), |store| store.mark_removed(&[0])));
+        assert_eq!(searcher.page(&indexer, &hits, 0..3).unwrap().len(), 3);
+        indexer.set_entries_from_cache(vec![entry("app.log", "C:/logs/app.log")]);
+        assert!(searcher.page(&indexer, &hits, 0..3).is_none());
+        assert!(!indexer.edit(hits.generation, |store| store.mark_removed(&[0])));
+    }
+
+    #[test]
+    fn type_prefix_filters_by_category() {
+        let mut indexer = FileIndexer::new();
+        let mut dir = entry("src", "C:/proj/src");
+        dir.is_dir = true;
+        indexer.set_entries_from_cache(vec![
+            entry("main.rs", "C:/proj/src/main.rs"),
+            entry("main.zip", "C:/proj/main.zip"),
+            entry("model.STL", "C:/proj/model.STL"),
+            dir,
+        ]);
+
+        let searcher = Searcher::new();
+        let names = |pattern: &str| -> Vec<String> {
+            let mut names: Vec<String> = searcher
+                .search(&indexer, pattern)
+                .iter()
+                .map(|r| r.entry().name().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(names("main type:code"), ["main.rs"]);
+        assert_eq!(names("Type:压缩包 main"), ["main.zip"]);
+        assert_eq!(names("type:model"), ["model.STL"]);
+        assert_eq!(names("type:folder"), ["src"]);
+        assert!(names("main type:unknown").is_empty());
+
+        let mut custom = CategoryRegistry::default();
+        custom.categories[0].extensions.push("zip".to_string());
+        let mut searcher = Searcher::new();
+        searcher.options.categories = Arc::new(custom);
+        assert_eq!(searcher.search(&indexer, "main type:document").len(), 1);
     }
 }
@@ -0,0 +1,107 @@
+//! 结果列表的多选：单击选中一项，Ctrl+单击切换，Shift+单击（或 Shift+方向键）从锚点连续选择。
+//!
+//! 下标指向当前结果列表；重新搜索后由调用方清空。
+
+use std::collections::BTreeSet;
+
+#[derive(Clone, Debug, Default)]
+pub struct Selection {
+    items: BTreeSet<usize>,
+    /// Shift 连续选择的起点。
+    anchor: Option<usize>,
+    /// 键盘焦点所在的一项（最近一次点击或导航到的项）。
+    cursor: Option<usize>,
+}
+
+impl Selection {
+    pub fn single(index: usize) -> Self {
+        Self {
+            items: BTreeSet::from([index]),
+            anchor: Some(index),
+            cursor: Some(index),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn select(&mut self, index: usize) {
+        *self = Self::single(index);
+    }
+
+    /// Ctrl+单击：切换该项，并把它作为新的锚点。
+    pub fn toggle(&mut self, index: usize) {
+        if !self.items.remove(&index) {
+            self.items.insert(index);
+        }
+        self.anchor = Some(index);
+        self.cursor = Some(index);
+    }
+
+    /// Shift+单击：选中锚点到该项之间的全部项（替换原有选择）。
+    pub fn extend_to(&mut self, index: usize) {
+        let anchor = self.anchor.unwrap_or(index);
+        self.items = (anchor.min(index)..=anchor.max(index)).collect();
+        self.anchor = Some(anchor);
+        self.cursor = Some(index);
+    }
+
+    pub fn select_all(&mut self, len: usize) {
+        self.items = (0..len).collect();
+        if len == 0 {
+            self.anchor = None;
+            self.cursor = None;
+        } else if self.cursor.is_none_or(|c| c >= len) {
+            self.anchor = Some(0);
+            self.cursor = Some(0);
+        }
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.items.contains(&index)
+    }
+
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// 按结果顺序列出选中项。
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.items.iter().copied()
+    }
+
+    /// 去掉超出结果列表的下标（结果变少时）。
+    pub fn retain_below(&mut self, len: usize) {
+        self.items.retain(|&i| i < len);
+        self.anchor = self.anchor.filter(|&i| i < len);
+        self.cursor = self.cursor.filter(|&i| i < len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ctrl_and_shift_clicks_build_the_selection() {
+        let mut selection = Selection::single(2);
+        selection.toggle(5);
+        assert_eq!(selection.indices().collect::<Vec<_>>(), [2, 5]);
+        selection.extend_to(3);
+        assert_eq!(selection.indices().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(selection.cursor(), Some(3));
+        selection.toggle(4);
+        assert_eq!(selection.indices().collect::<Vec<_>>(), [3, 5]);
+
+        selection.select_all(4);
+        assert_eq!(selection.len(), 4);
+        selection.retain_below(2);
+        assert_eq!(selection.indices().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(selection.cursor(), Some(0));
+    }
+}
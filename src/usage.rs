@@ -0,0 +1,205 @@
+//! 本地使用记录：按路径记录打开/定位（在文件夹中显示）的时间，用于 frecency（频率 + 新近度）排序加分。
+//!
+//! 记录以 bincode 保存在索引缓存同目录的 `usage.bin`，每个路径只保留最近 `MAX_EVENTS` 次事件。
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// 每个路径保留的最近事件数。
+const MAX_EVENTS: usize = 10;
+/// 总路径数上限；超出时淘汰最久未使用的路径。
+const MAX_PATHS: usize = 5000;
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UsageKind {
+    Open,
+    Reveal,
+}
+
+impl UsageKind {
+    /// 定位只说明“关心这个文件”，权重低于直接打开。
+    fn weight(self) -> f32 {
+        match self {
+            UsageKind::Open => 1.0,
+            UsageKind::Reveal => 0.5,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct UsageRecord {
+    /// (时间戳毫秒, 类型)，按时间先后排列。
+    events: Vec<(u64, UsageKind)>,
+}
+
+impl UsageRecord {
+    fn last_used_ms(&self) -> u64 {
+        self.events.last().map_or(0, |e| e.0)
+    }
+
+    fn frecency(&self, now_ms: u64) -> f32 {
+        self.events
+            .iter()
+            .map(|&(at, kind)| recency_weight(now_ms.saturating_sub(at)) * kind.weight())
+            .sum()
+    }
+}
+
+/// 按事件距今的时间分档加权（与浏览器地址栏的 frecency 分档类似）。
+fn recency_weight(age_ms: u64) -> f32 {
+    match age_ms / DAY_MS {
+        0..4 => 100.0,
+        4..14 => 70.0,
+        14..31 => 50.0,
+        31..90 => 30.0,
+        _ => 10.0,
+    }
+}
+
+#[derive(Default)]
+pub struct UsageStore {
+    file: Option<PathBuf>,
+    records: HashMap<String, UsageRecord>,
+}
+
+impl UsageStore {
+    /// 从文件加载；文件不存在或无法解析时从空记录开始。`file` 为 `None` 时只保存在内存中。
+    pub fn load(file: Option<PathBuf>) -> Self {
+        let records = file
+            .as_deref()
+            .and_then(|f| std::fs::read(f).ok())
+            .and_then(|bytes| bincode::deserialize(&bytes).ok())
+            .unwrap_or_default();
+        Self { file, records }
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// 记录一次使用并立即写回文件。
+    pub fn record(&mut self, path: &str, kind: UsageKind, now_ms: u64) -> std::io::Result<()> {
+        let record = self.records.entry(path.to_string()).or_default();
+        record.events.push((now_ms, kind));
+        if record.events.len() > MAX_EVENTS {
+            record.events.remove(0);
+        }
+        if self.records.len() > MAX_PATHS {
+            let stalest = self
+                .records
+                .iter()
+                .min_by_key(|(_, r)| r.last_used_ms())
+                .map(|(p, _)| p.clone());
+            if let Some(p) = stalest {
+                self.records.remove(&p);
+            }
+        }
+        self.save()
+    }
+
+    /// 清空全部记录并删除文件。
+    pub fn clear(&mut self) -> std::io::Result<()> {
+        self.records.clear();
+        match self.file.as_deref() {
+            Some(f) if f.exists() => std::fs::remove_file(f),
+            _ => Ok(()),
+        }
+    }
+
+    /// 生成供一次搜索使用的只读快照。
+    pub fn frecency_table(&self, now_ms: u64) -> FrecencyTable {
+        let mut table = FrecencyTable::default();
+        for (path, record) in &self.records {
+            table.names.insert(file_name(path).to_string());
+            table.by_path.insert(path.clone(), record.frecency(now_ms));
+        }
+        table
+    }
+
+    fn save(&self) -> std::io::Result<()> {
+        let Some(file) = self.file.as_deref() else {
+            return Ok(());
+        };
+        if let Some(dir) = file.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let bytes = bincode::serialize(&self.records)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        let tmp = file.with_extension("bin.tmp");
+        std::fs::write(&tmp, bytes)?;
+        std::fs::rename(&tmp, file)
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    let trimmed = path.trim_end_matches(['/', '\\']);
+    trimmed.rsplit(['/', '\\']).next().unwrap_or(trimmed)
+}
+
+/// 一次搜索期间使用的 frecency 快照。`names` 用于在拼接路径前快速排除绝大多数条目。
+#[derive(Default)]
+pub struct FrecencyTable {
+    by_path: HashMap<String, f32>,
+    names: HashSet<String>,
+}
+
+impl FrecencyTable {
+    pub fn is_empty(&self) -> bool {
+        self.by_path.is_empty()
+    }
+
+    pub fn may_contain_name(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    pub fn frecency(&self, path: &str) -> f32 {
+        self.by_path.get(path).copied().unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frecency_favours_frequent_and_recent_use() {
+        let now = 200 * DAY_MS;
+        let mut store = UsageStore::load(None);
+        store.record("C:/a.txt", UsageKind::Open, now - DAY_MS).unwrap();
+        store.record("C:/a.txt", UsageKind::Open, now - 2 * DAY_MS).unwrap();
+        store.record("C:/b.txt", UsageKind::Open, now - DAY_MS).unwrap();
+        store.record("C:/c.txt", UsageKind::Open, now - 100 * DAY_MS).unwrap();
+        store.record("C:/d.txt", UsageKind::Reveal, now - DAY_MS).unwrap();
+
+        let table = store.frecency_table(now);
+        let f = |p: &str| table.frecency(p);
+        assert!(f("C:/a.txt") > f("C:/b.txt"));
+        assert!(f("C:/b.txt") > f("C:/d.txt"));
+        assert!(f("C:/d.txt") > f("C:/c.txt"));
+        assert_eq!(f("C:/missing.txt"), 0.0);
+        assert!(table.may_contain_name("a.txt"));
+    }
+
+    #[test]
+    fn records_persist_and_clear_removes_file() {
+        let dir = std::env::temp_dir().join(format!("rust_search_usage_{}", std::process::id()));
+        let file = dir.join("usage.bin");
+        let mut store = UsageStore::load(Some(file.clone()));
+        for i in 0..(MAX_EVENTS as u64 + 3) {
+            store.record("/home/u/报告.docx", UsageKind::Open, i).unwrap();
+        }
+
+        let reloaded = UsageStore::load(Some(file.clone()));
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(reloaded.records["/home/u/报告.docx"].events.len(), MAX_EVENTS);
+
+        store.clear().unwrap();
+        assert!(!file.exists());
+        assert_eq!(UsageStore::load(Some(file)).len(), 0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
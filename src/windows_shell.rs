@@ -0,0 +1,363 @@
+//! Windows 资源管理器集成：系统右键菜单、属性对话框、“打开方式”、以文件形式复制到剪贴板（CF_HDROP），
+//! 按扩展名取系统图标，以及从托盘或全局快捷键唤起主窗口。
+//!
+//! winapi 0.3 没有 `IContextMenu` 与 `DROPFILES` 的定义，这里按 SDK 头文件声明所需的最小部分。
+
+// FFI 结构体的字段只由系统读取。
+#![allow(non_snake_case, dead_code)]
+
+use std::ffi::c_void;
+use std::io;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr;
+
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use winapi::Interface;
+use winapi::shared::basetsd::UINT_PTR;
+use winapi::shared::guiddef::GUID;
+use winapi::shared::minwindef::{BOOL, DWORD, UINT};
+use winapi::shared::windef::{HBITMAP, HICON, HMENU, HWND, POINT};
+use winapi::shared::winerror::FAILED;
+use winapi::um::combaseapi::CoInitializeEx;
+use winapi::um::objbase::COINIT_APARTMENTTHREADED;
+use winapi::um::shellapi::{
+    SEE_MASK_INVOKEIDLIST, SHELLEXECUTEINFOW, SHFILEINFOW, SHGFI_ICON, SHGFI_LARGEICON, SHGFI_SMALLICON,
+    SHGFI_USEFILEATTRIBUTES, SHGetFileInfoW, ShellExecuteExW,
+};
+use winapi::um::shobjidl_core::{IShellItem, SHCreateItemFromParsingName};
+use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+use winapi::um::winbase::{GMEM_MOVEABLE, GlobalAlloc, GlobalFree, GlobalLock, GlobalUnlock};
+use winapi::um::wingdi::{
+    BI_RGB, BITMAP, BITMAPINFO, BITMAPINFOHEADER, DIB_RGB_COLORS, DeleteObject, GetDIBits, GetObjectW,
+};
+use winapi::um::winnt::{FILE_ATTRIBUTE_DIRECTORY, FILE_ATTRIBUTE_NORMAL, HANDLE, HRESULT, LPCSTR, LPSTR};
+use winapi::um::winuser::{
+    CF_HDROP, CloseClipboard, CreatePopupMenu, DestroyIcon, DestroyMenu, EmptyClipboard, GetCursorPos, GetDC,
+    GetForegroundWindow, GetIconInfo, ICONINFO, IsIconic, OpenClipboard, ReleaseDC, SW_RESTORE, SW_SHOW,
+    SW_SHOWNORMAL, SetClipboardData, SetForegroundWindow, ShowWindow, TPM_RETURNCMD, TPM_RIGHTBUTTON,
+    TrackPopupMenuEx,
+};
+
+/// {3981E225-F559-11D3-8E3A-00C04F6837D5}
+const BHID_SF_UI_OBJECT: GUID = GUID {
+    Data1: 0x3981_e225,
+    Data2: 0xf559,
+    Data3: 0x11d3,
+    Data4: [0x8e, 0x3a, 0x00, 0xc0, 0x4f, 0x68, 0x37, 0xd5],
+};
+
+/// {000214E4-0000-0000-C000-000000000046}
+const IID_ICONTEXT_MENU: GUID = GUID {
+    Data1: 0x0002_14e4,
+    Data2: 0x0000,
+    Data3: 0x0000,
+    Data4: [0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+const CMF_NORMAL: UINT = 0;
+const FIRST_COMMAND: UINT = 1;
+const LAST_COMMAND: UINT = 0x7fff;
+
+#[repr(C)]
+struct CMINVOKECOMMANDINFO {
+    cbSize: DWORD,
+    fMask: DWORD,
+    hwnd: HWND,
+    lpVerb: LPCSTR,
+    lpParameters: LPCSTR,
+    lpDirectory: LPCSTR,
+    nShow: i32,
+    dwHotKey: DWORD,
+    hIcon: HANDLE,
+}
+
+#[repr(C)]
+struct IContextMenuVtbl {
+    parent: IUnknownVtbl,
+    QueryContextMenu: unsafe extern "system" fn(
+        This: *mut IContextMenu,
+        hmenu: HMENU,
+        indexMenu: UINT,
+        idCmdFirst: UINT,
+        idCmdLast: UINT,
+        uFlags: UINT,
+    ) -> HRESULT,
+    InvokeCommand: unsafe extern "system" fn(This: *mut IContextMenu, pici: *mut CMINVOKECOMMANDINFO) -> HRESULT,
+    GetCommandString: unsafe extern "system" fn(
+        This: *mut IContextMenu,
+        idCmd: UINT_PTR,
+        uType: UINT,
+        pReserved: *mut UINT,
+        pszName: LPSTR,
+        cchMax: UINT,
+    ) -> HRESULT,
+}
+
+#[repr(C)]
+struct IContextMenu {
+    lpVtbl: *const IContextMenuVtbl,
+}
+
+#[repr(C)]
+struct DROPFILES {
+    pFiles: DWORD,
+    pt: POINT,
+    fNC: BOOL,
+    fWide: BOOL,
+}
+
+fn to_wide(path: &str) -> Vec<u16> {
+    std::ffi::OsStr::new(&path.replace('/', "\\"))
+        .encode_wide()
+        .chain(Some(0))
+        .collect()
+}
+
+fn check(hr: HRESULT) -> io::Result<()> {
+    if FAILED(hr) {
+        return Err(io::Error::from_raw_os_error(hr));
+    }
+    Ok(())
+}
+
+/// 在鼠标位置弹出资源管理器的右键菜单，并执行用户选择的命令（阻塞到菜单关闭）。
+pub fn show_context_menu(path: &str) -> io::Result<()> {
+    let wide = to_wide(path);
+    unsafe {
+        // 已初始化（S_FALSE）或线程模型不同（RPC_E_CHANGED_MODE）都不影响后续调用
+        CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+
+        let mut item: *mut IShellItem = ptr::null_mut();
+        check(SHCreateItemFromParsingName(
+            wide.as_ptr(),
+            ptr::null_mut(),
+            &IShellItem::uuidof(),
+            &mut item as *mut _ as *mut *mut c_void,
+        ))?;
+        let mut menu: *mut IContextMenu = ptr::null_mut();
+        let hr = (*item).BindToHandler(
+            ptr::null_mut(),
+            &BHID_SF_UI_OBJECT,
+            &IID_ICONTEXT_MENU,
+            &mut menu as *mut _ as *mut *mut c_void,
+        );
+        (*item).Release();
+        check(hr)?;
+
+        let result = track_context_menu(menu);
+        ((*(*menu).lpVtbl).parent.Release)(menu as *mut IUnknown);
+        result
+    }
+}
+
+/// 调用方需保证 `menu` 有效。
+unsafe fn track_context_menu(menu: *mut IContextMenu) -> io::Result<()> {
+    let hmenu = unsafe { CreatePopupMenu() };
+    if hmenu.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+    let result = unsafe { run_context_menu(menu, hmenu) };
+    unsafe { DestroyMenu(hmenu) };
+    result
+}
+
+unsafe fn run_context_menu(menu: *mut IContextMenu, hmenu: HMENU) -> io::Result<()> {
+    unsafe {
+        let vtbl = &*(*menu).lpVtbl;
+        check((vtbl.QueryContextMenu)(menu, hmenu, 0, FIRST_COMMAND, LAST_COMMAND, CMF_NORMAL))?;
+
+        let hwnd = GetForegroundWindow();
+        let mut pt = POINT { x: 0, y: 0 };
+        GetCursorPos(&mut pt);
+        let cmd = TrackPopupMenuEx(hmenu, TPM_RETURNCMD | TPM_RIGHTBUTTON, pt.x, pt.y, hwnd, ptr::null_mut());
+        if cmd <= 0 {
+            return Ok(());
+        }
+
+        let mut info: CMINVOKECOMMANDINFO = std::mem::zeroed();
+        info.cbSize = std::mem::size_of::<CMINVOKECOMMANDINFO>() as DWORD;
+        info.hwnd = hwnd;
+        // MAKEINTRESOURCEA：以命令偏移代替动词字符串
+        info.lpVerb = (cmd as UINT - FIRST_COMMAND) as usize as LPCSTR;
+        info.nShow = SW_SHOWNORMAL;
+        check((vtbl.InvokeCommand)(menu, &mut info))
+    }
+}
+
+/// 打开资源管理器的“属性”对话框。
+pub fn show_properties(path: &str) -> io::Result<()> {
+    let file = to_wide(path);
+    let verb: Vec<u16> = "properties".encode_utf16().chain(Some(0)).collect();
+    unsafe {
+        let mut info: SHELLEXECUTEINFOW = std::mem::zeroed();
+        info.cbSize = std::mem::size_of::<SHELLEXECUTEINFOW>() as DWORD;
+        info.fMask = SEE_MASK_INVOKEIDLIST;
+        info.hwnd = GetForegroundWindow();
+        info.lpVerb = verb.as_ptr();
+        info.lpFile = file.as_ptr();
+        info.nShow = SW_SHOWNORMAL;
+        if ShellExecuteExW(&mut info) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// 弹出系统的“打开方式”选择对话框。
+pub fn open_with(path: &str) -> io::Result<()> {
+    std::process::Command::new("rundll32.exe")
+        .arg(format!("shell32.dll,OpenAs_RunDLL {}", path.replace('/', "\\")))
+        .spawn()
+        .map(|_| ())
+}
+
+/// 以 CF_HDROP 格式把文件放到剪贴板，可在资源管理器中粘贴。
+pub fn copy_files_to_clipboard(paths: &[String]) -> io::Result<()> {
+    // DROPFILES 头之后是以 NUL 分隔、以两个 NUL 结尾的 UTF-16 路径列表
+    let mut list: Vec<u16> = paths.iter().flat_map(|p| to_wide(p)).collect();
+    list.push(0);
+    let header = std::mem::size_of::<DROPFILES>();
+    let bytes = header + list.len() * 2;
+    unsafe {
+        let hglobal = GlobalAlloc(GMEM_MOVEABLE, bytes);
+        if hglobal.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let data = GlobalLock(hglobal) as *mut u8;
+        if data.is_null() {
+            GlobalFree(hglobal);
+            return Err(io::Error::last_os_error());
+        }
+        ptr::write_unaligned(
+            data as *mut DROPFILES,
+            DROPFILES {
+                pFiles: header as DWORD,
+                pt: POINT { x: 0, y: 0 },
+                fNC: 0,
+                fWide: 1,
+            },
+        );
+        ptr::copy_nonoverlapping(list.as_ptr() as *const u8, data.add(header), list.len() * 2);
+        GlobalUnlock(hglobal);
+
+        if OpenClipboard(ptr::null_mut()) == 0 {
+            GlobalFree(hglobal);
+            return Err(io::Error::last_os_error());
+        }
+        EmptyClipboard();
+        // 成功后内存归剪贴板所有
+        let ok = !SetClipboardData(CF_HDROP, hglobal).is_null();
+        CloseClipboard();
+        if !ok {
+            GlobalFree(hglobal);
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// 按扩展名取系统图标（只看扩展名，不访问磁盘），返回尺寸与 RGBA 像素；`is_dir` 时取文件夹图标。
+pub fn extension_icon(extension: &str, is_dir: bool, large: bool) -> Option<([usize; 2], Vec<u8>)> {
+    let name = if is_dir { "folder".to_string() } else { format!("file.{extension}") };
+    let wide: Vec<u16> = std::ffi::OsStr::new(&name).encode_wide().chain(Some(0)).collect();
+    let attributes = if is_dir { FILE_ATTRIBUTE_DIRECTORY } else { FILE_ATTRIBUTE_NORMAL };
+    let size = if large { SHGFI_LARGEICON } else { SHGFI_SMALLICON };
+    unsafe {
+        CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+        let mut info: SHFILEINFOW = std::mem::zeroed();
+        let found = SHGetFileInfoW(
+            wide.as_ptr(),
+            attributes,
+            &mut info,
+            std::mem::size_of::<SHFILEINFOW>() as UINT,
+            SHGFI_ICON | SHGFI_USEFILEATTRIBUTES | size,
+        );
+        if found == 0 || info.hIcon.is_null() {
+            return None;
+        }
+        let pixels = icon_pixels(info.hIcon);
+        DestroyIcon(info.hIcon);
+        pixels
+    }
+}
+
+/// 调用方需保证 `icon` 有效。
+unsafe fn icon_pixels(icon: HICON) -> Option<([usize; 2], Vec<u8>)> {
+    unsafe {
+        let mut icon_info: ICONINFO = std::mem::zeroed();
+        if GetIconInfo(icon, &mut icon_info) == 0 {
+            return None;
+        }
+        // 单色图标没有彩色位图，交给调用方改用内置图标
+        let pixels = if icon_info.hbmColor.is_null() { None } else { bitmap_pixels(icon_info.hbmColor) };
+        if !icon_info.hbmColor.is_null() {
+            DeleteObject(icon_info.hbmColor as _);
+        }
+        if !icon_info.hbmMask.is_null() {
+            DeleteObject(icon_info.hbmMask as _);
+        }
+        pixels
+    }
+}
+
+unsafe fn bitmap_pixels(bitmap: HBITMAP) -> Option<([usize; 2], Vec<u8>)> {
+    unsafe {
+        let mut bm: BITMAP = std::mem::zeroed();
+        if GetObjectW(bitmap as _, std::mem::size_of::<BITMAP>() as i32, &mut bm as *mut _ as *mut c_void) == 0 {
+            return None;
+        }
+        let (width, height) = (bm.bmWidth, bm.bmHeight);
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+        let mut header: BITMAPINFO = std::mem::zeroed();
+        header.bmiHeader.biSize = std::mem::size_of::<BITMAPINFOHEADER>() as DWORD;
+        header.bmiHeader.biWidth = width;
+        // 负高度表示自上而下的行序
+        header.bmiHeader.biHeight = -height;
+        header.bmiHeader.biPlanes = 1;
+        header.bmiHeader.biBitCount = 32;
+        header.bmiHeader.biCompression = BI_RGB;
+        let mut pixels = vec![0u8; width as usize * height as usize * 4];
+        let dc = GetDC(ptr::null_mut());
+        let lines = GetDIBits(
+            dc,
+            bitmap,
+            0,
+            height as UINT,
+            pixels.as_mut_ptr() as *mut c_void,
+            &mut header,
+            DIB_RGB_COLORS,
+        );
+        ReleaseDC(ptr::null_mut(), dc);
+        if lines == 0 {
+            return None;
+        }
+        // BGRA → RGBA；旧式图标的 alpha 全为 0，视为不透明
+        let has_alpha = pixels.chunks_exact(4).any(|p| p[3] != 0);
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+            if !has_alpha {
+                pixel[3] = 255;
+            }
+        }
+        Some(([width as usize, height as usize], pixels))
+    }
+}
+
+/// 窗口的 HWND，以整数保存以便传给托盘与全局快捷键的事件回调。
+pub fn window_hwnd(window: &impl HasWindowHandle) -> Option<isize> {
+    match window.window_handle().ok()?.as_raw() {
+        RawWindowHandle::Win32(handle) => Some(handle.hwnd.get()),
+        _ => None,
+    }
+}
+
+/// 显示、还原并激活窗口。隐藏的窗口收不到重绘请求，因此托盘与全局快捷键的回调先直接唤起窗口。
+pub fn show_window(hwnd: isize) {
+    let hwnd = hwnd as HWND;
+    unsafe {
+        ShowWindow(hwnd, if IsIconic(hwnd) != 0 { SW_RESTORE } else { SW_SHOW });
+        SetForegroundWindow(hwnd);
+    }
+}
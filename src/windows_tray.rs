@@ -0,0 +1,83 @@
+//! 系统托盘图标：关闭主窗口后程序驻留在托盘，索引与全局快捷键继续可用。
+//!
+//! 菜单与图标的事件在界面线程的消息循环中回调，这里只记下命令，由 `FileSearchApp` 在下一帧处理。
+
+use std::sync::{Arc, Mutex};
+
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrayCommand {
+    ShowMain,
+    QuickSearch,
+    Quit,
+}
+
+pub struct Tray {
+    _icon: TrayIcon,
+    commands: Arc<Mutex<Vec<TrayCommand>>>,
+}
+
+impl Tray {
+    /// 事件回调全局只能设置一次，因此每个进程只创建一个。`wake` 在记下命令后调用。
+    pub fn new(wake: impl Fn() + Send + Sync + 'static) -> Result<Self, String> {
+        let show = MenuItem::new("显示主窗口", true, None);
+        let quick = MenuItem::new("快速搜索", true, None);
+        let quit = MenuItem::new("退出", true, None);
+        let menu = Menu::new();
+        menu.append_items(&[&show, &quick, &PredefinedMenuItem::separator(), &quit])
+            .map_err(|e| e.to_string())?;
+
+        let image = crate::load_icon();
+        let icon = Icon::from_rgba(image.rgba, image.width, image.height).map_err(|e| e.to_string())?;
+        let tray = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip("RustSearch")
+            .with_icon(icon)
+            .with_menu_on_left_click(false)
+            .build()
+            .map_err(|e| format!("无法创建托盘图标：{e}"))?;
+
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let wake = Arc::new(wake);
+        let push = {
+            let commands = Arc::clone(&commands);
+            move |command: TrayCommand| {
+                commands.lock().unwrap().push(command);
+                wake();
+            }
+        };
+        let items = [
+            (show.id().clone(), TrayCommand::ShowMain),
+            (quick.id().clone(), TrayCommand::QuickSearch),
+            (quit.id().clone(), TrayCommand::Quit),
+        ];
+        let on_menu = push.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            if let Some((_, command)) = items.iter().find(|(id, _)| *id == event.id) {
+                on_menu(*command);
+            }
+        }));
+        // 左键单击图标显示主窗口，右键弹出菜单
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                push(TrayCommand::ShowMain);
+            }
+        }));
+
+        Ok(Self {
+            _icon: tray,
+            commands,
+        })
+    }
+
+    pub fn take_commands(&self) -> Vec<TrayCommand> {
+        std::mem::take(&mut *self.commands.lock().unwrap())
+    }
+}
@@ -1,6 +1,7 @@
 #![cfg(windows)]
 
-use crate::indexer::{FileEntry, IndexerHandles, UsnDriveState};
+use crate::entry_store::{EntryMeta, EntryStore};
+use crate::indexer::{IndexerHandles, UsnDriveState};
 use std::collections::HashMap;
 use std::io;
 use std::os::windows::ffi::OsStrExt;
@@ -92,8 +93,9 @@ struct UsnEvent {
     name: String,
 }
 
+/// 读取各卷自上次以来的 USN 记录，直接在 `entries` 上增删改对应条目（下标保持不变）。
 pub fn try_apply_usn_incremental(
-    entries: &mut Vec<FileEntry>,
+    entries: &mut EntryStore,
     usn_states: &mut Vec<UsnDriveState>,
     handles: &IndexerHandles,
 ) -> io::Result<()> {
@@ -244,7 +246,8 @@ pub fn try_enumerate_drive_root(
     progress_base: usize,
     is_indexing: Option<&AtomicBool>,
     progress: Option<&AtomicUsize>,
-) -> io::Result<(Vec<FileEntry>, UsnDriveState)> {
+    out: &mut EntryStore,
+) -> io::Result<UsnDriveState> {
     let Some(drive) = is_drive_root(root_path) else {
         return Err(io::Error::new(io::ErrorKind::InvalidInput, "不是盘符根目录"));
     };
@@ -262,7 +265,8 @@ pub fn try_enumerate_drive_root(
     // 1MB 缓冲区：在大盘上可减少 ioctl 次数
     let mut buffer = vec![0u8; 1024 * 1024];
 
-    let mut entries: Vec<FileEntry> = Vec::new();
+    // 直接写入调用方的列式存储；取消或失败时回滚到 `base_len`。
+    let base_len = out.len();
     let mut seen = 0usize;
 
     loop {
@@ -271,15 +275,13 @@ pub fn try_enumerate_drive_root(
                 unsafe {
                     CloseHandle(volume_handle);
                 }
-                return Ok((
-                    Vec::new(),
-                    UsnDriveState {
-                        drive: drive as u8,
-                        journal_id: journal.usn_journal_id,
-                        root_frn,
-                        last_usn: journal.next_usn,
-                    },
-                ));
+                out.truncate(base_len);
+                return Ok(UsnDriveState {
+                    drive: drive as u8,
+                    journal_id: journal.usn_journal_id,
+                    root_frn,
+                    last_usn: journal.next_usn,
+                });
             }
         }
 
@@ -306,6 +308,7 @@ pub fn try_enumerate_drive_root(
             unsafe {
                 CloseHandle(volume_handle);
             }
+            out.truncate(base_len);
             return Err(err);
         }
 
@@ -344,23 +347,14 @@ pub fn try_enumerate_drive_root(
                 let name = String::from_utf16_lossy(name_slice);
                 if !name.is_empty() {
                     if frn != root_frn {
-                        let name_lower = lowercase_for_search(&name);
                         let is_dir = (attrs & FILE_ATTRIBUTE_DIRECTORY) != 0;
                         let is_hidden =
                             (attrs & (FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM)) != 0;
-                        entries.push(FileEntry {
-                            name,
-                            name_lower,
-                            path: String::new(),
-                            path_lower: String::new(),
-                            drive: drive as u8,
-                            frn,
-                            parent_frn: parent,
-                            size: u64::MAX,
-                            modified_ms: 0,
-                            is_dir,
-                            is_hidden,
-                        });
+                        out.push(
+                            &name,
+                            "",
+                            EntryMeta::new(drive as u8, frn, parent, u64::MAX, 0, is_dir, is_hidden),
+                        );
                     }
                     seen += 1;
                     if seen % 50_000 == 0 {
@@ -380,18 +374,18 @@ pub fn try_enumerate_drive_root(
     }
 
     if let Some(p) = progress {
-        p.store(progress_base.saturating_add(entries.len()), Ordering::SeqCst);
+        p.store(
+            progress_base.saturating_add(out.len().saturating_sub(base_len)),
+            Ordering::SeqCst,
+        );
     }
 
-    Ok((
-        entries,
-        UsnDriveState {
-            drive: drive as u8,
-            journal_id: journal.usn_journal_id,
-            root_frn,
-            last_usn: journal.next_usn,
-        },
-    ))
+    Ok(UsnDriveState {
+        drive: drive as u8,
+        journal_id: journal.usn_journal_id,
+        root_frn,
+        last_usn: journal.next_usn,
+    })
 }
 
 fn read_usn_events(
@@ -557,7 +551,7 @@ fn read_usn_events(
     Ok(events)
 }
 
-fn apply_events_for_drive(entries: &mut Vec<FileEntry>, state: &UsnDriveState, events: Vec<UsnEvent>) {
+fn apply_events_for_drive(entries: &mut EntryStore, state: &UsnDriveState, events: Vec<UsnEvent>) {
     if events.is_empty() {
         return;
     }
@@ -565,191 +559,62 @@ fn apply_events_for_drive(entries: &mut Vec<FileEntry>, state: &UsnDriveState, e
     let drive = state.drive;
     let root_frn = state.root_frn;
 
-    let mut frn_to_idx: HashMap<u64, usize> = HashMap::new();
-    for (idx, entry) in entries.iter().enumerate() {
-        if entry.drive == drive && entry.frn != 0 {
-            frn_to_idx.insert(entry.frn, idx);
+    let mut frn_to_idx: HashMap<u64, usize> = entries
+        .iter()
+        .filter(|e| e.drive() == drive && e.frn() != 0 && !e.is_removed())
+        .map(|e| (e.frn(), e.index()))
+        .collect();
+    // 卷根下的条目没有父条目；父目录不在索引中（如系统目录）时返回 `Err`
+    let parent_of = |frn_to_idx: &HashMap<u64, usize>, parent_frn: u64| -> Result<Option<usize>, ()> {
+        if parent_frn == root_frn {
+            Ok(None)
+        } else {
+            frn_to_idx.get(&parent_frn).map(|&p| Some(p)).ok_or(())
         }
-    }
+    };
+    let mut removed = Vec::new();
 
     for ev in events {
-        // delete
+        let is_dir = (ev.attrs & FILE_ATTRIBUTE_DIRECTORY) != 0;
+        let is_hidden = (ev.attrs & (FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM)) != 0;
+
+        // delete：目录下的条目由 `mark_removed` 按父链一并标记
         if (ev.reason & USN_REASON_FILE_DELETE) != 0 {
-            let Some(&idx) = frn_to_idx.get(&ev.frn) else {
-                continue;
-            };
-            let is_dir = entries[idx].is_dir;
-            let old_path = entries[idx].path.clone();
-            remove_entry_by_idx(entries, idx, &mut frn_to_idx);
-            if is_dir && !old_path.is_empty() {
-                let prefix = if old_path.ends_with('/') {
-                    old_path
-                } else {
-                    format!("{old_path}/")
-                };
-                remove_entries_by_prefix(entries, drive, &prefix, &mut frn_to_idx);
+            if let Some(idx) = frn_to_idx.remove(&ev.frn) {
+                removed.push(idx);
             }
             continue;
         }
 
-        // rename/move (new name)
-        if (ev.reason & USN_REASON_RENAME_NEW_NAME) != 0 {
-            if let Some(&idx) = frn_to_idx.get(&ev.frn) {
-                let old_path = entries[idx].path.clone();
-                let old_is_dir = entries[idx].is_dir;
-                if let Some(new_path) = compose_path(entries, &frn_to_idx, drive, root_frn, ev.parent_frn, &ev.name)
-                {
-                    let entry = &mut entries[idx];
-                    entry.name = ev.name;
-                    entry.name_lower = lowercase_for_search(&entry.name);
-                    entry.path = new_path.clone();
-                    entry.path_lower = lowercase_for_search(&new_path);
-                    entry.parent_frn = ev.parent_frn;
-                    entry.is_dir = (ev.attrs & FILE_ATTRIBUTE_DIRECTORY) != 0;
-                    entry.is_hidden =
-                        (ev.attrs & (FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM)) != 0;
-
-                    if old_is_dir && !old_path.is_empty() && old_path != new_path {
-                        let old_prefix = if old_path.ends_with('/') {
-                            old_path
-                        } else {
-                            format!("{old_path}/")
-                        };
-                        let new_prefix = if new_path.ends_with('/') {
-                            new_path
-                        } else {
-                            format!("{new_path}/")
-                        };
-                        update_entries_prefix(entries, drive, &old_prefix, &new_prefix);
-                    }
-                }
-            } else if let Some(new_path) =
-                compose_path(entries, &frn_to_idx, drive, root_frn, ev.parent_frn, &ev.name)
-            {
-                let is_dir = (ev.attrs & FILE_ATTRIBUTE_DIRECTORY) != 0;
-                let is_hidden = (ev.attrs & (FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM)) != 0;
-                let name_lower = lowercase_for_search(&ev.name);
-                let path_lower = lowercase_for_search(&new_path);
-                let new_entry = FileEntry {
-                    name: ev.name,
-                    name_lower,
-                    path: new_path,
-                    path_lower,
-                    drive,
-                    frn: ev.frn,
-                    parent_frn: ev.parent_frn,
-                    size: u64::MAX,
-                    modified_ms: 0,
-                    is_dir,
-                    is_hidden,
-                };
-                entries.push(new_entry);
-                frn_to_idx.insert(ev.frn, entries.len() - 1);
-            }
+        let is_rename = (ev.reason & USN_REASON_RENAME_NEW_NAME) != 0;
+        if !is_rename && (ev.reason & USN_REASON_FILE_CREATE) == 0 {
             continue;
         }
-
-        // create
-        if (ev.reason & USN_REASON_FILE_CREATE) != 0 {
-            if frn_to_idx.contains_key(&ev.frn) {
-                continue;
+        match (frn_to_idx.get(&ev.frn).copied(), parent_of(&frn_to_idx, ev.parent_frn)) {
+            // rename/move：只改写这一个条目，子项路径沿父链拼接，自然跟着变化
+            (Some(idx), Ok(parent)) if is_rename => {
+                if entries.name(idx) != ev.name {
+                    entries.rename(idx, &ev.name);
+                }
+                entries.set_parent(idx, ev.parent_frn, parent);
+                entries.set_attributes(idx, is_dir, is_hidden);
+            }
+            // 移到了未索引的目录下
+            (Some(idx), Err(())) if is_rename => {
+                frn_to_idx.remove(&ev.frn);
+                removed.push(idx);
+            }
+            (Some(_), _) | (None, Err(())) => {}
+            // create，或从未索引的位置移入
+            (None, Ok(parent)) => {
+                let meta = EntryMeta::new(drive, ev.frn, ev.parent_frn, u64::MAX, 0, is_dir, is_hidden);
+                let idx = entries.push_linked(&ev.name, "", meta, parent);
+                frn_to_idx.insert(ev.frn, idx);
             }
-            let Some(new_path) =
-                compose_path(entries, &frn_to_idx, drive, root_frn, ev.parent_frn, &ev.name)
-            else {
-                continue;
-            };
-
-            let is_dir = (ev.attrs & FILE_ATTRIBUTE_DIRECTORY) != 0;
-            let is_hidden = (ev.attrs & (FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM)) != 0;
-            let name_lower = lowercase_for_search(&ev.name);
-            let path_lower = lowercase_for_search(&new_path);
-            let new_entry = FileEntry {
-                name: ev.name,
-                name_lower,
-                path: new_path,
-                path_lower,
-                drive,
-                frn: ev.frn,
-                parent_frn: ev.parent_frn,
-                size: u64::MAX,
-                modified_ms: 0,
-                is_dir,
-                is_hidden,
-            };
-            entries.push(new_entry);
-            frn_to_idx.insert(ev.frn, entries.len() - 1);
-        }
-    }
-}
-
-fn compose_path(
-    entries: &[FileEntry],
-    frn_to_idx: &HashMap<u64, usize>,
-    drive: u8,
-    root_frn: u64,
-    parent_frn: u64,
-    name: &str,
-) -> Option<String> {
-    let mut base = if parent_frn == root_frn {
-        format!("{}:/", drive as char)
-    } else {
-        let idx = *frn_to_idx.get(&parent_frn)?;
-        entries.get(idx)?.path.clone()
-    };
-
-    if !base.ends_with('/') {
-        base.push('/');
-    }
-    base.push_str(name);
-    Some(base)
-}
-
-fn remove_entry_by_idx(
-    entries: &mut Vec<FileEntry>,
-    idx: usize,
-    frn_to_idx: &mut HashMap<u64, usize>,
-) {
-    let removed = entries.swap_remove(idx);
-    if removed.frn != 0 {
-        frn_to_idx.remove(&removed.frn);
-    }
-    if idx < entries.len() {
-        let swapped = &entries[idx];
-        if swapped.frn != 0 {
-            frn_to_idx.insert(swapped.frn, idx);
-        }
-    }
-}
-
-fn remove_entries_by_prefix(
-    entries: &mut Vec<FileEntry>,
-    drive: u8,
-    prefix: &str,
-    frn_to_idx: &mut HashMap<u64, usize>,
-) {
-    let mut i = 0usize;
-    while i < entries.len() {
-        if entries[i].drive == drive && entries[i].path.starts_with(prefix) {
-            remove_entry_by_idx(entries, i, frn_to_idx);
-            continue;
         }
-        i += 1;
     }
-}
 
-fn update_entries_prefix(entries: &mut [FileEntry], drive: u8, from_prefix: &str, to_prefix: &str) {
-    for entry in entries.iter_mut() {
-        if entry.drive != drive {
-            continue;
-        }
-        if !entry.path.starts_with(from_prefix) {
-            continue;
-        }
-        let rest = entry.path[from_prefix.len()..].to_string();
-        entry.path = format!("{to_prefix}{rest}");
-        entry.path_lower = lowercase_for_search(&entry.path);
-    }
+    entries.mark_removed(&removed);
 }
 
 fn open_volume_handle(drive: char) -> io::Result<HANDLE> {
@@ -829,18 +694,3 @@ fn to_wide_null(s: &str) -> Vec<u16> {
         .chain(std::iter::once(0))
         .collect()
 }
-
-fn lowercase_for_search(s: &str) -> String {
-    if !s.is_ascii() {
-        return String::new();
-    }
-
-    let bytes = s.as_bytes();
-    if !bytes.iter().any(|b| (b'A'..=b'Z').contains(b)) {
-        return String::new();
-    }
-
-    let mut out = s.to_string();
-    out.make_ascii_lowercase();
-    out
-}